@@ -0,0 +1,58 @@
+// localizer-rs benchmarks
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+
+////////////////
+// BENCHMARKS //
+////////////////
+
+/// Translates a key with an already cached catalog, which only costs a hash map lookup.
+fn translate_cached(c: &mut Criterion) {
+    let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+    config.reload();
+
+    c.bench_function("translate (cached)", |b| {
+        b.iter(|| config.translate("error", vec![("details", "Something went wrong")]))
+    });
+}
+
+/// Translates a key after discarding the cache, which reads and parses the translation file.
+fn translate_uncached(c: &mut Criterion) {
+    let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+
+    c.bench_function("translate (uncached)", |b| {
+        b.iter(|| {
+            config.clear_cache();
+            config.translate("error", vec![("details", "Something went wrong")])
+        })
+    });
+}
+
+criterion_group!(benches, translate_cached, translate_uncached);
+criterion_main!(benches);
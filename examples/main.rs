@@ -1,5 +1,3 @@
-use localizer_rs;
-
 fn main() {
     let config: localizer_rs::Config = localizer_rs::Config::new("translations", "en");
 
@@ -15,6 +13,7 @@ fn main() {
         "{:}",
         localizer_rs::t!(config, "success", "balance" = "$10", "user" = "John Doe")
     );
+    println!("{:}", localizer_rs::t!(config, "files", count = 3));
 
     println!("{:}", localizer_rs::t!(config, "all"));
 }
@@ -0,0 +1,29 @@
+# Example Fluent resource, loaded because there is no fr.json.
+
+-brand = Localizer
+
+test = Quelque chose qui peut être traduit.
+error = { color.red }{ bold }Erreur :{ end } { $details }
+success = { color.green }{ bold }Succès :{ end } { $balance } transférés à { $user }
+
+files =
+    { $count ->
+        [0] Aucun fichier
+        [one] { $count } fichier
+       *[other] { $count } fichiers
+    }
+
+welcome = Bienvenue dans { -brand }, { $user } !
+    .title = { -brand }
+
+place =
+    { NUMBER($place, type: "ordinal") ->
+        [one] { $place }er
+       *[other] { $place }e
+    }
+
+menu = Menu
+    .open = Ouvrir un fichier
+    .save = Enregistrer
+
+appointment = Votre rendez-vous est le { DATETIME($when, dateStyle: "full") } à { DATETIME($when, timeStyle: "short") }.
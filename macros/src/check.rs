@@ -0,0 +1,384 @@
+// localizer-rs macros check
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::Parse;
+use syn::parse::ParseStream;
+
+
+///////////
+// INPUT //
+///////////
+
+/// Argument of the `checked_t!` macro.
+enum Argument {
+    /// The number to select the plural form for, `count = value`.
+    Count(syn::Expr),
+    /// An argument to replace, `"name" = value`, `name = value` or `name`.
+    Named(syn::LitStr, syn::Expr),
+}
+
+/// Input of the `checked_t!` macro.
+pub(crate) struct Input {
+    /// The reference translation file.
+    reference: syn::LitStr,
+    /// The config object.
+    config: syn::Expr,
+    /// The key to translate.
+    key: syn::LitStr,
+    /// The arguments of the translation.
+    arguments: Vec<Argument>,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Input> {
+        let reference: syn::LitStr = input.parse()?;
+        input.parse::<syn::Token![,]>()?;
+        let config: syn::Expr = input.parse()?;
+        input.parse::<syn::Token![,]>()?;
+        let key: syn::LitStr = input.parse()?;
+        let mut arguments: Vec<Argument> = vec![];
+
+        while input.peek(syn::Token![,]) {
+            input.parse::<syn::Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            if input.peek(syn::Ident) {
+                let name: syn::Ident = input.parse()?;
+                let literal: syn::LitStr = syn::LitStr::new(&name.to_string(), name.span());
+
+                if !input.peek(syn::Token![=]) {
+                    arguments.push(Argument::Named(literal, syn::parse_quote!(#name)));
+                    continue;
+                }
+                input.parse::<syn::Token![=]>()?;
+
+                match name == "count" && arguments.is_empty() {
+                    true => arguments.push(Argument::Count(input.parse()?)),
+                    false => arguments.push(Argument::Named(literal, input.parse()?)),
+                }
+            } else {
+                let name: syn::LitStr = input.parse()?;
+                input.parse::<syn::Token![=]>()?;
+                arguments.push(Argument::Named(name, input.parse()?));
+            }
+        }
+
+        if !input.is_empty() {
+            return Err(input.error("Expected `,`"));
+        }
+
+        return Ok(Input {
+            reference,
+            config,
+            key,
+            arguments,
+        });
+    }
+}
+
+
+//////////
+// KEYS //
+//////////
+
+/// Returns the messages of a translation.
+///
+/// # Returns
+///
+/// The message of a string or the messages of all plural forms. `None` if the value is not a
+/// translation.
+fn messages(value: &serde_json::Value) -> Option<Vec<&str>> {
+    if let Some(message) = value.as_str() {
+        return Some(vec![message]);
+    }
+    if !localizer_rs_shared::is_plural(value) {
+        return None;
+    }
+
+    return value.as_object().map(|forms| {
+        forms
+            .values()
+            .filter_map(serde_json::Value::as_str)
+            .collect()
+    });
+}
+
+/// Returns all keys of the reference catalog that resolve to translations.
+fn all_keys(prefix: &str, values: &serde_json::Map<String, serde_json::Value>) -> Vec<String> {
+    let mut keys: Vec<String> = vec![];
+
+    for (key, value) in values {
+        let key: String = format!(
+            "{}{}",
+            prefix,
+            key.replace('\\', "\\\\").replace('.', "\\.")
+        );
+
+        match value.as_object() {
+            Some(nested) if !localizer_rs_shared::is_plural(value) => {
+                keys.extend(all_keys(&format!("{}.", key), nested));
+            }
+            _ => keys.push(key),
+        }
+    }
+
+    return keys;
+}
+
+/// Returns the edit distance between two strings.
+fn distance(first: &str, second: &str) -> usize {
+    let second: Vec<char> = second.chars().collect();
+    let mut previous: Vec<usize> = (0..=second.len()).collect();
+
+    for (index, first_character) in first.chars().enumerate() {
+        let mut current: Vec<usize> = vec![index + 1];
+
+        for (other_index, second_character) in second.iter().enumerate() {
+            let substitution: usize =
+                previous[other_index] + (first_character != *second_character) as usize;
+            current.push(
+                substitution
+                    .min(previous[other_index + 1] + 1)
+                    .min(current[other_index] + 1),
+            );
+        }
+        previous = current;
+    }
+
+    return previous[second.len()];
+}
+
+/// Built-in style placeholders that are not arguments.
+const STYLES: [&str; 5] = ["end", "bold", "italic", "underline", "overline"];
+
+/// Prefixes of color and theme style placeholders that are not arguments.
+const STYLE_PREFIXES: [&str; 3] = ["color.", "back.", "style."];
+
+/// Returns whether a placeholder name is a style, like `bold`, `color.red` or `style.error`.
+fn is_style(name: &str) -> bool {
+    return STYLES.contains(&name)
+        || STYLE_PREFIXES
+            .iter()
+            .any(|prefix: &&str| name.starts_with(prefix));
+}
+
+/// Returns the placeholder and argument names used in a message.
+///
+/// Both `{{name}}` placeholders and ICU MessageFormat arguments like `{name}` or
+/// `{name, plural, ...}` are detected. Style placeholders are skipped.
+fn placeholders(message: &str) -> Vec<String> {
+    let characters: Vec<char> = message.chars().collect();
+    let mut names: Vec<String> = vec![];
+    let mut index: usize = 0;
+
+    while index < characters.len() {
+        if characters[index] != '{' {
+            index += 1;
+            continue;
+        }
+
+        let mut start: usize = index + 1;
+        while characters.get(start) == Some(&'{') {
+            start += 1;
+        }
+        let mut end: usize = start;
+        while characters.get(end).is_some_and(|character: &char| {
+            character.is_alphanumeric() || "_.-".contains(*character)
+        }) {
+            end += 1;
+        }
+        let mut next: usize = end;
+        while characters.get(next) == Some(&' ') {
+            next += 1;
+        }
+
+        if end > start && matches!(characters.get(next), Some('}' | ',')) {
+            let name: String = characters[start..end].iter().collect();
+            if !is_style(&name) {
+                names.push(name);
+            }
+        }
+        index = start.max(index + 1);
+    }
+
+    return names;
+}
+
+
+///////////////
+// EXPANSION //
+///////////////
+
+/// Expands the `checked_t!` macro.
+///
+/// # Parameters
+///
+/// - `input`: The parsed input of the macro.
+///
+/// # Returns
+///
+/// The expanded tokens or a compile error.
+pub(crate) fn expand(input: Input) -> TokenStream {
+    return match check(&input) {
+        Ok(path) => {
+            let config: &syn::Expr = &input.config;
+            let key: &syn::LitStr = &input.key;
+            let mut count: Option<&syn::Expr> = None;
+            let mut names: Vec<&syn::LitStr> = vec![];
+            let mut values: Vec<&syn::Expr> = vec![];
+
+            for argument in &input.arguments {
+                match argument {
+                    Argument::Count(value) => count = Some(value),
+                    Argument::Named(name, value) => {
+                        names.push(name);
+                        values.push(value);
+                    }
+                }
+            }
+
+            let arguments: TokenStream = quote! {
+                ::localizer_rs::arguments::Args::new()
+                    #(.with(#names, (&::localizer_rs::arguments::__private::Wrap(&#values)).value()))*
+            };
+            let translation: TokenStream = match count {
+                Some(count) => quote! {
+                    #config.translate_plural(#key, #count, #arguments)
+                },
+                None => quote! {
+                    #config.t(#key, #arguments)
+                },
+            };
+
+            quote! {
+                {
+                    #[allow(unused_imports)]
+                    use ::localizer_rs::arguments::__private::{ViaDisplay, ViaInto};
+
+                    const _: &[u8] = ::std::include_bytes!(#path);
+                    #translation
+                }
+            }
+        }
+        Err(_error) => _error.to_compile_error(),
+    };
+}
+
+/// Checks the key and the argument names against the reference translation file.
+///
+/// # Parameters
+///
+/// - `input`: The parsed input of the macro.
+///
+/// # Returns
+///
+/// A `Result` containing the absolute path of the reference file or the compile error.
+fn check(input: &Input) -> syn::Result<String> {
+    let reference: &syn::LitStr = &input.reference;
+    let path: PathBuf = crate::manifest_path(&reference.value());
+    let error = |message: String| syn::Error::new(reference.span(), message);
+
+    let content: String = match fs::read_to_string(&path) {
+        Ok(value) => value,
+        Err(_error) => {
+            return Err(error(format!(
+                "Could not read the reference file {:?}: {}",
+                path, _error
+            )));
+        }
+    };
+    let catalog: HashMap<String, serde_json::Value> = match serde_json::from_str(&content) {
+        Ok(value) => value,
+        Err(_error) => {
+            return Err(error(format!(
+                "Could not parse the reference file {:?}: {}",
+                path, _error
+            )));
+        }
+    };
+
+    let key: String = input.key.value();
+    let messages: Vec<&str> = match localizer_rs_shared::resolve(&catalog, &key)
+        .ok()
+        .and_then(messages)
+    {
+        Some(value) => value,
+        None => {
+            let object: serde_json::Map<String, serde_json::Value> =
+                catalog.clone().into_iter().collect();
+            let suggestion: Option<String> = all_keys("", &object)
+                .into_iter()
+                .map(|candidate: String| (distance(&key, &candidate), candidate))
+                .filter(|(distance, _candidate)| *distance <= (key.chars().count() / 3).max(1))
+                .min()
+                .map(|(_distance, candidate)| candidate);
+
+            return Err(syn::Error::new(
+                input.key.span(),
+                match suggestion {
+                    Some(suggestion) => format!(
+                        "Could not find translation key {:?}, did you mean {:?}?",
+                        key, suggestion
+                    ),
+                    None => format!("Could not find translation key {:?}", key),
+                },
+            ));
+        }
+    };
+
+    let available: Vec<String> = messages
+        .iter()
+        .flat_map(|message| placeholders(message))
+        .collect();
+    for argument in &input.arguments {
+        if let Argument::Named(name, _value) = argument {
+            if !available.contains(&name.value()) {
+                return Err(syn::Error::new(
+                    name.span(),
+                    format!(
+                        "Translation {:?} does not contain the placeholder {:?}",
+                        key,
+                        name.value()
+                    ),
+                ));
+            }
+        }
+    }
+
+    return match path.to_str() {
+        Some(value) => Ok(value.to_owned()),
+        None => Err(error(format!("The path {:?} is not valid unicode", path))),
+    };
+}
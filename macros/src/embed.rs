@@ -0,0 +1,145 @@
+// localizer-rs macros embed
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+
+///////////////
+// CONSTANTS //
+///////////////
+
+/// Extensions of the translation files supported by localizer-rs.
+const EXTENSIONS: [&str; 4] = ["json", "ftl", "po", "mo"];
+
+
+///////////////
+// FUNCTIONS //
+///////////////
+
+/// Returns the translation files of a directory.
+///
+/// # Parameters
+///
+/// - `directory`: The directory containing the translation files.
+///
+/// # Returns
+///
+/// A `Result` containing the languages, extensions and absolute paths of the translation files,
+/// sorted by their paths, or the description of the error.
+fn translation_files(directory: &Path) -> Result<Vec<(String, String, String)>, String> {
+    let entries: fs::ReadDir = match fs::read_dir(directory) {
+        Ok(value) => value,
+        Err(_error) => {
+            return Err(format!(
+                "Could not read the translation directory {:?}: {}",
+                directory, _error
+            ));
+        }
+    };
+    let mut files: Vec<(String, String, String)> = vec![];
+
+    for entry in entries {
+        let path: PathBuf = match entry {
+            Ok(value) => value.path(),
+            Err(_error) => return Err(format!("Could not read {:?}: {}", directory, _error)),
+        };
+        let extension: &str = match path.extension().and_then(|extension| extension.to_str()) {
+            Some(value) if path.is_file() && EXTENSIONS.contains(&value) => value,
+            _ => continue,
+        };
+        let language: &str = match path.file_stem().and_then(|stem| stem.to_str()) {
+            // The theme file is stored next to the translation files
+            Some("theme") | None => continue,
+            Some(value) => value,
+        };
+        let absolute: &str = match path.to_str() {
+            Some(value) => value,
+            None => return Err(format!("The path {:?} is not valid unicode", path)),
+        };
+
+        files.push((
+            language.to_owned(),
+            extension.to_owned(),
+            absolute.to_owned(),
+        ));
+    }
+    files.sort_by(|first, second| first.2.cmp(&second.2));
+
+    return Ok(files);
+}
+
+/// Expands the `embed!` macro.
+///
+/// # Parameters
+///
+/// - `literal`: The directory containing the translation files, relative to the manifest
+///   directory of the crate using the macro.
+///
+/// # Returns
+///
+/// The expanded tokens or a compile error.
+pub(crate) fn expand(literal: syn::LitStr) -> TokenStream {
+    let directory: PathBuf = crate::manifest_path(&literal.value());
+
+    let files: Vec<(String, String, String)> = match translation_files(&directory) {
+        Ok(value) => value,
+        Err(_error) => return syn::Error::new(literal.span(), _error).to_compile_error(),
+    };
+    let files = files.iter().map(|(language, extension, path)| {
+        return quote! {
+            ::localizer_rs::sources::EmbeddedFile {
+                language: #language,
+                extension: #extension,
+                content: ::std::include_bytes!(#path),
+            }
+        };
+    });
+
+    let theme_path: PathBuf = directory.join("theme.json");
+    let theme: TokenStream = match (theme_path.is_file(), theme_path.to_str()) {
+        (true, Some(path)) => quote! {
+            .with_theme(::std::include_bytes!(#path))
+        },
+        (true, None) => {
+            return syn::Error::new(
+                literal.span(),
+                format!("The path {:?} is not valid unicode", theme_path),
+            )
+            .to_compile_error();
+        }
+        (false, _path) => quote! {},
+    };
+
+    return quote! {
+        ::localizer_rs::sources::Embedded::new(&[#(#files),*])#theme
+    };
+}
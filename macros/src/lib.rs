@@ -0,0 +1,85 @@
+#![doc = "Procedural macros for [localizer-rs](https://docs.rs/localizer_rs/). Use the macros re-exported by `localizer_rs` instead of depending on this crate directly."]
+#![allow(clippy::needless_return)]
+// localizer-rs macros
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// MODULES //
+/////////////
+
+mod check;
+mod embed;
+
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::env;
+use std::path::Path;
+use std::path::PathBuf;
+
+use proc_macro::TokenStream;
+
+
+///////////////
+// FUNCTIONS //
+///////////////
+
+/// Returns the absolute path of a path relative to the manifest directory of the crate using the
+/// macro.
+///
+/// # Parameters
+///
+/// - `relative`: The relative path.
+///
+/// # Returns
+///
+/// The absolute path.
+pub(crate) fn manifest_path(relative: &str) -> PathBuf {
+    let manifest_directory: String = env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    return Path::new(&manifest_directory).join(relative);
+}
+
+
+////////////
+// MACROS //
+////////////
+
+/// Embeds the translation files of a directory into the binary.
+///
+/// See `localizer_rs::embed!()` for the documentation.
+#[proc_macro]
+pub fn embed(input: TokenStream) -> TokenStream {
+    let literal: syn::LitStr = syn::parse_macro_input!(input as syn::LitStr);
+    return embed::expand(literal).into();
+}
+
+/// Translates a key after checking it against a reference translation file at compile time.
+///
+/// See `localizer_rs::checked_t!()` for the documentation.
+#[proc_macro]
+pub fn checked_t(input: TokenStream) -> TokenStream {
+    let input: check::Input = syn::parse_macro_input!(input as check::Input);
+    return check::expand(input).into();
+}
@@ -0,0 +1,154 @@
+#![doc = "Key and plural form logic shared by [localizer-rs](https://docs.rs/localizer_rs/) and its macros. Use `localizer_rs` instead of depending on this crate directly."]
+#![allow(clippy::needless_return)]
+// localizer-rs shared
+// Version: 2.0.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::collections::HashMap;
+
+
+//////////
+// KEYS //
+//////////
+
+/// Splits a key into its segments.
+///
+/// Segments are separated by dots. A dot preceded by a backslash is part of the segment, as is a
+/// backslash preceded by another backslash.
+///
+/// # Parameters
+///
+/// - `key`: The key to split.
+///
+/// # Returns
+///
+/// A `Vec` containing the unescaped segments.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(localizer_rs_shared::split("menu.file\\.txt"), vec!["menu", "file.txt"]);
+/// ```
+pub fn split(key: &str) -> Vec<String> {
+    let mut segments: Vec<String> = vec![];
+    let mut segment: String = String::new();
+    let mut characters = key.chars();
+
+    while let Some(character) = characters.next() {
+        match character {
+            '\\' => match characters.next() {
+                Some(next @ ('.' | '\\')) => segment.push(next),
+                Some(next) => {
+                    segment.push(character);
+                    segment.push(next);
+                }
+                None => segment.push(character),
+            },
+            '.' => segments.push(std::mem::take(&mut segment)),
+            _ => segment.push(character),
+        }
+    }
+    segments.push(segment);
+
+    return segments;
+}
+
+/// Resolves a key in the messages of a catalog.
+///
+/// Keys present at the top level of the messages are returned directly, all other keys are split
+/// into their segments, which are looked up in the nested json objects.
+///
+/// # Parameters
+///
+/// - `messages`: The messages to search.
+/// - `key`: The key to resolve.
+///
+/// # Returns
+///
+/// A `Result` containing the json value of the key or the index of the first segment that could
+/// not be resolved.
+pub fn resolve<'a>(
+    messages: &'a HashMap<String, serde_json::Value>,
+    key: &str,
+) -> Result<&'a serde_json::Value, usize> {
+    if let Some(value) = messages.get(key) {
+        return Ok(value);
+    }
+
+    let segments: Vec<String> = split(key);
+    let mut value: &serde_json::Value = match messages.get(&segments[0]) {
+        Some(value) => value,
+        None => return Err(0),
+    };
+
+    for (index, segment) in segments.iter().enumerate().skip(1) {
+        value = match value.get(segment) {
+            Some(value) => value,
+            None => return Err(index),
+        };
+    }
+
+    return Ok(value);
+}
+
+
+/////////////
+// PLURALS //
+/////////////
+
+/// Names of the CLDR plural categories.
+pub const CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+/// Returns whether the json value contains plural forms.
+///
+/// Plural forms are json objects whose keys are all CLDR plural categories or exact matches like
+/// `=0`.
+///
+/// # Parameters
+///
+/// - `value`: The json value to check.
+///
+/// # Returns
+///
+/// `true` if the value is an object containing plural forms.
+///
+/// # Examples
+///
+/// ```rust
+/// assert!(localizer_rs_shared::is_plural(&serde_json::json!({"=0": "none", "other": "some"})));
+/// assert!(!localizer_rs_shared::is_plural(&serde_json::json!({"title": "Title"})));
+/// ```
+pub fn is_plural(value: &serde_json::Value) -> bool {
+    return match value.as_object() {
+        Some(forms) => {
+            !forms.is_empty()
+                && forms
+                    .keys()
+                    .all(|key: &String| CATEGORIES.contains(&key.as_str()) || key.starts_with('='))
+        }
+        None => false,
+    };
+}
@@ -0,0 +1,524 @@
+#![doc = include_str!("../.github/arguments.md")]
+// localizer-rs arguments
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::fmt;
+
+use crate::plurals;
+
+
+///////////
+// VALUE //
+///////////
+
+/// Value of a translation argument.
+///
+/// Values keep the type of the argument, so numbers can be used to select plural forms and
+/// formatted for the language of the translation. Use the `From` implementations to create values.
+///
+/// # Variants
+///
+/// - `Str`: A string.
+/// - `Int`: A signed integer.
+/// - `UInt`: An unsigned integer that does not fit into `Int`.
+/// - `Float`: A floating point number.
+/// - `Bool`: A boolean.
+/// - `List`: A list of values.
+/// - `Date`: A calendar date, requires the `chrono` feature.
+/// - `Time`: A time of day, requires the `chrono` feature.
+/// - `DateTime`: A date and time in the local time of its time zone, requires the `chrono` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// let value: localizer_rs::arguments::Value = localizer_rs::arguments::Value::from(3);
+///
+/// assert_eq!(value, localizer_rs::arguments::Value::Int(3));
+/// assert_eq!(value.to_string(), "3");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Value {
+    /// A string.
+    Str(String),
+    /// A signed integer.
+    Int(i64),
+    /// An unsigned integer that does not fit into `Int`.
+    UInt(u64),
+    /// A floating point number.
+    Float(f64),
+    /// A boolean.
+    Bool(bool),
+    /// A list of values.
+    List(Vec<Value>),
+    /// A calendar date.
+    #[cfg(feature = "chrono")]
+    Date(chrono::NaiveDate),
+    /// A time of day.
+    #[cfg(feature = "chrono")]
+    Time(chrono::NaiveTime),
+    /// A date and time in the local time of its time zone.
+    #[cfg(feature = "chrono")]
+    DateTime(chrono::NaiveDateTime),
+}
+
+impl Value {
+    /// Returns the plural operands of numeric values.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The value object.
+    ///
+    /// # Returns
+    ///
+    /// The operands of numbers and strings containing numbers, `None` for all other values.
+    pub(crate) fn operands(&self) -> Option<plurals::Operands> {
+        return match self {
+            Value::Str(value) => plurals::Operands::parse(value),
+            Value::Int(value) => Some(plurals::Operands::from(*value)),
+            Value::UInt(value) => Some(plurals::Operands::from(*value)),
+            Value::Float(value) => Some(plurals::Operands::from(*value)),
+            Value::Bool(_value) => None,
+            Value::List(_value) => None,
+            #[cfg(feature = "chrono")]
+            Value::Date(_value) => None,
+            #[cfg(feature = "chrono")]
+            Value::Time(_value) => None,
+            #[cfg(feature = "chrono")]
+            Value::DateTime(_value) => None,
+        };
+    }
+}
+
+/// Display implementation for the value object.
+impl fmt::Display for Value {
+    /// Format implementation for the value object.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The value object.
+    /// - `f`: The [`fmt::Formatter`] to use.
+    ///
+    /// # Returns
+    ///
+    /// A [`fmt::Result`] containing the formatted value.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Str(value) => write!(f, "{}", value),
+            Value::Int(value) => write!(f, "{}", value),
+            Value::UInt(value) => write!(f, "{}", value),
+            Value::Float(value) => write!(f, "{}", value),
+            Value::Bool(value) => write!(f, "{}", value),
+            Value::List(values) => {
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                Ok(())
+            }
+            #[cfg(feature = "chrono")]
+            Value::Date(value) => write!(f, "{}", value),
+            #[cfg(feature = "chrono")]
+            Value::Time(value) => write!(f, "{}", value),
+            #[cfg(feature = "chrono")]
+            Value::DateTime(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+macro_rules! value_from {
+    ($variant:ident, $target:ty, $($type:ty),*) => {
+        $(
+            impl From<$type> for Value {
+                fn from(value: $type) -> Value {
+                    return Value::$variant(value as $target);
+                }
+            }
+        )*
+    };
+}
+
+value_from!(Int, i64, i8, i16, i32, i64, isize, u8, u16, u32);
+value_from!(Float, f64, f32, f64);
+
+macro_rules! value_from_unsigned {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for Value {
+                fn from(value: $type) -> Value {
+                    return match i64::try_from(value) {
+                        Ok(value) => Value::Int(value),
+                        Err(_error) => Value::UInt(value as u64),
+                    };
+                }
+            }
+        )*
+    };
+}
+
+value_from_unsigned!(u64, usize);
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Value {
+        return Value::Bool(value);
+    }
+}
+
+impl From<char> for Value {
+    fn from(value: char) -> Value {
+        return Value::Str(value.to_string());
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Value {
+        return Value::Str(value.to_owned());
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Value {
+        return Value::Str(value);
+    }
+}
+
+impl From<&String> for Value {
+    fn from(value: &String) -> Value {
+        return Value::Str(value.to_owned());
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(values: Vec<T>) -> Value {
+        return Value::List(values.into_iter().map(Into::into).collect());
+    }
+}
+
+impl<T: Into<Value>, const N: usize> From<[T; N]> for Value {
+    fn from(values: [T; N]) -> Value {
+        return Value::List(values.into_iter().map(Into::into).collect());
+    }
+}
+
+impl<T: Clone + Into<Value>> From<&[T]> for Value {
+    fn from(values: &[T]) -> Value {
+        return Value::List(values.iter().cloned().map(Into::into).collect());
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDate> for Value {
+    fn from(value: chrono::NaiveDate) -> Value {
+        return Value::Date(value);
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveTime> for Value {
+    fn from(value: chrono::NaiveTime) -> Value {
+        return Value::Time(value);
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDateTime> for Value {
+    fn from(value: chrono::NaiveDateTime) -> Value {
+        return Value::DateTime(value);
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for Value {
+    fn from(value: chrono::DateTime<Tz>) -> Value {
+        return Value::DateTime(value.naive_local());
+    }
+}
+
+
+///////////////
+// ARGUMENTS //
+///////////////
+
+/// Arguments of a translation.
+///
+/// Arguments are created using the [`Args::with()`] builder or converted from the `Vec` of
+/// string pairs taken by earlier versions. Setting an argument twice replaces its value.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// # let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+/// let arguments: localizer_rs::arguments::Args = localizer_rs::arguments::Args::new()
+///     .with("balance", 10.5)
+///     .with("user", "John Doe");
+///
+/// assert_eq!(
+///     config.t("success", arguments),
+///     config.t("success", vec![("balance", "10.5"), ("user", "John Doe")])
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Args {
+    /// The names and values of the arguments, in order of insertion.
+    values: Vec<(String, Value)>,
+}
+
+impl Args {
+    /// Creates a new empty arguments object.
+    ///
+    /// # Returns
+    ///
+    /// A new `Args` object without arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// assert!(localizer_rs::arguments::Args::new().is_empty());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Args::with()`]
+    pub fn new() -> Args {
+        return Args { values: vec![] };
+    }
+
+    /// Adds an argument and returns the arguments object.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The arguments object.
+    /// - `name`: The name of the argument.
+    /// - `value`: The value of the argument.
+    ///
+    /// # Returns
+    ///
+    /// The `Args` object containing the argument.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// let arguments: localizer_rs::arguments::Args =
+    ///     localizer_rs::arguments::Args::new().with("count", 3);
+    ///
+    /// assert_eq!(arguments.get("count"), Some(&localizer_rs::arguments::Value::Int(3)));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Args::set()`]
+    pub fn with<V: Into<Value>>(mut self, name: &str, value: V) -> Args {
+        self.set(name, value);
+        return self;
+    }
+
+    /// Sets an argument.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The arguments object.
+    /// - `name`: The name of the argument.
+    /// - `value`: The value of the argument.
+    ///
+    /// # Returns
+    ///
+    /// The `Args` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// let mut arguments: localizer_rs::arguments::Args = localizer_rs::arguments::Args::new();
+    /// arguments.set("user", "John Doe");
+    /// arguments.set("user", "Jane Doe");
+    ///
+    /// assert_eq!(arguments.len(), 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Args::with()`]
+    pub fn set<V: Into<Value>>(&mut self, name: &str, value: V) -> &Args {
+        let value: Value = value.into();
+
+        match self
+            .values
+            .iter_mut()
+            .find(|(existing, _value)| existing == name)
+        {
+            Some((_name, existing)) => *existing = value,
+            None => self.values.push((name.to_owned(), value)),
+        }
+
+        return self;
+    }
+
+    /// Returns the value of an argument.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The arguments object.
+    /// - `name`: The name of the argument.
+    ///
+    /// # Returns
+    ///
+    /// The value of the argument or `None` if it is not set.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        return self
+            .values
+            .iter()
+            .find(|(existing, _value)| existing == name)
+            .map(|(_name, value)| value);
+    }
+
+    /// Returns the arguments in order of insertion.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The arguments object.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the names and values of the arguments.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Value)> {
+        return self
+            .values
+            .iter()
+            .map(|(name, value)| (name.as_str(), value));
+    }
+
+    /// Returns the number of arguments.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The arguments object.
+    ///
+    /// # Returns
+    ///
+    /// The number of arguments.
+    pub fn len(&self) -> usize {
+        return self.values.len();
+    }
+
+    /// Returns whether there are no arguments.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The arguments object.
+    ///
+    /// # Returns
+    ///
+    /// `true` if no arguments are set.
+    pub fn is_empty(&self) -> bool {
+        return self.values.is_empty();
+    }
+}
+
+/// Converts the arguments taken by earlier versions.
+///
+/// Only the first value of an argument is kept, like earlier versions did.
+impl From<Vec<(&str, &str)>> for Args {
+    fn from(values: Vec<(&str, &str)>) -> Args {
+        let mut arguments: Args = Args::new();
+
+        for (name, value) in values {
+            if arguments.get(name).is_none() {
+                arguments.set(name, value);
+            }
+        }
+
+        return arguments;
+    }
+}
+
+impl<V: Into<Value>, const N: usize> From<[(&str, V); N]> for Args {
+    fn from(values: [(&str, V); N]) -> Args {
+        return values.into_iter().collect();
+    }
+}
+
+impl From<&Args> for Args {
+    fn from(arguments: &Args) -> Args {
+        return arguments.clone();
+    }
+}
+
+impl<'a, V: Into<Value>> FromIterator<(&'a str, V)> for Args {
+    fn from_iter<I: IntoIterator<Item = (&'a str, V)>>(values: I) -> Args {
+        let mut arguments: Args = Args::new();
+
+        for (name, value) in values {
+            arguments.set(name, value);
+        }
+
+        return arguments;
+    }
+}
+
+
+/////////////
+// PRIVATE //
+/////////////
+
+/// Helpers of the `t!` and `checked_t!` macros. Not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    use std::fmt;
+
+    use super::Value;
+
+    /// Wrapper selecting how a macro argument is converted into a value.
+    pub struct Wrap<'a, T: ?Sized>(pub &'a T);
+
+    /// Converts arguments implementing `Into<Value>`, keeping their type.
+    pub trait ViaInto {
+        fn value(&self) -> Value;
+    }
+
+    impl<T: Clone + Into<Value>> ViaInto for Wrap<'_, T> {
+        fn value(&self) -> Value {
+            return self.0.clone().into();
+        }
+    }
+
+    /// Converts all other arguments implementing `Display` into strings.
+    pub trait ViaDisplay {
+        fn value(&self) -> Value;
+    }
+
+    impl<T: fmt::Display + ?Sized> ViaDisplay for &Wrap<'_, T> {
+        fn value(&self) -> Value {
+            return Value::Str(self.0.to_string());
+        }
+    }
+}
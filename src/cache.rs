@@ -0,0 +1,152 @@
+// localizer-rs cache
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use crate::sources::Catalog;
+
+
+///////////
+// CACHE //
+///////////
+
+/// Thread safe cache of parsed catalogs, indexed by the translation path and the language.
+///
+/// Including the path in the key keeps catalogs of another directory from being served after the
+/// public `path` field of the config object is changed directly.
+///
+/// The cache is an implementation detail of [`crate::Config`] and therefore does not take part in
+/// comparisons: two caches always compare as equal.
+#[derive(Default)]
+pub(crate) struct Cache {
+    /// The cached catalogs, indexed by the path and the language.
+    catalogs: RwLock<HashMap<(String, String), Arc<Catalog>>>,
+}
+
+impl Cache {
+    /// Returns the cached catalog for the specified path and language.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The cache object.
+    /// - `path`: The translation path the catalog was loaded for.
+    /// - `language`: The language of the catalog.
+    ///
+    /// # Returns
+    ///
+    /// The cached catalog or `None` if the language is not cached yet.
+    pub(crate) fn get(&self, path: &str, language: &str) -> Option<Arc<Catalog>> {
+        let catalogs = match self.catalogs.read() {
+            Ok(value) => value,
+            Err(_error) => _error.into_inner(),
+        };
+
+        return catalogs
+            .get(&(path.to_owned(), language.to_owned()))
+            .cloned();
+    }
+
+    /// Stores the catalog for the specified path and language.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The cache object.
+    /// - `path`: The translation path the catalog was loaded for.
+    /// - `language`: The language of the catalog.
+    /// - `catalog`: The catalog to store.
+    ///
+    /// # Returns
+    ///
+    /// The stored catalog.
+    pub(crate) fn insert(&self, path: &str, language: &str, catalog: Catalog) -> Arc<Catalog> {
+        let catalog: Arc<Catalog> = Arc::new(catalog);
+        let mut catalogs = match self.catalogs.write() {
+            Ok(value) => value,
+            Err(_error) => _error.into_inner(),
+        };
+        catalogs.insert((path.to_owned(), language.to_owned()), Arc::clone(&catalog));
+
+        return catalog;
+    }
+
+    /// Removes all cached catalogs.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The cache object.
+    pub(crate) fn clear(&self) {
+        let mut catalogs = match self.catalogs.write() {
+            Ok(value) => value,
+            Err(_error) => _error.into_inner(),
+        };
+        catalogs.clear();
+    }
+}
+
+/// Clone implementation for the cache object.
+///
+/// The clone shares the already parsed catalogs but is filled independently afterwards.
+impl Clone for Cache {
+    fn clone(&self) -> Cache {
+        let catalogs = match self.catalogs.read() {
+            Ok(value) => value,
+            Err(_error) => _error.into_inner(),
+        };
+
+        return Cache {
+            catalogs: RwLock::new(catalogs.clone()),
+        };
+    }
+}
+
+/// Debug implementation for the cache object.
+impl fmt::Debug for Cache {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let catalogs = match self.catalogs.read() {
+            Ok(value) => value,
+            Err(_error) => _error.into_inner(),
+        };
+        let mut languages: Vec<&String> =
+            catalogs.keys().map(|(_path, language)| language).collect();
+        languages.sort();
+
+        f.debug_struct("Cache")
+            .field("languages", &languages)
+            .finish()
+    }
+}
+
+impl PartialEq for Cache {
+    fn eq(&self, _other: &Cache) -> bool {
+        return true;
+    }
+}
+
+impl Eq for Cache {}
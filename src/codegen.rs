@@ -0,0 +1,527 @@
+#![doc = include_str!("../.github/codegen.md")]
+// localizer-rs codegen
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::collections::BTreeMap;
+use std::fs;
+
+use crate::errors;
+use crate::keys;
+use crate::message_format;
+use crate::plurals;
+use crate::sources;
+use crate::sources::CatalogSource;
+use crate::styles;
+
+
+///////////////
+// CONSTANTS //
+///////////////
+
+/// Keywords that can not be used as identifiers.
+const KEYWORDS: [&str; 50] = [
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "union", "unsafe", "unsized", "use", "virtual", "where",
+];
+
+
+////////////
+// MODULE //
+////////////
+
+/// Accessor function generated for a translation.
+#[derive(Debug, Clone, PartialEq)]
+struct Function {
+    /// The key of the translation.
+    key: String,
+    /// Whether the translation has plural forms.
+    plural: bool,
+    /// The names of the arguments of the translation.
+    arguments: Vec<String>,
+    /// The translation in the reference language.
+    message: String,
+}
+
+/// Rust module generated for the translations sharing a key prefix.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Module {
+    /// The accessor functions of the module.
+    functions: BTreeMap<String, Function>,
+    /// The nested modules.
+    modules: BTreeMap<String, Module>,
+}
+
+impl Module {
+    /// Adds the accessor functions of a translation or a nested json object.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The module object.
+    /// - `segments`: The segments of the key, relative to the module.
+    /// - `key`: The escaped key of the translation.
+    /// - `value`: The json value of the translation.
+    fn insert(&mut self, segments: &[String], key: String, value: &serde_json::Value) {
+        if segments.len() > 1 {
+            return self
+                .modules
+                .entry(identifier(&segments[0]))
+                .or_default()
+                .insert(&segments[1..], key, value);
+        }
+
+        let messages: Vec<&str> = match value {
+            serde_json::Value::String(message) => vec![message],
+            serde_json::Value::Array(forms) => {
+                forms.iter().filter_map(|form| form.as_str()).collect()
+            }
+            serde_json::Value::Object(forms) if plurals::is_plural(value) => {
+                forms.values().filter_map(|form| form.as_str()).collect()
+            }
+            serde_json::Value::Object(nested) => {
+                let module: &mut Module = self.modules.entry(identifier(&segments[0])).or_default();
+                for (name, nested_value) in nested {
+                    module.insert(
+                        &[name.to_owned()],
+                        format!("{}.{}", key, escape(name)),
+                        nested_value,
+                    );
+                }
+                return;
+            }
+            _ => return,
+        };
+        let plural: bool = !value.is_string();
+
+        let mut arguments: Vec<String> = vec![];
+        for message in &messages {
+            for name in argument_names(message) {
+                let count: bool = plural && name == "count";
+                if !count && !arguments.contains(&name) {
+                    arguments.push(name);
+                }
+            }
+        }
+
+        let message: &str = match value.get("other").and_then(serde_json::Value::as_str) {
+            Some(other) => other,
+            None => messages.first().copied().unwrap_or_default(),
+        };
+
+        // Every function reserves its own name and the name of its `try_` variant
+        let taken = |name: &str| -> bool {
+            return self.functions.keys().any(|other: &String| {
+                other == name
+                    || format!("try_{}", other) == name
+                    || format!("try_{}", name) == *other
+            });
+        };
+        let mut name: String = identifier(&segments[0]);
+        while taken(&name) {
+            name.push('_');
+        }
+        self.functions.insert(
+            name,
+            Function {
+                key,
+                plural,
+                arguments,
+                message: message.to_owned(),
+            },
+        );
+    }
+
+    /// Writes the source code of the module contents.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The module object.
+    /// - `indentation`: The indentation of the module contents.
+    /// - `output`: The string to write to.
+    fn write(&self, indentation: &str, output: &mut String) {
+        for (name, function) in &self.functions {
+            function.write(name, indentation, output);
+        }
+
+        for (name, module) in &self.modules {
+            output.push_str(&format!("{}pub mod {} {{\n", indentation, name));
+            let mut contents: String = String::new();
+            module.write(&format!("{}    ", indentation), &mut contents);
+            output.push_str(contents.trim_end());
+            output.push_str(&format!("\n{}}}\n\n", indentation));
+        }
+    }
+}
+
+impl Function {
+    /// Writes the source code of the accessor function and its `try_` variant.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The function object.
+    /// - `name`: The name of the function.
+    /// - `indentation`: The indentation of the function.
+    /// - `output`: The string to write to.
+    fn write(&self, name: &str, indentation: &str, output: &mut String) {
+        let mut parameters: String = "config: &::localizer_rs::Config".to_owned();
+        let mut generics: &str = "";
+        let mut arguments: Vec<String> = vec![];
+        let mut identifiers: Vec<String> = vec!["config".to_owned()];
+
+        if self.plural {
+            generics = "<C: ::std::convert::Into<::localizer_rs::plurals::Operands>>";
+            parameters.push_str(", count: C");
+            identifiers.push("count".to_owned());
+        }
+
+        for argument in &self.arguments {
+            let mut parameter: String = identifier(argument);
+            while identifiers.contains(&parameter) {
+                parameter.push('_');
+            }
+            parameters.push_str(&format!(
+                ", {}: impl ::std::convert::Into<::localizer_rs::arguments::Value>",
+                parameter
+            ));
+            arguments.push(format!(".with({:?}, {})", argument, parameter));
+            identifiers.push(parameter);
+        }
+
+        let (method, count): (&str, &str) = match self.plural {
+            true => ("translate_plural", ", count"),
+            false => ("t", ""),
+        };
+        let arguments: String = format!(
+            "::localizer_rs::arguments::Args::new(){}",
+            arguments.concat()
+        );
+
+        for (prefix, result, summary) in [
+            ("", "::std::string::String", "Translates"),
+            (
+                "try_",
+                "::std::result::Result<::std::string::String, ::localizer_rs::errors::Error>",
+                "Tries to translate",
+            ),
+        ] {
+            let documentation: String = format!(
+                "{} the {:?} key.\n\nReference translation: {}",
+                summary, self.key, self.message
+            );
+            output.push_str(&format!(
+                "{indentation}#[doc = {documentation:?}]\n\
+                 {indentation}pub fn {prefix}{name}{generics}({parameters}) -> {result} {{\n\
+                 {indentation}    config.{prefix}{method}({key:?}{count}, {arguments})\n\
+                 {indentation}}}\n\n",
+                key = self.key,
+            ));
+        }
+    }
+}
+
+
+///////////////
+// FUNCTIONS //
+///////////////
+
+/// Escapes the dots and backslashes of a key segment.
+///
+/// # Parameters
+///
+/// - `segment`: The segment to escape.
+///
+/// # Returns
+///
+/// The escaped segment.
+fn escape(segment: &str) -> String {
+    return segment.replace('\\', "\\\\").replace('.', "\\.");
+}
+
+/// Converts a key segment or an argument name into a Rust identifier.
+///
+/// # Parameters
+///
+/// - `name`: The name to convert.
+///
+/// # Returns
+///
+/// The name in snake case, with all other characters replaced by underscores.
+fn identifier(name: &str) -> String {
+    let mut result: String = String::new();
+
+    for (index, character) in name.chars().enumerate() {
+        if character.is_ascii_uppercase() && index > 0 && !result.ends_with('_') {
+            result.push('_');
+        }
+        match character.is_ascii_alphanumeric() {
+            true => result.push(character.to_ascii_lowercase()),
+            false => result.push('_'),
+        }
+    }
+
+    if result.is_empty() || result.starts_with(|character: char| character.is_ascii_digit()) {
+        result.insert(0, '_');
+    }
+    if result == "_" || KEYWORDS.contains(&result.as_str()) {
+        result.push('_');
+    }
+
+    return result;
+}
+
+/// Returns the names of the arguments used in a message.
+///
+/// The names of `{{name}}` placeholders are returned, except for the formatting placeholders.
+/// Messages that are valid ICU MessageFormat messages also return the names of their arguments.
+///
+/// # Parameters
+///
+/// - `message`: The message to search.
+///
+/// # Returns
+///
+/// The argument names in order of their first occurrence.
+fn argument_names(message: &str) -> Vec<String> {
+    let mut names: Vec<String> = vec![];
+
+    match message_format::parse(message) {
+        Ok(parts) => collect(&parts, &mut names),
+        Err(_error) => {
+            let mut rest: &str = message;
+            while let Some(start) = rest.find("{{") {
+                rest = &rest[start + 2..];
+                if let Some(end) = rest.find("}}") {
+                    names.push(rest[..end].to_owned());
+                    rest = &rest[end + 2..];
+                }
+            }
+        }
+    }
+
+    let mut result: Vec<String> = vec![];
+    for name in names {
+        if styles::is_style(&name) {
+            continue;
+        }
+        // Placeholders like `{{balance, number}}` specify the style after the name
+        let name: String = name.split(',').next().unwrap_or_default().trim().to_owned();
+        if !result.contains(&name) {
+            result.push(name);
+        }
+    }
+
+    return result;
+}
+
+/// Collects the argument names of parsed message parts.
+///
+/// # Parameters
+///
+/// - `parts`: The parsed message.
+/// - `names`: The names to add to.
+fn collect(parts: &[message_format::Part], names: &mut Vec<String>) {
+    for part in parts {
+        match part {
+            message_format::Part::Placeholder(name)
+            | message_format::Part::Argument { name, .. } => {
+                names.push(name.to_owned());
+            }
+            message_format::Part::Plural { name, arms, .. }
+            | message_format::Part::Select { name, arms } => {
+                names.push(name.to_owned());
+                for (_selector, arm) in arms {
+                    collect(arm, names);
+                }
+            }
+            message_format::Part::Text(_) | message_format::Part::Pound => {}
+        }
+    }
+}
+
+
+////////////////
+// GENERATION //
+////////////////
+
+/// Generates the source code of the typed accessor functions for a translation directory.
+///
+/// # Parameters
+///
+/// - `path`: The directory containing the translation files.
+/// - `language`: The reference language, whose translation file defines the generated functions.
+///
+/// # Returns
+///
+/// A `String` containing the generated source code.
+///
+/// # Raises
+///
+/// This function throws an exception and exits if the translation file could not be loaded.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// let source: String = localizer_rs::codegen::source("examples/translations", "en");
+///
+/// assert!(source.contains("pub fn success(config: &::localizer_rs::Config, balance: impl"));
+/// ```
+///
+/// # See also
+///
+/// - [`try_source()`]
+/// - [`generate()`]
+pub fn source(path: &str, language: &str) -> String {
+    return match try_source(path, language) {
+        Ok(value) => value,
+        Err(_error) => _error.raise(),
+    };
+}
+
+/// Generates the source code of the typed accessor functions for a translation directory.
+///
+/// # Parameters
+///
+/// - `path`: The directory containing the translation files.
+/// - `language`: The reference language, whose translation file defines the generated functions.
+///
+/// # Returns
+///
+/// A `Result` containing the generated source code or the error that occurred while loading the
+/// translation file.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// let source: String = localizer_rs::codegen::try_source("examples/translations", "en").unwrap();
+///
+/// assert!(source.contains("pub fn files<C: ::std::convert::Into<::localizer_rs::plurals::Operands>>"));
+/// ```
+///
+/// # See also
+///
+/// - [`source()`]
+/// - [`try_generate()`]
+pub fn try_source(path: &str, language: &str) -> Result<String, errors::Error> {
+    let catalog: sources::Catalog = sources::Files::new(path).load(language)?;
+    let mut module: Module = Module::default();
+
+    // Keys are inserted in order, so renamed functions do not depend on the order of the catalog
+    let mut messages: Vec<(&String, &serde_json::Value)> = catalog.messages().iter().collect();
+    messages.sort_by(|first, second| first.0.cmp(second.0));
+    for (key, value) in messages {
+        module.insert(&keys::split(key), escape(key), value);
+    }
+
+    let mut output: String = format!(
+        "// Generated by localizer-rs from the {:?} translations in {:?}. Do not edit.\n\n",
+        language, path
+    );
+    module.write("", &mut output);
+
+    return Ok(output.trim_end().to_owned() + "\n");
+}
+
+/// Generates the typed accessor functions for a translation directory and writes them to a file.
+///
+/// Meant to be called from a build script. Tells cargo to run the build script again if the
+/// translation directory changes.
+///
+/// # Parameters
+///
+/// - `path`: The directory containing the translation files.
+/// - `language`: The reference language, whose translation file defines the generated functions.
+/// - `output`: The file to write the generated source code to.
+///
+/// # Raises
+///
+/// This function throws an exception and exits if the translation file could not be loaded or
+/// the generated file could not be written.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use localizer_rs;
+/// // build.rs
+/// let output: String = format!("{}/translations.rs", std::env::var("OUT_DIR").unwrap());
+/// localizer_rs::codegen::generate("translations", "en", &output);
+/// ```
+///
+/// # See also
+///
+/// - [`try_generate()`]
+/// - [`source()`]
+pub fn generate(path: &str, language: &str, output: &str) {
+    if let Err(_error) = try_generate(path, language, output) {
+        _error.raise();
+    }
+}
+
+/// Generates the typed accessor functions for a translation directory and writes them to a file.
+///
+/// Meant to be called from a build script. Tells cargo to run the build script again if the
+/// translation directory changes.
+///
+/// # Parameters
+///
+/// - `path`: The directory containing the translation files.
+/// - `language`: The reference language, whose translation file defines the generated functions.
+/// - `output`: The file to write the generated source code to.
+///
+/// # Returns
+///
+/// A `Result` containing nothing or the error that occurred.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use localizer_rs;
+/// // build.rs
+/// let output: String = format!("{}/translations.rs", std::env::var("OUT_DIR").unwrap());
+/// localizer_rs::codegen::try_generate("translations", "en", &output).unwrap();
+/// ```
+///
+/// # See also
+///
+/// - [`generate()`]
+/// - [`try_source()`]
+pub fn try_generate(path: &str, language: &str, output: &str) -> Result<(), errors::Error> {
+    let source: String = try_source(path, language)?;
+
+    if let Err(_error) = fs::write(output, source) {
+        return Err(errors::Error::FileUnwritable {
+            path: output.to_owned(),
+            source: _error,
+        });
+    }
+    println!("cargo:rerun-if-changed={}", path);
+
+    return Ok(());
+}
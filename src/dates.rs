@@ -0,0 +1,890 @@
+#![doc = include_str!("../.github/dates.md")]
+// localizer-rs dates
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use chrono::{Datelike, Timelike};
+
+use crate::arguments;
+use crate::plurals;
+
+
+///////////
+// NAMES //
+///////////
+
+/// Month and day names and date patterns of a locale.
+///
+/// Patterns use the CLDR pattern syntax and are ordered from the shortest to the longest style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Names {
+    /// The language tag of the locale.
+    pub(crate) language: &'static str,
+    /// The names of the months, in the form used inside of dates.
+    pub(crate) months: [&'static str; 12],
+    /// The abbreviated names of the months.
+    pub(crate) months_abbreviated: [&'static str; 12],
+    /// The names of the weekdays, starting with sunday.
+    pub(crate) weekdays: [&'static str; 7],
+    /// The abbreviated names of the weekdays, starting with sunday.
+    pub(crate) weekdays_abbreviated: [&'static str; 7],
+    /// The names of the periods of the 12 hour clock.
+    pub(crate) periods: [&'static str; 2],
+    /// The patterns of dates.
+    pub(crate) dates: [&'static str; 4],
+    /// The patterns of times.
+    pub(crate) times: [&'static str; 4],
+    /// The patterns combining a date `{1}` and a time `{0}`.
+    pub(crate) date_times: [&'static str; 4],
+}
+
+/// Creates the names of a locale, using the 24 hour clock and separating dates and times by a
+/// comma.
+const fn names(
+    language: &'static str,
+    months: [&'static str; 12],
+    months_abbreviated: [&'static str; 12],
+    weekdays: [&'static str; 7],
+    weekdays_abbreviated: [&'static str; 7],
+    dates: [&'static str; 4],
+) -> Names {
+    return Names {
+        language,
+        months,
+        months_abbreviated,
+        weekdays,
+        weekdays_abbreviated,
+        periods: ["AM", "PM"],
+        dates,
+        times: ["HH:mm", "HH:mm:ss", "HH:mm:ss", "HH:mm:ss"],
+        date_times: ["{1}, {0}", "{1}, {0}", "{1}, {0}", "{1}, {0}"],
+    };
+}
+
+impl Names {
+    /// Sets the patterns of times.
+    const fn times(mut self, times: [&'static str; 4]) -> Names {
+        self.times = times;
+        return self;
+    }
+
+    /// Sets the patterns combining dates and times.
+    const fn date_times(mut self, date_times: [&'static str; 4]) -> Names {
+        self.date_times = date_times;
+        return self;
+    }
+
+    /// Sets the names of the periods of the 12 hour clock.
+    const fn periods(mut self, am: &'static str, pm: &'static str) -> Names {
+        self.periods = [am, pm];
+        return self;
+    }
+}
+
+/// Names and patterns of the bundled locales, taken from the CLDR.
+///
+/// Time zones are left out of the patterns, as values are formatted in their local time.
+const NAMES: [Names; 13] = [
+    names(
+        "en",
+        [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ],
+        [
+            "Sunday",
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+        ],
+        ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+        ["M/d/yy", "MMM d, y", "MMMM d, y", "EEEE, MMMM d, y"],
+    )
+    .times(["h:mm a", "h:mm:ss a", "h:mm:ss a", "h:mm:ss a"])
+    .date_times(["{1}, {0}", "{1}, {0}", "{1} 'at' {0}", "{1} 'at' {0}"]),
+    names(
+        "en-GB",
+        [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec",
+        ],
+        [
+            "Sunday",
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+        ],
+        ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+        ["dd/MM/y", "d MMM y", "d MMMM y", "EEEE d MMMM y"],
+    )
+    .periods("am", "pm")
+    .date_times(["{1}, {0}", "{1}, {0}", "{1} 'at' {0}", "{1} 'at' {0}"]),
+    names(
+        "de",
+        [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        [
+            "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+            "Dez.",
+        ],
+        [
+            "Sonntag",
+            "Montag",
+            "Dienstag",
+            "Mittwoch",
+            "Donnerstag",
+            "Freitag",
+            "Samstag",
+        ],
+        ["So.", "Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa."],
+        ["dd.MM.yy", "dd.MM.y", "d. MMMM y", "EEEE, d. MMMM y"],
+    )
+    .date_times(["{1}, {0}", "{1}, {0}", "{1} 'um' {0}", "{1} 'um' {0}"]),
+    names(
+        "fr",
+        [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        [
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+            "nov.", "déc.",
+        ],
+        [
+            "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+        ],
+        ["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."],
+        ["dd/MM/y", "d MMM y", "d MMMM y", "EEEE d MMMM y"],
+    )
+    .date_times(["{1} {0}", "{1}, {0}", "{1} 'à' {0}", "{1} 'à' {0}"]),
+    names(
+        "es",
+        [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        [
+            "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+        ],
+        [
+            "domingo",
+            "lunes",
+            "martes",
+            "miércoles",
+            "jueves",
+            "viernes",
+            "sábado",
+        ],
+        ["dom", "lun", "mar", "mié", "jue", "vie", "sáb"],
+        [
+            "d/M/yy",
+            "d MMM y",
+            "d 'de' MMMM 'de' y",
+            "EEEE, d 'de' MMMM 'de' y",
+        ],
+    )
+    .times(["H:mm", "H:mm:ss", "H:mm:ss", "H:mm:ss"]),
+    names(
+        "it",
+        [
+            "gennaio",
+            "febbraio",
+            "marzo",
+            "aprile",
+            "maggio",
+            "giugno",
+            "luglio",
+            "agosto",
+            "settembre",
+            "ottobre",
+            "novembre",
+            "dicembre",
+        ],
+        [
+            "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+        ],
+        [
+            "domenica",
+            "lunedì",
+            "martedì",
+            "mercoledì",
+            "giovedì",
+            "venerdì",
+            "sabato",
+        ],
+        ["dom", "lun", "mar", "mer", "gio", "ven", "sab"],
+        ["dd/MM/yy", "d MMM y", "d MMMM y", "EEEE d MMMM y"],
+    ),
+    names(
+        "pt",
+        [
+            "janeiro",
+            "fevereiro",
+            "março",
+            "abril",
+            "maio",
+            "junho",
+            "julho",
+            "agosto",
+            "setembro",
+            "outubro",
+            "novembro",
+            "dezembro",
+        ],
+        [
+            "jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.",
+            "dez.",
+        ],
+        [
+            "domingo",
+            "segunda-feira",
+            "terça-feira",
+            "quarta-feira",
+            "quinta-feira",
+            "sexta-feira",
+            "sábado",
+        ],
+        ["dom.", "seg.", "ter.", "qua.", "qui.", "sex.", "sáb."],
+        [
+            "dd/MM/y",
+            "d 'de' MMM 'de' y",
+            "d 'de' MMMM 'de' y",
+            "EEEE, d 'de' MMMM 'de' y",
+        ],
+    )
+    .date_times(["{1} {0}", "{1} {0}", "{1} {0}", "{1} {0}"]),
+    names(
+        "nl",
+        [
+            "januari",
+            "februari",
+            "maart",
+            "april",
+            "mei",
+            "juni",
+            "juli",
+            "augustus",
+            "september",
+            "oktober",
+            "november",
+            "december",
+        ],
+        [
+            "jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec",
+        ],
+        [
+            "zondag",
+            "maandag",
+            "dinsdag",
+            "woensdag",
+            "donderdag",
+            "vrijdag",
+            "zaterdag",
+        ],
+        ["zo", "ma", "di", "wo", "do", "vr", "za"],
+        ["dd-MM-y", "d MMM y", "d MMMM y", "EEEE d MMMM y"],
+    )
+    .date_times(["{1} {0}", "{1} {0}", "{1} 'om' {0}", "{1} 'om' {0}"]),
+    names(
+        "pl",
+        [
+            "stycznia",
+            "lutego",
+            "marca",
+            "kwietnia",
+            "maja",
+            "czerwca",
+            "lipca",
+            "sierpnia",
+            "września",
+            "października",
+            "listopada",
+            "grudnia",
+        ],
+        [
+            "sty", "lut", "mar", "kwi", "maj", "cze", "lip", "sie", "wrz", "paź", "lis", "gru",
+        ],
+        [
+            "niedziela",
+            "poniedziałek",
+            "wtorek",
+            "środa",
+            "czwartek",
+            "piątek",
+            "sobota",
+        ],
+        ["niedz.", "pon.", "wt.", "śr.", "czw.", "pt.", "sob."],
+        ["d.MM.y", "d MMM y", "d MMMM y", "EEEE, d MMMM y"],
+    )
+    .date_times(["{1}, {0}", "{1}, {0}", "{1} {0}", "{1} {0}"]),
+    names(
+        "ru",
+        [
+            "января",
+            "февраля",
+            "марта",
+            "апреля",
+            "мая",
+            "июня",
+            "июля",
+            "августа",
+            "сентября",
+            "октября",
+            "ноября",
+            "декабря",
+        ],
+        [
+            "янв.",
+            "февр.",
+            "мар.",
+            "апр.",
+            "мая",
+            "июн.",
+            "июл.",
+            "авг.",
+            "сент.",
+            "окт.",
+            "нояб.",
+            "дек.",
+        ],
+        [
+            "воскресенье",
+            "понедельник",
+            "вторник",
+            "среда",
+            "четверг",
+            "пятница",
+            "суббота",
+        ],
+        ["вс", "пн", "вт", "ср", "чт", "пт", "сб"],
+        [
+            "dd.MM.y",
+            "d MMM y 'г'.",
+            "d MMMM y 'г'.",
+            "EEEE, d MMMM y 'г'.",
+        ],
+    ),
+    names(
+        "sv",
+        [
+            "januari",
+            "februari",
+            "mars",
+            "april",
+            "maj",
+            "juni",
+            "juli",
+            "augusti",
+            "september",
+            "oktober",
+            "november",
+            "december",
+        ],
+        [
+            "jan.", "feb.", "mars", "apr.", "maj", "juni", "juli", "aug.", "sep.", "okt.", "nov.",
+            "dec.",
+        ],
+        [
+            "söndag", "måndag", "tisdag", "onsdag", "torsdag", "fredag", "lördag",
+        ],
+        ["sön", "mån", "tis", "ons", "tors", "fre", "lör"],
+        ["y-MM-dd", "d MMM y", "d MMMM y", "EEEE d MMMM y"],
+    )
+    .date_times(["{1} {0}", "{1} {0}", "{1} {0}", "{1} {0}"]),
+    names(
+        "ja",
+        [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        [
+            "日曜日",
+            "月曜日",
+            "火曜日",
+            "水曜日",
+            "木曜日",
+            "金曜日",
+            "土曜日",
+        ],
+        ["日", "月", "火", "水", "木", "金", "土"],
+        ["y/MM/dd", "y/MM/dd", "y年M月d日", "y年M月d日EEEE"],
+    )
+    .periods("午前", "午後")
+    .times(["H:mm", "H:mm:ss", "H:mm:ss", "H時mm分ss秒"])
+    .date_times(["{1} {0}", "{1} {0}", "{1} {0}", "{1} {0}"]),
+    names(
+        "zh",
+        [
+            "一月",
+            "二月",
+            "三月",
+            "四月",
+            "五月",
+            "六月",
+            "七月",
+            "八月",
+            "九月",
+            "十月",
+            "十一月",
+            "十二月",
+        ],
+        [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        [
+            "星期日",
+            "星期一",
+            "星期二",
+            "星期三",
+            "星期四",
+            "星期五",
+            "星期六",
+        ],
+        ["周日", "周一", "周二", "周三", "周四", "周五", "周六"],
+        ["y/M/d", "y年M月d日", "y年M月d日", "y年M月d日EEEE"],
+    )
+    .periods("上午", "下午")
+    .date_times(["{1} {0}", "{1} {0}", "{1} {0}", "{1} {0}"]),
+];
+
+
+/////////////////
+// DATE FORMAT //
+/////////////////
+
+/// Length of a formatted date or time.
+///
+/// # Variants
+///
+/// - `Short`: Numeric dates and times without seconds, eg. `10/5/23` and `2:30 PM`.
+/// - `Medium`: Abbreviated month names and times with seconds, eg. `Oct 5, 2023`. The default.
+/// - `Long`: Full month names, eg. `October 5, 2023`.
+/// - `Full`: Full month and weekday names, eg. `Thursday, October 5, 2023`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum DateStyle {
+    /// Numeric dates and times without seconds.
+    Short,
+    /// Abbreviated month names and times with seconds.
+    #[default]
+    Medium,
+    /// Full month names.
+    Long,
+    /// Full month and weekday names.
+    Full,
+}
+
+impl DateStyle {
+    /// Parses the option of a date placeholder.
+    ///
+    /// # Parameters
+    ///
+    /// - `option`: The style, `short`, `medium`, `long` or `full`.
+    ///
+    /// # Returns
+    ///
+    /// The date style or `None` if the option is not valid.
+    pub(crate) fn parse(option: &str) -> Option<DateStyle> {
+        return match option {
+            "short" => Some(DateStyle::Short),
+            "medium" => Some(DateStyle::Medium),
+            "long" => Some(DateStyle::Long),
+            "full" => Some(DateStyle::Full),
+            _ => None,
+        };
+    }
+}
+
+/// Format of a date, a time or both.
+///
+/// Parts that are `None` are left out, eg. the time of a date format.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// use localizer_rs::dates::{DateFormat, DateStyle};
+///
+/// let when = chrono::NaiveDate::from_ymd_opt(2023, 10, 5)
+///     .unwrap()
+///     .and_hms_opt(14, 30, 0)
+///     .unwrap();
+///
+/// assert_eq!(
+///     localizer_rs::dates::format("en", when, &DateFormat::datetime(DateStyle::Short)),
+///     "10/5/23, 2:30 PM"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateFormat {
+    /// The style of the date.
+    pub date: Option<DateStyle>,
+    /// The style of the time.
+    pub time: Option<DateStyle>,
+}
+
+impl DateFormat {
+    /// Creates the format of dates.
+    ///
+    /// # Parameters
+    ///
+    /// - `style`: The style of the date.
+    ///
+    /// # Returns
+    ///
+    /// A `DateFormat` without the time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// use localizer_rs::dates::{DateFormat, DateStyle};
+    ///
+    /// let date = chrono::NaiveDate::from_ymd_opt(2023, 10, 5).unwrap();
+    ///
+    /// assert_eq!(
+    ///     localizer_rs::dates::format("de", date, &DateFormat::date(DateStyle::Long)),
+    ///     "5. Oktober 2023"
+    /// );
+    /// ```
+    pub fn date(style: DateStyle) -> DateFormat {
+        return DateFormat {
+            date: Some(style),
+            time: None,
+        };
+    }
+
+    /// Creates the format of times.
+    ///
+    /// # Parameters
+    ///
+    /// - `style`: The style of the time.
+    ///
+    /// # Returns
+    ///
+    /// A `DateFormat` without the date.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// use localizer_rs::dates::{DateFormat, DateStyle};
+    ///
+    /// let time = chrono::NaiveTime::from_hms_opt(14, 30, 0).unwrap();
+    ///
+    /// assert_eq!(
+    ///     localizer_rs::dates::format("en", time, &DateFormat::time(DateStyle::Short)),
+    ///     "2:30 PM"
+    /// );
+    /// ```
+    pub fn time(style: DateStyle) -> DateFormat {
+        return DateFormat {
+            date: None,
+            time: Some(style),
+        };
+    }
+
+    /// Creates the format of dates with times.
+    ///
+    /// # Parameters
+    ///
+    /// - `style`: The style of the date and the time.
+    ///
+    /// # Returns
+    ///
+    /// A `DateFormat` containing the date and the time.
+    pub fn datetime(style: DateStyle) -> DateFormat {
+        return DateFormat {
+            date: Some(style),
+            time: Some(style),
+        };
+    }
+
+    /// Parses the options of a date placeholder.
+    ///
+    /// # Parameters
+    ///
+    /// - `style`: The style of the placeholder, `date`, `time` or `datetime`.
+    /// - `option`: The date style, eg. `short`. Defaults to `medium`.
+    ///
+    /// # Returns
+    ///
+    /// The date format or `None` if the style or the option is not valid.
+    pub(crate) fn parse(style: &str, option: Option<&str>) -> Option<DateFormat> {
+        let date_style: DateStyle = match option {
+            Some(option) => DateStyle::parse(option)?,
+            None => DateStyle::default(),
+        };
+
+        return match style {
+            "date" => Some(DateFormat::date(date_style)),
+            "time" => Some(DateFormat::time(date_style)),
+            "datetime" => Some(DateFormat::datetime(date_style)),
+            _ => None,
+        };
+    }
+}
+
+impl Default for DateFormat {
+    fn default() -> DateFormat {
+        return DateFormat::date(DateStyle::default());
+    }
+}
+
+
+////////////////
+// FORMATTING //
+////////////////
+
+/// Writes a field of a date pattern.
+///
+/// # Parameters
+///
+/// - `output`: The string to write the field to.
+/// - `names`: The names of the locale.
+/// - `field`: The pattern letter of the field, eg. `M` for the month.
+/// - `width`: The number of repetitions of the pattern letter.
+/// - `date`: The date to format.
+/// - `time`: The time to format.
+fn write_field(
+    output: &mut String,
+    names: &Names,
+    field: char,
+    width: usize,
+    date: Option<chrono::NaiveDate>,
+    time: Option<chrono::NaiveTime>,
+) {
+    let number = |value: u32| -> String {
+        return format!("{:0width$}", value, width = width);
+    };
+
+    let text: String = match (field, date, time) {
+        ('y', Some(date), _) if width == 2 => format!("{:02}", date.year().rem_euclid(100)),
+        ('y', Some(date), _) => format!("{:0width$}", date.year(), width = width),
+        ('M' | 'L', Some(date), _) => match width {
+            1 | 2 => number(date.month()),
+            3 => names.months_abbreviated[date.month0() as usize].to_owned(),
+            _ => names.months[date.month0() as usize].to_owned(),
+        },
+        ('d', Some(date), _) => number(date.day()),
+        ('E', Some(date), _) => {
+            let weekday: usize = date.weekday().num_days_from_sunday() as usize;
+            match width {
+                1..=3 => names.weekdays_abbreviated[weekday].to_owned(),
+                _ => names.weekdays[weekday].to_owned(),
+            }
+        }
+        ('H', _, Some(time)) => number(time.hour()),
+        ('h', _, Some(time)) => number(time.hour12().1),
+        ('m', _, Some(time)) => number(time.minute()),
+        ('s', _, Some(time)) => number(time.second()),
+        ('a', _, Some(time)) => names.periods[time.hour12().0 as usize].to_owned(),
+        _ => String::new(),
+    };
+    output.push_str(&text);
+}
+
+/// Formats a date and time using a CLDR pattern.
+///
+/// # Parameters
+///
+/// - `names`: The names of the locale.
+/// - `pattern`: The pattern, eg. `d MMMM y`. Text in single quotes is written as it is.
+/// - `date`: The date to format.
+/// - `time`: The time to format.
+///
+/// # Returns
+///
+/// The formatted date and time.
+fn write(
+    names: &Names,
+    pattern: &str,
+    date: Option<chrono::NaiveDate>,
+    time: Option<chrono::NaiveTime>,
+) -> String {
+    let mut output: String = String::new();
+    let mut characters = pattern.chars().peekable();
+    let mut quoted: bool = false;
+
+    while let Some(character) = characters.next() {
+        if character == '\'' {
+            // Two quotes are an escaped quote
+            if characters.peek() == Some(&'\'') {
+                characters.next();
+                output.push('\'');
+            } else {
+                quoted = !quoted;
+            }
+        } else if quoted || !character.is_ascii_alphabetic() {
+            output.push(character);
+        } else {
+            let mut width: usize = 1;
+            while characters.peek() == Some(&character) {
+                characters.next();
+                width += 1;
+            }
+            write_field(&mut output, names, character, width, date, time);
+        }
+    }
+
+    return output;
+}
+
+/// Formats a date or time for a language.
+///
+/// Dates are formatted using the month and day names and the patterns of the language. Values
+/// that are not dates or times are returned unchanged, as are dates formatted as times and the
+/// other way around.
+///
+/// # Parameters
+///
+/// - `language`: The language to format the value for, eg. `de`.
+/// - `value`: The date or time to format.
+/// - `format`: The date format to use.
+///
+/// # Returns
+///
+/// A `String` containing the formatted date or time.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// use localizer_rs::dates::{DateFormat, DateStyle};
+///
+/// let when = chrono::NaiveDate::from_ymd_opt(2023, 10, 5)
+///     .unwrap()
+///     .and_hms_opt(14, 30, 0)
+///     .unwrap();
+///
+/// assert_eq!(
+///     localizer_rs::dates::format("en", when, &DateFormat::date(DateStyle::Full)),
+///     "Thursday, October 5, 2023"
+/// );
+/// assert_eq!(
+///     localizer_rs::dates::format("de", when, &DateFormat::datetime(DateStyle::Long)),
+///     "5. Oktober 2023 um 14:30:00"
+/// );
+/// assert_eq!(
+///     localizer_rs::dates::format("fr", when, &DateFormat::date(DateStyle::Medium)),
+///     "5 oct. 2023"
+/// );
+/// ```
+///
+/// # See also
+///
+/// - [`DateFormat`]
+/// - [`crate::Config::format_date()`]
+pub fn format<V: Into<arguments::Value>>(language: &str, value: V, format: &DateFormat) -> String {
+    let value: arguments::Value = value.into();
+
+    let (date, time) = match &value {
+        arguments::Value::Date(date) => (Some(*date), None),
+        arguments::Value::Time(time) => (None, Some(*time)),
+        arguments::Value::DateTime(datetime) => (Some(datetime.date()), Some(datetime.time())),
+        _ => return value.to_string(),
+    };
+
+    let names: &Names = plurals::locale_entry(&NAMES, language, |names: &Names| names.language);
+    let date_style: Option<DateStyle> = format.date.filter(|_style| date.is_some());
+    let time_style: Option<DateStyle> = format.time.filter(|_style| time.is_some());
+
+    let pattern: String = match (date_style, time_style) {
+        (Some(date_style), Some(time_style)) => names.date_times[date_style as usize]
+            .replace("{1}", names.dates[date_style as usize])
+            .replace("{0}", names.times[time_style as usize]),
+        (Some(date_style), None) => names.dates[date_style as usize].to_owned(),
+        (None, Some(time_style)) => names.times[time_style as usize].to_owned(),
+        (None, None) => return value.to_string(),
+    };
+
+    return write(names, &pattern, date, time);
+}
@@ -26,7 +26,9 @@
 // IMPORTS AND USE STATEMENTS //
 ////////////////////////////////
 
+use std::error;
 use std::fmt;
+use std::io;
 
 
 ///////////
@@ -35,36 +37,155 @@ use std::fmt;
 
 /// Error object.
 ///
-/// Use [`Error::new()`] to create error objects instead of using this struct.
+/// Every variant carries the information needed to describe what went wrong. Variants wrapping an
+/// underlying error expose it through [`error::Error::source()`].
 ///
-/// # Parameters
+/// # Variants
 ///
-/// - `name`: The errors name.
-/// - `description`: The error description.
-/// - `exit_code`: The errors exit code.
-///
-/// # Returns
-///
-/// A new `Error` object with the specified name and description.
+/// - `PathNotFound`: The translation path does not exist.
+/// - `PathUnreadable`: The translation path could not be accessed.
+/// - `InvalidPath`: The translation path is not valid unicode.
+/// - `FileNotFound`: The translation file does not exist.
+/// - `FileUnreadable`: The translation file could not be opened or read.
+/// - `Parse`: The translation file could not be parsed.
+/// - `KeyNotFound`: The translation file does not contain the key.
+/// - `InvalidMessage`: The translation is not a valid ICU MessageFormat message.
+/// - `InvalidFile`: The translation file is not a valid Fluent resource or gettext catalog.
+/// - `LanguageNotFound`: The catalog source does not provide the language.
+/// - `SourceFailed`: The catalog source could not load the language.
+/// - `FileUnwritable`: The generated file could not be written.
+/// - `InvalidStyle`: The style of a theme uses an unknown attribute.
+/// - `UnbalancedTags`: The style tags of the translation are not balanced.
 ///
 /// # Examples
 ///
 /// ```rust
 /// # use localizer_rs;
-/// localizer_rs::errors::Error {
-///     name: "name".to_owned(),
-///     description: "description".to_owned(),
-///     exit_code: 1
+/// let error: localizer_rs::errors::Error = localizer_rs::errors::Error::KeyNotFound {
+///     key: "key".to_owned(),
+///     segment: "key".to_owned(),
+///     language: "en".to_owned(),
 /// };
+///
+/// match error {
+///     localizer_rs::errors::Error::KeyNotFound { key, .. } => assert_eq!(key, "key"),
+///     _ => unreachable!(),
+/// }
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Error {
-    /// The errors name.
-    pub name: String,
-    /// The error description.
-    pub description: String,
-    /// The errors exit code.
-    pub exit_code: i32,
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The translation path does not exist.
+    PathNotFound {
+        /// The path that was not found.
+        path: String,
+    },
+    /// The translation path could not be accessed.
+    PathUnreadable {
+        /// The path that could not be accessed.
+        path: String,
+        /// The underlying io error.
+        source: io::Error,
+    },
+    /// The translation path is not valid unicode.
+    InvalidPath {
+        /// The invalid path.
+        path: String,
+    },
+    /// The translation file does not exist.
+    FileNotFound {
+        /// The file that was not found.
+        path: String,
+        /// The underlying io error.
+        source: io::Error,
+    },
+    /// The translation file could not be opened or read.
+    FileUnreadable {
+        /// The file that could not be read.
+        path: String,
+        /// The underlying io error.
+        source: io::Error,
+    },
+    /// The translation file could not be parsed.
+    Parse {
+        /// The file that could not be parsed.
+        path: String,
+        /// The line the error occurred in.
+        line: usize,
+        /// The column the error occurred in.
+        column: usize,
+        /// The underlying parsing error.
+        source: serde_json::Error,
+    },
+    /// The translation file does not contain the key.
+    KeyNotFound {
+        /// The key that was not found.
+        key: String,
+        /// The deepest segment of the key that could not be resolved. This is the key itself if
+        /// it does not contain any dots.
+        segment: String,
+        /// The language that was searched.
+        language: String,
+    },
+    /// The translation is not a valid ICU MessageFormat message.
+    InvalidMessage {
+        /// The key of the translation.
+        key: String,
+        /// The language of the translation.
+        language: String,
+        /// The character position of the syntax error.
+        position: usize,
+        /// The description of the syntax error.
+        reason: String,
+    },
+    /// The translation file is not a valid Fluent resource or gettext catalog.
+    InvalidFile {
+        /// The path of the translation file.
+        path: String,
+        /// The line of the syntax error, 0 for binary files.
+        line: usize,
+        /// The column of the syntax error or the byte offset for binary files.
+        column: usize,
+        /// The description of the syntax error.
+        reason: String,
+    },
+    /// The catalog source does not provide the language.
+    LanguageNotFound {
+        /// The language that was not found.
+        language: String,
+    },
+    /// The catalog source could not load the language.
+    SourceFailed {
+        /// The language that could not be loaded.
+        language: String,
+        /// The underlying error of the catalog source.
+        source: Box<dyn error::Error + Send + Sync>,
+    },
+    /// The generated file could not be written.
+    FileUnwritable {
+        /// The file that could not be written.
+        path: String,
+        /// The underlying io error.
+        source: io::Error,
+    },
+    /// The style of a theme uses an unknown attribute.
+    InvalidStyle {
+        /// The name of the style.
+        name: String,
+        /// The unknown attribute.
+        attribute: String,
+    },
+    /// The style tags of the translation are not balanced.
+    UnbalancedTags {
+        /// The key of the translation.
+        key: String,
+        /// The language of the translation.
+        language: String,
+        /// The character position of the unbalanced tag.
+        position: usize,
+        /// The description of the error.
+        reason: String,
+    },
 }
 
 /// Display implementation for the error object.
@@ -84,7 +205,7 @@ impl fmt::Display for Error {
     ///
     /// ```rust
     /// # use localizer_rs;
-    /// # let error = localizer_rs::errors::Error::new("name", "description", 1);
+    /// # let error = localizer_rs::errors::Error::InvalidPath { path: "path".to_owned() };
     /// println!("{}", error);
     /// ```
     ///
@@ -93,67 +214,258 @@ impl fmt::Display for Error {
     /// - [`fmt::Display`]
     /// - [`Error`]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "\x1b[31;1m{}\x1b[0m: {}", self.name, self.description)
+        match self {
+            Error::PathNotFound { path } => {
+                write!(f, "Translation path was not found (path: {:?})", path)
+            }
+            Error::PathUnreadable { path, .. } => {
+                write!(f, "Could not open path (path: {:?})", path)
+            }
+            Error::InvalidPath { path } => {
+                write!(f, "Path does not seem to be valid (path: {:?})", path)
+            }
+            Error::FileNotFound { path, .. } => {
+                write!(f, "Translation file was not found (file: {})", path)
+            }
+            Error::FileUnreadable { path, .. } => {
+                write!(f, "Could not open translation file (file: {})", path)
+            }
+            Error::Parse {
+                path, line, column, ..
+            } => write!(
+                f,
+                "Translation file could not be parsed (file: {}, line: {}, column: {})",
+                path, line, column
+            ),
+            Error::KeyNotFound {
+                key,
+                segment,
+                language,
+            } => write!(
+                f,
+                "Could not find translation key (key: {:?}, segment: {:?}, language: {:?})",
+                key, segment, language
+            ),
+            Error::InvalidMessage {
+                key,
+                language,
+                position,
+                reason,
+            } => write!(
+                f,
+                "Translation is not a valid message (key: {:?}, language: {:?}, position: {}): {}",
+                key, language, position, reason
+            ),
+            Error::InvalidFile {
+                path,
+                line,
+                column,
+                reason,
+            } => write!(
+                f,
+                "Translation file is not valid (path: {:?}, line: {}, column: {}): {}",
+                path, line, column, reason
+            ),
+            Error::LanguageNotFound { language } => write!(
+                f,
+                "Catalog source does not provide the language (language: {:?})",
+                language
+            ),
+            Error::SourceFailed { language, .. } => write!(
+                f,
+                "Catalog source could not load the language (language: {:?})",
+                language
+            ),
+            Error::FileUnwritable { path, .. } => {
+                write!(f, "Could not write generated file (file: {})", path)
+            }
+            Error::InvalidStyle { name, attribute } => write!(
+                f,
+                "Style uses an unknown attribute (style: {:?}, attribute: {:?})",
+                name, attribute
+            ),
+            Error::UnbalancedTags {
+                key,
+                language,
+                position,
+                reason,
+            } => write!(
+                f,
+                "Translation has unbalanced style tags (key: {:?}, language: {:?}, position: {}): {}",
+                key, language, position, reason
+            ),
+        }
+    }
+}
+
+/// Error implementation for the error object.
+impl error::Error for Error {
+    /// Returns the underlying error, if any.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The error object.
+    ///
+    /// # Returns
+    ///
+    /// The [`io::Error`] or [`serde_json::Error`] that caused this error or `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// use std::error::Error;
+    ///
+    /// let error = localizer_rs::errors::Error::InvalidPath { path: "path".to_owned() };
+    /// assert!(error.source().is_none());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`error::Error`]
+    /// - [`Error`]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::PathUnreadable { source, .. }
+            | Error::FileNotFound { source, .. }
+            | Error::FileUnreadable { source, .. }
+            | Error::FileUnwritable { source, .. } => Some(source),
+            Error::Parse { source, .. } => Some(source),
+            Error::SourceFailed { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
     }
 }
 
 impl Error {
-    /// Creates a new error object.
+    /// Returns the errors name.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The error object.
+    ///
+    /// # Returns
+    ///
+    /// The name of the error category, eg. `OS Error`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// # let error = localizer_rs::errors::Error::InvalidPath { path: "path".to_owned() };
+    /// assert_eq!(error.name(), "OS Error");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Error`]
+    pub fn name(&self) -> &'static str {
+        return match self {
+            Error::PathNotFound { .. }
+            | Error::PathUnreadable { .. }
+            | Error::InvalidPath { .. }
+            | Error::FileNotFound { .. }
+            | Error::FileUnreadable { .. }
+            | Error::FileUnwritable { .. } => "OS Error",
+            Error::Parse { .. } => "Parsing error",
+            Error::KeyNotFound { .. } => "Indexing error",
+            Error::InvalidMessage { .. } => "Parsing error",
+            Error::InvalidFile { .. } => "Parsing error",
+            Error::LanguageNotFound { .. } => "Indexing error",
+            Error::SourceFailed { .. } => "Source error",
+            Error::InvalidStyle { .. } => "Parsing error",
+            Error::UnbalancedTags { .. } => "Parsing error",
+        };
+    }
+
+    /// Returns the errors exit code.
+    ///
+    /// Every variant has its own exit code.
     ///
     /// # Parameters
     ///
-    /// - `name`: The errors name.
-    /// - `description`: The error description.
-    /// - `exit_code`: The errors exit code.
+    /// - `self`: The error object.
     ///
     /// # Returns
     ///
-    /// A new `Error` object with the specified name and description.
+    /// The exit code used by [`Error::raise()`].
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use localizer_rs;
-    /// localizer_rs::errors::Error::new("name", "description", 1);
+    /// # let error = localizer_rs::errors::Error::InvalidPath { path: "path".to_owned() };
+    /// assert_eq!(error.exit_code(), 3);
     /// ```
     ///
     /// # See also
     ///
     /// - [`Error`]
-    pub fn new(name: &str, description: &str, exit_code: i32) -> Error {
-        return Error {
-            name: name.to_owned(),
-            description: description.to_owned(),
-            exit_code: exit_code,
+    pub fn exit_code(&self) -> i32 {
+        return match self {
+            Error::PathNotFound { .. } => 1,
+            Error::PathUnreadable { .. } => 2,
+            Error::InvalidPath { .. } => 3,
+            Error::FileNotFound { .. } => 4,
+            Error::Parse { .. } => 5,
+            Error::KeyNotFound { .. } => 6,
+            Error::FileUnreadable { .. } => 7,
+            Error::InvalidMessage { .. } => 8,
+            Error::InvalidFile { .. } => 9,
+            Error::LanguageNotFound { .. } => 10,
+            Error::SourceFailed { .. } => 11,
+            Error::FileUnwritable { .. } => 12,
+            Error::InvalidStyle { .. } => 13,
+            Error::UnbalancedTags { .. } => 14,
         };
     }
 
-    /// Raises the error and exits with the specified exit code.
+    /// Returns whether the error signals a missing language.
+    ///
+    /// Missing languages are skipped when walking the fallback chain.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The error object.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the error is a [`Error::FileNotFound`] or [`Error::LanguageNotFound`] error.
+    pub(crate) fn is_not_found(&self) -> bool {
+        return matches!(
+            self,
+            Error::FileNotFound { .. } | Error::LanguageNotFound { .. }
+        );
+    }
+
+    /// Raises the error and exits with the errors exit code.
     ///
     /// # Parameters
     ///
     /// - `self`: The error object.
-    /// - `details`: The error details.
     ///
     /// # Aborts
     ///
-    /// Exits with the specified exit code.
+    /// Exits with the errors exit code.
     ///
     /// # Examples
     ///
     /// ```should_panic
     /// # use localizer_rs;
-    /// # let error: localizer_rs::errors::Error = localizer_rs::errors::Error::new("name", "description", 1);
-    /// error.raise("Something went very wrong");
+    /// # let error = localizer_rs::errors::Error::InvalidPath { path: "path".to_owned() };
+    /// error.raise();
     /// ```
     ///
     /// # See also
     ///
     /// - [`Error`]
-    pub fn raise(&self, details: &str) {
-        eprintln!("{}", self);
-        eprintln!("{}", details);
+    /// - [`Error::exit_code()`]
+    pub fn raise(&self) -> ! {
+        eprintln!("\x1b[31;1m{}\x1b[0m: {}", self.name(), self);
+        if let Some(source) = error::Error::source(self) {
+            eprintln!("Details: {}", source);
+        }
 
-        std::process::exit(self.exit_code);
+        std::process::exit(self.exit_code());
     }
 }
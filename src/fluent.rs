@@ -0,0 +1,1052 @@
+// localizer-rs fluent
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::collections::HashMap;
+
+use crate::message_format::{self, Part};
+use crate::plurals;
+
+
+/////////
+// AST //
+/////////
+
+/// Expression inside of a placeable.
+#[derive(Debug, Clone)]
+enum Expression {
+    /// A string literal like `"text"`.
+    String(String),
+    /// A number literal like `-1.5`.
+    Number(String),
+    /// A variable reference like `$name`.
+    Variable(String),
+    /// A message reference like `message` or `message.attribute`.
+    Message {
+        /// The identifier of the message.
+        id: String,
+        /// The attribute of the message.
+        attribute: Option<String>,
+    },
+    /// A term reference like `-term`, `-term.attribute` or `-term(case: "genitive")`.
+    Term {
+        /// The identifier of the term.
+        id: String,
+        /// The attribute of the term.
+        attribute: Option<String>,
+        /// The named arguments of the term.
+        arguments: Vec<(String, Expression)>,
+    },
+    /// A function call like `NUMBER($count, type: "ordinal")`.
+    Function {
+        /// The name of the function.
+        name: String,
+        /// The positional arguments.
+        positional: Vec<Expression>,
+        /// The named arguments.
+        named: Vec<(String, Expression)>,
+    },
+    /// A select expression.
+    Select {
+        /// The expression to select on.
+        selector: Box<Expression>,
+        /// The variants of the select expression.
+        variants: Vec<Variant>,
+    },
+}
+
+/// Variant of a select expression.
+#[derive(Debug, Clone)]
+struct Variant {
+    /// The key of the variant.
+    key: String,
+    /// Whether this is the default variant.
+    default: bool,
+    /// The pattern of the variant.
+    pattern: Pattern,
+}
+
+/// Element of a pattern.
+#[derive(Debug, Clone)]
+enum Element {
+    /// Literal text.
+    Text(String),
+    /// A placeable containing an expression.
+    Placeable(Expression),
+}
+
+/// Pattern of a message, term or attribute.
+type Pattern = Vec<Element>;
+
+/// Positional and named arguments of a function call or term reference.
+type Arguments = (Vec<Expression>, Vec<(String, Expression)>);
+
+/// Message or term.
+#[derive(Debug, Clone, Default)]
+struct Entry {
+    /// The value of the entry.
+    value: Option<Pattern>,
+    /// The attributes of the entry.
+    attributes: Vec<(String, Pattern)>,
+}
+
+/// Parsed Fluent resource.
+#[derive(Debug, Clone, Default)]
+struct Resource {
+    /// The messages of the resource, in the order they are defined.
+    messages: Vec<(String, Entry)>,
+    /// The terms of the resource.
+    terms: HashMap<String, Entry>,
+}
+
+
+////////////
+// PARSER //
+////////////
+
+/// Error produced while parsing a resource.
+///
+/// Contains the character position of the error and its description.
+pub(crate) type ParseError = (usize, String);
+
+/// Raw element of a pattern, before the indentation is removed.
+enum RawElement {
+    /// Literal text.
+    Text(String),
+    /// The indentation of a continuation line.
+    Indent(usize),
+    /// A placeable containing an expression.
+    Placeable(Expression),
+}
+
+/// Fluent resource parser.
+struct Parser {
+    /// The characters of the resource.
+    characters: Vec<char>,
+    /// The current position.
+    position: usize,
+}
+
+impl Parser {
+    /// Returns the current character.
+    fn peek(&self) -> Option<char> {
+        return self.characters.get(self.position).copied();
+    }
+
+    /// Returns the character at the specified offset from the current position.
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        return self.characters.get(self.position + offset).copied();
+    }
+
+    /// Returns a parse error at the current position.
+    fn error(&self, description: &str) -> ParseError {
+        return (self.position, description.to_owned());
+    }
+
+    /// Consumes the expected character.
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        if self.peek() != Some(expected) {
+            return Err(self.error(format!("Expected {:?}", expected).as_str()));
+        }
+        self.position += 1;
+
+        return Ok(());
+    }
+
+    /// Skips spaces.
+    fn skip_blank_inline(&mut self) {
+        while self.peek() == Some(' ') {
+            self.position += 1;
+        }
+    }
+
+    /// Skips spaces and line breaks.
+    fn skip_blank(&mut self) {
+        while matches!(self.peek(), Some(' ' | '\n' | '\r')) {
+            self.position += 1;
+        }
+    }
+
+    /// Skips the rest of the current line, including the line break.
+    fn skip_line(&mut self) {
+        while let Some(character) = self.peek() {
+            self.position += 1;
+            if character == '\n' {
+                break;
+            }
+        }
+    }
+
+    /// Consumes a line break.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a line break was consumed.
+    fn line_end(&mut self) -> bool {
+        match (self.peek(), self.peek_at(1)) {
+            (Some('\r'), Some('\n')) => self.position += 2,
+            (Some('\n'), _) => self.position += 1,
+            _ => return false,
+        }
+
+        return true;
+    }
+
+    /// Parses an identifier.
+    fn identifier(&mut self) -> Result<String, ParseError> {
+        let start: usize = self.position;
+
+        if !self
+            .peek()
+            .is_some_and(|character: char| character.is_ascii_alphabetic())
+        {
+            return Err(self.error("Expected an identifier"));
+        }
+        while self.peek().is_some_and(|character: char| {
+            character.is_ascii_alphanumeric() || character == '_' || character == '-'
+        }) {
+            self.position += 1;
+        }
+
+        return Ok(self.characters[start..self.position].iter().collect());
+    }
+
+    /// Parses a resource.
+    fn resource(&mut self) -> Result<Resource, ParseError> {
+        let mut resource: Resource = Resource::default();
+
+        while let Some(character) = self.peek() {
+            match character {
+                '\n' | '\r' => self.position += 1,
+                ' ' => {
+                    self.skip_blank_inline();
+                    if !self.line_end() && self.peek().is_some() {
+                        return Err(self.error("Expected a message, term or comment"));
+                    }
+                }
+                '#' => self.skip_line(),
+                '-' => {
+                    self.position += 1;
+                    let id: String = self.identifier()?;
+                    let entry: Entry = self.entry()?;
+
+                    if entry.value.is_none() {
+                        return Err(self.error("Expected a value for the term"));
+                    }
+                    resource.terms.insert(id, entry);
+                }
+                _ => {
+                    let id: String = self.identifier()?;
+                    let entry: Entry = self.entry()?;
+
+                    if entry.value.is_none() && entry.attributes.is_empty() {
+                        return Err(self.error("Expected a value or an attribute"));
+                    }
+                    resource.messages.push((id, entry));
+                }
+            }
+        }
+
+        return Ok(resource);
+    }
+
+    /// Parses a message or term, starting after its identifier.
+    fn entry(&mut self) -> Result<Entry, ParseError> {
+        self.skip_blank_inline();
+        self.expect('=')?;
+
+        let mut entry: Entry = Entry {
+            value: self.pattern()?,
+            attributes: vec![],
+        };
+
+        loop {
+            let start: usize = self.position;
+            self.skip_blank();
+            let indented: bool = start < self.position && self.characters[self.position - 1] == ' ';
+
+            if !indented || self.peek() != Some('.') {
+                self.position = start;
+                break;
+            }
+
+            self.position += 1;
+            let id: String = self.identifier()?;
+            self.skip_blank_inline();
+            self.expect('=')?;
+
+            match self.pattern()? {
+                Some(pattern) => entry.attributes.push((id, pattern)),
+                None => return Err(self.error("Expected a value for the attribute")),
+            }
+        }
+
+        if self.peek() == Some('}') {
+            return Err(self.error("Unbalanced closing brace"));
+        }
+
+        return Ok(entry);
+    }
+
+    /// Parses a pattern, starting after the equals sign or variant key.
+    ///
+    /// The pattern ends at the end of the last continuation line or before a closing brace.
+    fn pattern(&mut self) -> Result<Option<Pattern>, ParseError> {
+        let mut elements: Vec<RawElement> = vec![];
+        self.skip_blank_inline();
+
+        while let Some(character) = self.peek() {
+            match character {
+                '\n' | '\r' => {
+                    let start: usize = self.position;
+                    let mut line_breaks: usize = 0;
+                    let mut indent: usize = 0;
+
+                    while self.line_end() {
+                        line_breaks += 1;
+                        indent = 0;
+                        while self.peek() == Some(' ') {
+                            self.position += 1;
+                            indent += 1;
+                        }
+                    }
+
+                    if indent == 0 || matches!(self.peek(), None | Some('[' | '*' | '.' | '}')) {
+                        self.position = start;
+                        break;
+                    }
+
+                    elements.push(RawElement::Text("\n".repeat(line_breaks)));
+                    elements.push(RawElement::Indent(indent));
+                }
+                '{' => {
+                    self.position += 1;
+                    elements.push(RawElement::Placeable(self.placeable()?));
+                }
+                '}' => break,
+                _ => {
+                    self.position += 1;
+                    match elements.last_mut() {
+                        Some(RawElement::Text(text)) => text.push(character),
+                        _ => elements.push(RawElement::Text(character.to_string())),
+                    }
+                }
+            }
+        }
+
+        let common_indent: usize = elements
+            .iter()
+            .filter_map(|element: &RawElement| match element {
+                RawElement::Indent(indent) => Some(*indent),
+                _ => None,
+            })
+            .min()
+            .unwrap_or(0);
+
+        let mut pattern: Pattern = vec![];
+        for element in elements {
+            let text: String = match element {
+                RawElement::Text(text) => text,
+                RawElement::Indent(indent) => " ".repeat(indent - common_indent),
+                RawElement::Placeable(expression) => {
+                    pattern.push(Element::Placeable(expression));
+                    continue;
+                }
+            };
+
+            match pattern.last_mut() {
+                Some(Element::Text(last)) => last.push_str(&text),
+                _ => pattern.push(Element::Text(text)),
+            }
+        }
+
+        if let Some(Element::Text(text)) = pattern.first_mut() {
+            *text = text.trim_start_matches(['\n', '\r']).to_owned();
+        }
+        if let Some(Element::Text(text)) = pattern.last_mut() {
+            *text = text.trim_end().to_owned();
+        }
+        pattern
+            .retain(|element: &Element| !matches!(element, Element::Text(text) if text.is_empty()));
+
+        if pattern.is_empty() {
+            return Ok(None);
+        }
+
+        return Ok(Some(pattern));
+    }
+
+    /// Parses a placeable, starting after its opening brace.
+    fn placeable(&mut self) -> Result<Expression, ParseError> {
+        self.skip_blank();
+        let mut expression: Expression = if self.peek() == Some('{') {
+            self.position += 1;
+            self.placeable()?
+        } else {
+            self.inline_expression()?
+        };
+        self.skip_blank();
+
+        if self.peek() == Some('-') && self.peek_at(1) == Some('>') {
+            self.position += 2;
+            self.skip_blank_inline();
+            expression = Expression::Select {
+                selector: Box::new(expression),
+                variants: self.variants()?,
+            };
+            self.skip_blank();
+        }
+
+        self.expect('}')?;
+
+        return Ok(expression);
+    }
+
+    /// Parses the variants of a select expression.
+    fn variants(&mut self) -> Result<Vec<Variant>, ParseError> {
+        let mut variants: Vec<Variant> = vec![];
+
+        loop {
+            self.skip_blank();
+            let default: bool = self.peek() == Some('*');
+            if default {
+                self.position += 1;
+            }
+            if self.peek() != Some('[') {
+                if default {
+                    return Err(self.error("Expected a variant key"));
+                }
+                break;
+            }
+
+            self.position += 1;
+            self.skip_blank();
+            let key: String = match self.peek() {
+                Some(character) if character.is_ascii_digit() || character == '-' => {
+                    self.number()?
+                }
+                _ => self.identifier()?,
+            };
+            self.skip_blank();
+            self.expect(']')?;
+
+            let pattern: Pattern = match self.pattern()? {
+                Some(pattern) => pattern,
+                None => return Err(self.error("Expected a value for the variant")),
+            };
+            variants.push(Variant {
+                key,
+                default,
+                pattern,
+            });
+        }
+
+        if variants
+            .iter()
+            .filter(|variant: &&Variant| variant.default)
+            .count()
+            != 1
+        {
+            return Err(self.error("Expected exactly one default variant"));
+        }
+
+        return Ok(variants);
+    }
+
+    /// Parses a number literal.
+    fn number(&mut self) -> Result<String, ParseError> {
+        let start: usize = self.position;
+
+        if self.peek() == Some('-') {
+            self.position += 1;
+        }
+        if !self
+            .peek()
+            .is_some_and(|character: char| character.is_ascii_digit())
+        {
+            return Err(self.error("Expected a number"));
+        }
+        while self
+            .peek()
+            .is_some_and(|character: char| character.is_ascii_digit())
+        {
+            self.position += 1;
+        }
+        if self.peek() == Some('.') && self.peek_at(1).is_some_and(|c: char| c.is_ascii_digit()) {
+            self.position += 1;
+            while self
+                .peek()
+                .is_some_and(|character: char| character.is_ascii_digit())
+            {
+                self.position += 1;
+            }
+        }
+
+        return Ok(self.characters[start..self.position].iter().collect());
+    }
+
+    /// Parses a string literal, starting at its opening quote.
+    fn string(&mut self) -> Result<String, ParseError> {
+        self.expect('"')?;
+        let mut value: String = String::new();
+
+        loop {
+            match self.peek() {
+                Some('"') => {
+                    self.position += 1;
+                    break;
+                }
+                Some('\\') => {
+                    self.position += 1;
+                    match self.peek() {
+                        Some(character @ ('"' | '\\')) => {
+                            value.push(character);
+                            self.position += 1;
+                        }
+                        Some(character @ ('u' | 'U')) => {
+                            let length: usize = if character == 'u' { 4 } else { 6 };
+                            let start: usize = self.position + 1;
+                            let end: usize = start + length;
+                            let digits: String = self
+                                .characters
+                                .get(start..end)
+                                .map(|digits: &[char]| digits.iter().collect())
+                                .unwrap_or_default();
+
+                            match u32::from_str_radix(&digits, 16)
+                                .ok()
+                                .and_then(char::from_u32)
+                            {
+                                Some(unicode) => value.push(unicode),
+                                None => return Err(self.error("Invalid unicode escape sequence")),
+                            }
+                            self.position = end;
+                        }
+                        _ => return Err(self.error("Invalid escape sequence")),
+                    }
+                }
+                Some('\n' | '\r') | None => return Err(self.error("Unterminated string literal")),
+                Some(character) => {
+                    value.push(character);
+                    self.position += 1;
+                }
+            }
+        }
+
+        return Ok(value);
+    }
+
+    /// Parses the optional attribute of a message or term reference.
+    fn attribute(&mut self) -> Result<Option<String>, ParseError> {
+        if self.peek() != Some('.') {
+            return Ok(None);
+        }
+        self.position += 1;
+
+        return Ok(Some(self.identifier()?));
+    }
+
+    /// Parses the arguments of a function call or term reference, starting at the opening
+    /// parenthesis.
+    fn arguments(&mut self) -> Result<Arguments, ParseError> {
+        let mut positional: Vec<Expression> = vec![];
+        let mut named: Vec<(String, Expression)> = vec![];
+        self.expect('(')?;
+
+        loop {
+            self.skip_blank();
+            if self.peek() == Some(')') {
+                self.position += 1;
+                break;
+            }
+
+            let expression: Expression = self.inline_expression()?;
+            self.skip_blank();
+
+            match expression {
+                Expression::Message {
+                    id,
+                    attribute: None,
+                } if self.peek() == Some(':') => {
+                    self.position += 1;
+                    self.skip_blank();
+                    let value: Expression = match self.peek() {
+                        Some('"') => Expression::String(self.string()?),
+                        _ => Expression::Number(self.number()?),
+                    };
+                    named.push((id, value));
+                }
+                expression => positional.push(expression),
+            }
+
+            self.skip_blank();
+            match self.peek() {
+                Some(',') => self.position += 1,
+                Some(')') => {}
+                _ => return Err(self.error("Expected ',' or ')'")),
+            }
+        }
+
+        return Ok((positional, named));
+    }
+
+    /// Parses an inline expression.
+    fn inline_expression(&mut self) -> Result<Expression, ParseError> {
+        return match self.peek() {
+            Some('"') => Ok(Expression::String(self.string()?)),
+            Some('-') if self.peek_at(1).is_some_and(|c: char| c.is_ascii_digit()) => {
+                Ok(Expression::Number(self.number()?))
+            }
+            Some(character) if character.is_ascii_digit() => Ok(Expression::Number(self.number()?)),
+            Some('$') => {
+                self.position += 1;
+                Ok(Expression::Variable(self.identifier()?))
+            }
+            Some('-') => {
+                self.position += 1;
+                let id: String = self.identifier()?;
+                let attribute: Option<String> = self.attribute()?;
+                let mut arguments: Vec<(String, Expression)> = vec![];
+
+                self.skip_blank_inline();
+                if self.peek() == Some('(') {
+                    arguments = self.arguments()?.1;
+                }
+
+                Ok(Expression::Term {
+                    id,
+                    attribute,
+                    arguments,
+                })
+            }
+            Some(character) if character.is_ascii_alphabetic() => {
+                let id: String = self.identifier()?;
+
+                if self.peek() == Some('(') {
+                    let (positional, named) = self.arguments()?;
+                    return Ok(Expression::Function {
+                        name: id,
+                        positional,
+                        named,
+                    });
+                }
+
+                Ok(Expression::Message {
+                    id,
+                    attribute: self.attribute()?,
+                })
+            }
+            _ => Err(self.error("Expected an expression")),
+        };
+    }
+}
+
+
+//////////////
+// COMPILER //
+//////////////
+
+/// Compiles Fluent patterns into message parts.
+struct Compiler<'a> {
+    /// The messages of the resource.
+    messages: HashMap<&'a str, &'a Entry>,
+    /// The terms of the resource.
+    terms: &'a HashMap<String, Entry>,
+    /// The messages and terms that are currently compiled, used to detect cycles.
+    stack: Vec<String>,
+}
+
+impl<'a> Compiler<'a> {
+    /// Compiles a pattern.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The compiler.
+    /// - `pattern`: The pattern to compile.
+    /// - `scope`: The arguments of the term the pattern belongs to, if any. Terms can not access
+    ///   the arguments of the message.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` containing the compiled parts.
+    fn pattern(&mut self, pattern: &Pattern, scope: Option<&HashMap<String, String>>) -> Vec<Part> {
+        let mut parts: Vec<Part> = vec![];
+
+        for element in pattern {
+            match element {
+                Element::Text(text) => parts.push(Part::Text(text.to_owned())),
+                Element::Placeable(expression) => self.expression(expression, scope, &mut parts),
+            }
+        }
+
+        return parts;
+    }
+
+    /// Compiles a referenced message or term pattern, guarding against cycles.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The compiler.
+    /// - `name`: The name of the reference, used to detect cycles.
+    /// - `pattern`: The referenced pattern.
+    /// - `scope`: The arguments of the term the pattern belongs to, if any.
+    ///
+    /// # Returns
+    ///
+    /// The compiled parts or `None` if the reference is cyclic.
+    fn reference(
+        &mut self,
+        name: String,
+        pattern: &Pattern,
+        scope: Option<&HashMap<String, String>>,
+    ) -> Option<Vec<Part>> {
+        if self.stack.contains(&name) {
+            return None;
+        }
+
+        self.stack.push(name);
+        let parts: Vec<Part> = self.pattern(pattern, scope);
+        self.stack.pop();
+
+        return Some(parts);
+    }
+
+    /// Returns the pattern of a message or term reference.
+    fn entry_pattern<'b>(entry: &'b Entry, attribute: &Option<String>) -> Option<&'b Pattern> {
+        return match attribute {
+            Some(attribute) => entry
+                .attributes
+                .iter()
+                .find(|(id, _pattern)| id == attribute)
+                .map(|(_id, pattern)| pattern),
+            None => entry.value.as_ref(),
+        };
+    }
+
+    /// Evaluates an expression that does not depend on the arguments of the message.
+    ///
+    /// # Returns
+    ///
+    /// The value of the expression or `None` if it depends on the arguments.
+    fn static_value(
+        &mut self,
+        expression: &Expression,
+        scope: Option<&HashMap<String, String>>,
+    ) -> Option<String> {
+        let mut parts: Vec<Part> = vec![];
+
+        match expression {
+            Expression::Variable(_name) if scope.is_none() => return None,
+            Expression::Function { .. } | Expression::Select { .. } if scope.is_none() => {
+                return None
+            }
+            _ => self.expression(expression, scope, &mut parts),
+        }
+
+        let mut value: String = String::new();
+        for part in parts {
+            match part {
+                Part::Text(text) => value.push_str(&text),
+                _ => return None,
+            }
+        }
+
+        return Some(value);
+    }
+
+    /// Compiles an expression.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The compiler.
+    /// - `expression`: The expression to compile.
+    /// - `scope`: The arguments of the term the expression belongs to, if any.
+    /// - `parts`: The parts to append to.
+    fn expression(
+        &mut self,
+        expression: &Expression,
+        scope: Option<&HashMap<String, String>>,
+        parts: &mut Vec<Part>,
+    ) {
+        match expression {
+            Expression::String(value) | Expression::Number(value) => {
+                parts.push(Part::Text(value.to_owned()))
+            }
+            Expression::Variable(name) => match scope {
+                Some(arguments) => parts.push(Part::Text(
+                    arguments
+                        .get(name)
+                        .cloned()
+                        .unwrap_or(format!("{{${}}}", name)),
+                )),
+                None => parts.push(Part::Argument {
+                    name: name.to_owned(),
+                    format: None,
+                    style: None,
+                }),
+            },
+            Expression::Message { id, attribute } => {
+                let name: String = match attribute {
+                    Some(attribute) => format!("{}.{}", id, attribute),
+                    None => id.to_owned(),
+                };
+                let pattern: Option<&Pattern> = self
+                    .messages
+                    .get(id.as_str())
+                    .and_then(|entry: &&Entry| Compiler::entry_pattern(entry, attribute));
+
+                match pattern {
+                    Some(pattern) => match self.reference(name.to_owned(), pattern, None) {
+                        Some(compiled) => parts.extend(compiled),
+                        None => parts.push(Part::Text(format!("{{{}}}", name))),
+                    },
+                    // Unknown messages are used for the style placeholders, eg. `{ bold }`.
+                    None => parts.push(Part::Placeholder(name)),
+                }
+            }
+            Expression::Term {
+                id,
+                attribute,
+                arguments,
+            } => {
+                let name: String = match attribute {
+                    Some(attribute) => format!("-{}.{}", id, attribute),
+                    None => format!("-{}", id),
+                };
+                let mut term_scope: HashMap<String, String> = HashMap::new();
+                for (argument, value) in arguments {
+                    if let Some(value) = self.static_value(value, scope) {
+                        term_scope.insert(argument.to_owned(), value);
+                    }
+                }
+                let terms: &HashMap<String, Entry> = self.terms;
+                let pattern: Option<&Pattern> = terms
+                    .get(id)
+                    .and_then(|entry: &Entry| Compiler::entry_pattern(entry, attribute));
+
+                match pattern.and_then(|pattern: &Pattern| {
+                    self.reference(name.to_owned(), pattern, Some(&term_scope))
+                }) {
+                    Some(compiled) => parts.extend(compiled),
+                    None => parts.push(Part::Text(format!("{{{}}}", name))),
+                }
+            }
+            Expression::Function {
+                name,
+                positional,
+                named,
+            } => {
+                let option = |option: &str| {
+                    named
+                        .iter()
+                        .find(|(name, _value)| name == option)
+                        .and_then(|(_name, value)| match value {
+                            Expression::String(value) | Expression::Number(value) => {
+                                Some(value.to_owned())
+                            }
+                            _ => None,
+                        })
+                };
+
+                match (name.as_str(), positional.first(), scope) {
+                    ("NUMBER", Some(Expression::Variable(variable)), None) => {
+                        parts.push(Part::Argument {
+                            name: variable.to_owned(),
+                            format: Some("number".to_owned()),
+                            style: option("style").filter(|style: &String| style == "percent"),
+                        })
+                    }
+                    ("DATETIME", Some(Expression::Variable(variable)), None) => {
+                        let (format, style) = match (option("dateStyle"), option("timeStyle")) {
+                            (Some(date), Some(_time)) => ("datetime", Some(date)),
+                            (None, Some(time)) => ("time", Some(time)),
+                            (date, None) => ("date", date),
+                        };
+                        parts.push(Part::Argument {
+                            name: variable.to_owned(),
+                            format: Some(format.to_owned()),
+                            style,
+                        })
+                    }
+                    (_name, Some(argument), _scope) => self.expression(argument, scope, parts),
+                    (_name, None, _scope) => {}
+                }
+            }
+            Expression::Select { selector, variants } => {
+                self.select(selector, variants, scope, parts);
+            }
+        }
+    }
+
+    /// Compiles a select expression.
+    ///
+    /// Selectors depending on the arguments of the message are compiled into `plural`,
+    /// `selectordinal` or `select` arguments. All other selectors are evaluated immediately.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The compiler.
+    /// - `selector`: The expression to select on.
+    /// - `variants`: The variants of the select expression.
+    /// - `scope`: The arguments of the term the expression belongs to, if any.
+    /// - `parts`: The parts to append to.
+    fn select(
+        &mut self,
+        selector: &Expression,
+        variants: &[Variant],
+        scope: Option<&HashMap<String, String>>,
+        parts: &mut Vec<Part>,
+    ) {
+        let default: &Variant = match variants.iter().find(|variant: &&Variant| variant.default) {
+            Some(variant) => variant,
+            None => return,
+        };
+
+        let (name, ordinal) = match (selector, scope) {
+            (Expression::Variable(name), None) => (name.to_owned(), false),
+            (
+                Expression::Function {
+                    name,
+                    positional,
+                    named,
+                },
+                None,
+            ) if name == "NUMBER" => match positional.first() {
+                Some(Expression::Variable(variable)) => (
+                    variable.to_owned(),
+                    named.iter().any(|(option, value)| {
+                        option == "type"
+                            && matches!(value, Expression::String(value) if value == "ordinal")
+                    }),
+                ),
+                _ => (String::new(), false),
+            },
+            _ => (String::new(), false),
+        };
+
+        if name.is_empty() {
+            let value: Option<String> = self.static_value(selector, scope);
+            let variant: &Variant = variants
+                .iter()
+                .find(|variant: &&Variant| {
+                    value.as_ref().is_some_and(|value: &String| {
+                        variant.key == *value
+                            || matches!(
+                                (variant.key.parse::<f64>(), value.parse::<f64>()),
+                                (Ok(key), Ok(value)) if key == value
+                            )
+                    })
+                })
+                .unwrap_or(default);
+            let compiled: Vec<Part> = self.pattern(&variant.pattern, scope);
+            parts.extend(compiled);
+            return;
+        }
+
+        let plural: bool = variants.iter().all(|variant: &Variant| {
+            plurals::Category::from_name(&variant.key).is_some()
+                || variant.key.parse::<f64>().is_ok()
+        });
+        let mut arms: Vec<(String, Vec<Part>)> = vec![];
+
+        for variant in variants {
+            let key: String = if plural && variant.key.parse::<f64>().is_ok() {
+                format!("={}", variant.key)
+            } else {
+                variant.key.to_owned()
+            };
+            arms.push((key, self.pattern(&variant.pattern, scope)));
+        }
+        if !arms.iter().any(|(key, _parts)| key == "other") {
+            arms.push(("other".to_owned(), self.pattern(&default.pattern, scope)));
+        }
+
+        if plural {
+            parts.push(Part::Plural {
+                name,
+                ordinal,
+                offset: 0.0,
+                arms,
+            });
+        } else {
+            parts.push(Part::Select { name, arms });
+        }
+    }
+}
+
+
+///////////////
+// FUNCTIONS //
+///////////////
+
+/// Parses a Fluent resource and compiles its messages.
+///
+/// Message values are stored using their identifier, attributes using the identifier of the
+/// message followed by a dot and the identifier of the attribute. Terms can only be referenced
+/// from messages.
+///
+/// # Parameters
+///
+/// - `source`: The content of the resource.
+///
+/// # Returns
+///
+/// A `Result` containing the messages in the ICU MessageFormat syntax or the position and
+/// description of the syntax error.
+pub(crate) fn parse(source: &str) -> Result<Vec<(String, String)>, ParseError> {
+    let mut parser: Parser = Parser {
+        characters: source.chars().collect(),
+        position: 0,
+    };
+    let resource: Resource = parser.resource()?;
+
+    let mut compiler: Compiler = Compiler {
+        messages: resource
+            .messages
+            .iter()
+            .map(|(id, entry)| (id.as_str(), entry))
+            .collect(),
+        terms: &resource.terms,
+        stack: vec![],
+    };
+    let mut messages: Vec<(String, String)> = vec![];
+
+    for (id, entry) in &resource.messages {
+        if let Some(value) = &entry.value {
+            compiler.stack = vec![id.to_owned()];
+            let parts: Vec<Part> = compiler.pattern(value, None);
+            messages.push((id.to_owned(), message_format::serialize(&parts)));
+        }
+        for (attribute, pattern) in &entry.attributes {
+            let name: String = format!("{}.{}", id, attribute);
+            compiler.stack = vec![name.to_owned()];
+            let parts: Vec<Part> = compiler.pattern(pattern, None);
+            messages.push((name, message_format::serialize(&parts)));
+        }
+    }
+
+    return Ok(messages);
+}
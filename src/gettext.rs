@@ -0,0 +1,785 @@
+// localizer-rs gettext
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::collections::HashMap;
+
+use crate::plurals;
+use crate::sources;
+use crate::sources::Catalog;
+
+
+/////////////
+// ENTRIES //
+/////////////
+
+/// Entry of a gettext catalog.
+#[derive(Debug, Clone, Default)]
+struct Entry {
+    /// The line of the entry in `.po` files or the byte offset of its translation in `.mo` files.
+    position: usize,
+    /// The context of the message.
+    context: Option<String>,
+    /// The untranslated message.
+    id: String,
+    /// The untranslated plural message.
+    plural: Option<String>,
+    /// The translations, one for every plural form.
+    translations: Vec<String>,
+    /// Whether the translation is marked as fuzzy.
+    fuzzy: bool,
+}
+
+
+//////////////////
+// PLURAL FORMS //
+//////////////////
+
+/// Binary operator of a plural expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    /// `||`
+    Or,
+    /// `&&`
+    And,
+    /// `==`
+    Equal,
+    /// `!=`
+    NotEqual,
+    /// `<`
+    Less,
+    /// `<=`
+    LessEqual,
+    /// `>`
+    Greater,
+    /// `>=`
+    GreaterEqual,
+    /// `+`
+    Add,
+    /// `-`
+    Subtract,
+    /// `*`
+    Multiply,
+    /// `/`
+    Divide,
+    /// `%`
+    Remainder,
+}
+
+impl Operator {
+    /// Returns the binding power of the operator. Higher values bind stronger.
+    fn precedence(&self) -> u8 {
+        return match self {
+            Operator::Or => 1,
+            Operator::And => 2,
+            Operator::Equal | Operator::NotEqual => 3,
+            Operator::Less | Operator::LessEqual | Operator::Greater | Operator::GreaterEqual => 4,
+            Operator::Add | Operator::Subtract => 5,
+            Operator::Multiply | Operator::Divide | Operator::Remainder => 6,
+        };
+    }
+
+    /// Applies the operator to two values.
+    fn apply(&self, left: u64, right: u64) -> u64 {
+        return match self {
+            Operator::Or => (left != 0 || right != 0) as u64,
+            Operator::And => (left != 0 && right != 0) as u64,
+            Operator::Equal => (left == right) as u64,
+            Operator::NotEqual => (left != right) as u64,
+            Operator::Less => (left < right) as u64,
+            Operator::LessEqual => (left <= right) as u64,
+            Operator::Greater => (left > right) as u64,
+            Operator::GreaterEqual => (left >= right) as u64,
+            Operator::Add => left.wrapping_add(right),
+            Operator::Subtract => left.wrapping_sub(right),
+            Operator::Multiply => left.wrapping_mul(right),
+            Operator::Divide => left.checked_div(right).unwrap_or(0),
+            Operator::Remainder => left.checked_rem(right).unwrap_or(0),
+        };
+    }
+}
+
+/// Plural expression of the `Plural-Forms` header, using the C syntax.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Expression {
+    /// The number to select the plural form for.
+    N,
+    /// A number literal.
+    Number(u64),
+    /// The logical negation `!`.
+    Not(Box<Expression>),
+    /// A binary operation.
+    Binary(Operator, Box<Expression>, Box<Expression>),
+    /// The conditional operator `condition ? then : else`.
+    Conditional(Box<Expression>, Box<Expression>, Box<Expression>),
+}
+
+impl Expression {
+    /// Evaluates the expression for the specified number.
+    fn evaluate(&self, n: u64) -> u64 {
+        return match self {
+            Expression::N => n,
+            Expression::Number(value) => *value,
+            Expression::Not(expression) => (expression.evaluate(n) == 0) as u64,
+            Expression::Binary(operator, left, right) => {
+                operator.apply(left.evaluate(n), right.evaluate(n))
+            }
+            Expression::Conditional(condition, then, otherwise) => {
+                if condition.evaluate(n) != 0 {
+                    then.evaluate(n)
+                } else {
+                    otherwise.evaluate(n)
+                }
+            }
+        };
+    }
+}
+
+/// Maximum number of nested parentheses, `!` operators and conditionals of a plural expression.
+///
+/// The parser is recursive, so deeper expressions in untrusted catalogs would overflow the stack.
+const MAXIMUM_DEPTH: usize = 64;
+
+/// Maximum number of binary operators of a plural expression.
+///
+/// Chained operators like `n+n+n` are nested in the parsed expression, which is evaluated
+/// recursively.
+const MAXIMUM_OPERATORS: usize = 256;
+
+/// Parser for plural expressions.
+struct ExpressionParser<'a> {
+    /// The bytes of the expression.
+    source: &'a [u8],
+    /// The current position.
+    position: usize,
+    /// The number of nested parentheses, `!` operators and conditionals at the current position.
+    depth: usize,
+    /// The number of binary operators parsed so far.
+    operators: usize,
+}
+
+impl<'a> ExpressionParser<'a> {
+    /// Skips whitespace and returns the current byte.
+    fn peek(&mut self) -> Option<u8> {
+        while self
+            .source
+            .get(self.position)
+            .is_some_and(u8::is_ascii_whitespace)
+        {
+            self.position += 1;
+        }
+
+        return self.source.get(self.position).copied();
+    }
+
+    /// Parses a nested expression, failing if the expression is nested too deeply.
+    fn nested(
+        &mut self,
+        parse: impl FnOnce(&mut ExpressionParser<'a>) -> Result<Expression, String>,
+    ) -> Result<Expression, String> {
+        if self.depth >= MAXIMUM_DEPTH {
+            return Err(format!(
+                "The plural expression is nested more than {} levels deep",
+                MAXIMUM_DEPTH
+            ));
+        }
+
+        self.depth += 1;
+        let expression: Result<Expression, String> = parse(self);
+        self.depth -= 1;
+
+        return expression;
+    }
+
+    /// Parses a conditional expression.
+    fn conditional(&mut self) -> Result<Expression, String> {
+        let condition: Expression = self.binary(1)?;
+
+        if self.peek() != Some(b'?') {
+            return Ok(condition);
+        }
+        self.position += 1;
+        let then: Expression = self.nested(ExpressionParser::conditional)?;
+
+        if self.peek() != Some(b':') {
+            return Err("Expected ':' in the plural expression".to_owned());
+        }
+        self.position += 1;
+        let otherwise: Expression = self.nested(ExpressionParser::conditional)?;
+
+        return Ok(Expression::Conditional(
+            Box::new(condition),
+            Box::new(then),
+            Box::new(otherwise),
+        ));
+    }
+
+    /// Parses the next binary operator without consuming it.
+    fn operator(&mut self) -> Option<(Operator, usize)> {
+        let first: u8 = self.peek()?;
+        let second: Option<u8> = self.source.get(self.position + 1).copied();
+
+        return match (first, second) {
+            (b'|', Some(b'|')) => Some((Operator::Or, 2)),
+            (b'&', Some(b'&')) => Some((Operator::And, 2)),
+            (b'=', Some(b'=')) => Some((Operator::Equal, 2)),
+            (b'!', Some(b'=')) => Some((Operator::NotEqual, 2)),
+            (b'<', Some(b'=')) => Some((Operator::LessEqual, 2)),
+            (b'>', Some(b'=')) => Some((Operator::GreaterEqual, 2)),
+            (b'<', _) => Some((Operator::Less, 1)),
+            (b'>', _) => Some((Operator::Greater, 1)),
+            (b'+', _) => Some((Operator::Add, 1)),
+            (b'-', _) => Some((Operator::Subtract, 1)),
+            (b'*', _) => Some((Operator::Multiply, 1)),
+            (b'/', _) => Some((Operator::Divide, 1)),
+            (b'%', _) => Some((Operator::Remainder, 1)),
+            _ => None,
+        };
+    }
+
+    /// Parses binary operations with at least the specified precedence.
+    fn binary(&mut self, precedence: u8) -> Result<Expression, String> {
+        let mut left: Expression = self.unary()?;
+
+        while let Some((operator, length)) = self.operator() {
+            if operator.precedence() < precedence {
+                break;
+            }
+            self.operators += 1;
+            if self.operators > MAXIMUM_OPERATORS {
+                return Err(format!(
+                    "The plural expression has more than {} operators",
+                    MAXIMUM_OPERATORS
+                ));
+            }
+            self.position += length;
+            let right: Expression = self.binary(operator.precedence() + 1)?;
+            left = Expression::Binary(operator, Box::new(left), Box::new(right));
+        }
+
+        return Ok(left);
+    }
+
+    /// Parses a unary expression.
+    fn unary(&mut self) -> Result<Expression, String> {
+        return match self.peek() {
+            Some(b'!') => {
+                self.position += 1;
+                Ok(Expression::Not(Box::new(
+                    self.nested(ExpressionParser::unary)?,
+                )))
+            }
+            Some(b'(') => {
+                self.position += 1;
+                let expression: Expression = self.nested(ExpressionParser::conditional)?;
+                if self.peek() != Some(b')') {
+                    return Err("Expected ')' in the plural expression".to_owned());
+                }
+                self.position += 1;
+                Ok(expression)
+            }
+            Some(b'n') => {
+                self.position += 1;
+                Ok(Expression::N)
+            }
+            Some(byte) if byte.is_ascii_digit() => {
+                let mut value: u64 = 0;
+                while let Some(digit) = self
+                    .source
+                    .get(self.position)
+                    .filter(|byte: &&u8| byte.is_ascii_digit())
+                {
+                    value = value.wrapping_mul(10).wrapping_add((digit - b'0') as u64);
+                    self.position += 1;
+                }
+                Ok(Expression::Number(value))
+            }
+            _ => Err("Expected an operand in the plural expression".to_owned()),
+        };
+    }
+}
+
+/// Plural forms of a gettext catalog, as specified by the `Plural-Forms` header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PluralForms {
+    /// The number of plural forms.
+    count: usize,
+    /// The expression selecting the index of the plural form.
+    expression: Expression,
+}
+
+impl Default for PluralForms {
+    /// Returns the germanic plural forms `nplurals=2; plural=n != 1;` used by gettext if the
+    /// header is missing.
+    fn default() -> PluralForms {
+        return PluralForms {
+            count: 2,
+            expression: Expression::Binary(
+                Operator::NotEqual,
+                Box::new(Expression::N),
+                Box::new(Expression::Number(1)),
+            ),
+        };
+    }
+}
+
+impl PluralForms {
+    /// Parses the value of a `Plural-Forms` header like `nplurals=2; plural=n != 1;`.
+    ///
+    /// # Parameters
+    ///
+    /// - `header`: The value of the header.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the plural forms or the description of the syntax error.
+    fn parse(header: &str) -> Result<PluralForms, String> {
+        let mut count: Option<usize> = None;
+        let mut expression: Option<Expression> = None;
+
+        for field in header.split(';') {
+            let (name, value) = match field.split_once('=') {
+                Some((name, value)) => (name.trim(), value.trim()),
+                None => continue,
+            };
+
+            match name {
+                "nplurals" => match value.parse::<usize>() {
+                    Ok(value) if value > 0 => count = Some(value),
+                    _ => return Err(format!("Invalid number of plural forms: {:?}", value)),
+                },
+                "plural" => {
+                    let mut parser: ExpressionParser = ExpressionParser {
+                        source: value.as_bytes(),
+                        position: 0,
+                        depth: 0,
+                        operators: 0,
+                    };
+                    expression = Some(parser.conditional()?);
+                    if parser.peek().is_some() {
+                        return Err("Unexpected characters after the plural expression".to_owned());
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        return match (count, expression) {
+            (Some(count), Some(expression)) => Ok(PluralForms { count, expression }),
+            _ => Err("Expected 'nplurals' and 'plural' in the Plural-Forms header".to_owned()),
+        };
+    }
+
+    /// Returns the index of the plural form to use.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The plural forms.
+    /// - `count`: The number to select the plural form for. The last plural form is used if this
+    ///   is `None`.
+    ///
+    /// # Returns
+    ///
+    /// The index of the plural form.
+    pub(crate) fn index(&self, count: Option<&plurals::Operands>) -> usize {
+        return match count {
+            Some(operands) => (self.expression.evaluate(operands.i) as usize).min(self.count - 1),
+            None => self.count - 1,
+        };
+    }
+}
+
+
+///////////////
+// PO PARSER //
+///////////////
+
+/// Keyword of a `.po` file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Keyword {
+    /// `msgctxt`
+    Context,
+    /// `msgid`
+    Id,
+    /// `msgid_plural`
+    Plural,
+    /// `msgstr` or `msgstr[index]`
+    Translation(usize),
+}
+
+/// Unescapes a quoted `.po` string.
+///
+/// # Parameters
+///
+/// - `quoted`: The string including its quotes.
+///
+/// # Returns
+///
+/// A `Result` containing the unescaped string or the column and description of the syntax error.
+fn unquote(quoted: &str) -> Result<String, (usize, String)> {
+    let characters: Vec<char> = quoted.chars().collect();
+
+    if characters.len() < 2 || characters[0] != '"' || characters[characters.len() - 1] != '"' {
+        return Err((0, "Expected a quoted string".to_owned()));
+    }
+
+    let mut value: String = String::new();
+    let mut index: usize = 1;
+    while index < characters.len() - 1 {
+        let character: char = characters[index];
+        index += 1;
+
+        if character == '"' {
+            return Err((index - 1, "Unescaped quote".to_owned()));
+        }
+        if character != '\\' {
+            value.push(character);
+            continue;
+        }
+
+        let escaped: char = match characters.get(index) {
+            Some(escaped) if index < characters.len() - 1 => *escaped,
+            _ => return Err((index - 1, "Unterminated escape sequence".to_owned())),
+        };
+        index += 1;
+
+        match escaped {
+            'n' => value.push('\n'),
+            't' => value.push('\t'),
+            'r' => value.push('\r'),
+            'a' => value.push('\x07'),
+            'b' => value.push('\x08'),
+            'f' => value.push('\x0c'),
+            'v' => value.push('\x0b'),
+            '"' | '\\' | '\'' | '?' => value.push(escaped),
+            '0'..='7' => {
+                let mut code: u32 = escaped.to_digit(8).unwrap_or(0);
+                for _digit in 0..2 {
+                    match characters.get(index).and_then(|c: &char| c.to_digit(8)) {
+                        Some(digit) if index < characters.len() - 1 => {
+                            code = code * 8 + digit;
+                            index += 1;
+                        }
+                        _ => break,
+                    }
+                }
+                value.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+            }
+            _ => {
+                return Err((
+                    index - 2,
+                    format!("Invalid escape sequence '\\{}'", escaped),
+                ))
+            }
+        }
+    }
+
+    return Ok(value);
+}
+
+/// Parses the entries of a `.po` file.
+///
+/// # Parameters
+///
+/// - `source`: The content of the file.
+///
+/// # Returns
+///
+/// A `Result` containing the entries or the line, column and description of the syntax error.
+fn po_entries(source: &str) -> Result<Vec<Entry>, (usize, usize, String)> {
+    let mut entries: Vec<Entry> = vec![];
+    let mut entry: Entry = Entry::default();
+    let mut keyword: Option<Keyword> = None;
+    let mut fuzzy: bool = false;
+
+    for (index, raw_line) in source.lines().enumerate() {
+        let line_number: usize = index + 1;
+        let indent: usize = raw_line.len() - raw_line.trim_start().len();
+        let line: &str = raw_line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(comment) = line.strip_prefix('#') {
+            if let Some(flags) = comment.strip_prefix(',') {
+                fuzzy = fuzzy || flags.split(',').any(|flag: &str| flag.trim() == "fuzzy");
+            }
+            continue;
+        }
+
+        let (name, rest) = match line.find(|character: char| character.is_whitespace()) {
+            Some(position) if !line.starts_with('"') => (&line[..position], &line[position..]),
+            _ => ("", line),
+        };
+        let column: usize = indent + 1 + name.len() + (rest.len() - rest.trim_start().len());
+        let value: String = unquote(rest.trim())
+            .map_err(|(offset, reason)| (line_number, column + offset, reason))?;
+
+        let next: Keyword = match name {
+            "" => match keyword {
+                Some(keyword) => keyword,
+                None => return Err((line_number, indent + 1, "Unexpected string".to_owned())),
+            },
+            "msgctxt" => Keyword::Context,
+            "msgid" => Keyword::Id,
+            "msgid_plural" => Keyword::Plural,
+            "msgstr" => Keyword::Translation(0),
+            _ => match name
+                .strip_prefix("msgstr[")
+                .and_then(|rest: &str| rest.strip_suffix(']'))
+                .and_then(|index: &str| index.parse::<usize>().ok())
+            {
+                Some(index) => Keyword::Translation(index),
+                None => {
+                    return Err((
+                        line_number,
+                        indent + 1,
+                        format!("Unknown keyword {:?}", name),
+                    ));
+                }
+            },
+        };
+
+        let starts_entry: bool = !name.is_empty()
+            && matches!(next, Keyword::Context | Keyword::Id)
+            && matches!(keyword, Some(Keyword::Translation(_)));
+        if starts_entry {
+            entries.push(std::mem::take(&mut entry));
+        }
+        if !name.is_empty() && matches!(next, Keyword::Context | Keyword::Id) && entry.position == 0
+        {
+            entry.position = line_number;
+            entry.fuzzy = fuzzy;
+            fuzzy = false;
+        }
+
+        match next {
+            Keyword::Context => entry
+                .context
+                .get_or_insert_with(String::new)
+                .push_str(&value),
+            Keyword::Id => entry.id.push_str(&value),
+            Keyword::Plural => entry
+                .plural
+                .get_or_insert_with(String::new)
+                .push_str(&value),
+            Keyword::Translation(index) => {
+                if !name.is_empty() && index != entry.translations.len() {
+                    return Err((
+                        line_number,
+                        indent + 1,
+                        format!("Expected msgstr[{}]", entry.translations.len()),
+                    ));
+                }
+                if !name.is_empty() {
+                    entry.translations.push(String::new());
+                }
+                entry.translations[index].push_str(&value);
+            }
+        }
+        keyword = Some(next);
+    }
+
+    if keyword.is_some() {
+        entries.push(entry);
+    }
+
+    return Ok(entries);
+}
+
+
+///////////////
+// MO PARSER //
+///////////////
+
+/// Parses the entries of a `.mo` file.
+///
+/// # Parameters
+///
+/// - `bytes`: The content of the file.
+///
+/// # Returns
+///
+/// A `Result` containing the entries or the byte offset and description of the error.
+fn mo_entries(bytes: &[u8]) -> Result<Vec<Entry>, (usize, String)> {
+    let word = |offset: usize, big_endian: bool| -> Result<usize, (usize, String)> {
+        let word: [u8; 4] = match bytes.get(offset..offset + 4) {
+            Some(word) => [word[0], word[1], word[2], word[3]],
+            None => return Err((offset, "Unexpected end of file".to_owned())),
+        };
+
+        if big_endian {
+            return Ok(u32::from_be_bytes(word) as usize);
+        }
+
+        return Ok(u32::from_le_bytes(word) as usize);
+    };
+
+    let big_endian: bool = match word(0, false)? {
+        0x950412de => false,
+        0xde120495 => true,
+        _ => return Err((0, "Invalid magic number".to_owned())),
+    };
+    if word(4, big_endian)? >> 16 > 1 {
+        return Err((4, "Unsupported file format revision".to_owned()));
+    }
+
+    let count: usize = word(8, big_endian)?;
+    let originals: usize = word(12, big_endian)?;
+    let translations: usize = word(16, big_endian)?;
+
+    let string = |table: usize, index: usize| -> Result<String, (usize, String)> {
+        let descriptor: usize = table + index * 8;
+        let length: usize = word(descriptor, big_endian)?;
+        let offset: usize = word(descriptor + 4, big_endian)?;
+
+        return match bytes.get(offset..offset + length) {
+            Some(value) => match String::from_utf8(value.to_vec()) {
+                Ok(value) => Ok(value),
+                Err(_error) => Err((offset, "String is not valid UTF-8".to_owned())),
+            },
+            None => Err((descriptor, "String is out of bounds".to_owned())),
+        };
+    };
+
+    let mut entries: Vec<Entry> = vec![];
+    for index in 0..count {
+        let original: String = string(originals, index)?;
+        let (context, original) = match original.split_once('\u{4}') {
+            Some((context, original)) => (Some(context.to_owned()), original.to_owned()),
+            None => (None, original),
+        };
+        let (id, plural) = match original.split_once('\0') {
+            Some((id, plural)) => (id.to_owned(), Some(plural.to_owned())),
+            None => (original, None),
+        };
+
+        entries.push(Entry {
+            position: translations + index * 8,
+            context,
+            id,
+            plural,
+            translations: string(translations, index)?
+                .split('\0')
+                .map(str::to_owned)
+                .collect(),
+            fuzzy: false,
+        });
+    }
+
+    return Ok(entries);
+}
+
+
+///////////////
+// FUNCTIONS //
+///////////////
+
+/// Builds a catalog from gettext entries.
+///
+/// Messages are stored using their `msgid`, prefixed by their `msgctxt` and the
+/// [`sources::CONTEXT_SEPARATOR`] if they have a context. Plural messages are stored as json arrays containing the translations of all plural
+/// forms. Untranslated and fuzzy entries are skipped.
+///
+/// # Parameters
+///
+/// - `entries`: The entries of the catalog.
+///
+/// # Returns
+///
+/// A `Result` containing the catalog or the position of the header entry and the description of
+/// the error.
+fn catalog(entries: Vec<Entry>) -> Result<Catalog, (usize, String)> {
+    let mut messages: HashMap<String, serde_json::Value> = HashMap::new();
+    let mut plural_forms: PluralForms = PluralForms::default();
+
+    for entry in entries {
+        if entry.id.is_empty() && entry.context.is_none() {
+            let header: &str = entry.translations.first().map_or("", String::as_str);
+            for line in header.lines() {
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.trim().eq_ignore_ascii_case("Plural-Forms") {
+                        plural_forms = PluralForms::parse(value)
+                            .map_err(|reason: String| (entry.position, reason))?;
+                    }
+                }
+            }
+            continue;
+        }
+
+        if entry.fuzzy || entry.translations.iter().all(String::is_empty) {
+            continue;
+        }
+
+        let key: String = match entry.context {
+            Some(context) => format!("{}{}{}", context, sources::CONTEXT_SEPARATOR, entry.id),
+            None => entry.id,
+        };
+        let value: serde_json::Value = match entry.plural {
+            Some(_plural) => serde_json::Value::Array(
+                entry
+                    .translations
+                    .into_iter()
+                    .map(serde_json::Value::String)
+                    .collect(),
+            ),
+            None => serde_json::Value::String(entry.translations.concat()),
+        };
+        messages.insert(key, value);
+    }
+
+    return Ok(Catalog {
+        messages,
+        syntax: None,
+        plural_forms: Some(plural_forms),
+    });
+}
+
+/// Parses a `.po` file.
+///
+/// # Parameters
+///
+/// - `source`: The content of the file.
+///
+/// # Returns
+///
+/// A `Result` containing the catalog or the line, column and description of the error.
+pub(crate) fn parse_po(source: &str) -> Result<Catalog, (usize, usize, String)> {
+    return catalog(po_entries(source)?).map_err(|(line, reason)| (line, 1, reason));
+}
+
+/// Parses a `.mo` file.
+///
+/// # Parameters
+///
+/// - `bytes`: The content of the file.
+///
+/// # Returns
+///
+/// A `Result` containing the catalog or the byte offset and description of the error.
+pub(crate) fn parse_mo(bytes: &[u8]) -> Result<Catalog, (usize, String)> {
+    return catalog(mo_entries(bytes)?);
+}
@@ -0,0 +1,53 @@
+// localizer-rs keys
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use crate::sources::Catalog;
+
+pub(crate) use localizer_rs_shared::split;
+
+
+///////////////////
+// KEY FUNCTIONS //
+///////////////////
+
+/// Resolves a key in a catalog.
+///
+/// Keys present at the top level of the catalog are returned directly, all other keys are split
+/// into their segments, which are looked up in the nested json objects.
+///
+/// # Parameters
+///
+/// - `catalog`: The catalog to search.
+/// - `key`: The key to resolve.
+///
+/// # Returns
+///
+/// A `Result` containing the json value of the key or the index of the first segment that could
+/// not be resolved.
+pub(crate) fn resolve<'a>(catalog: &'a Catalog, key: &str) -> Result<&'a serde_json::Value, usize> {
+    return localizer_rs_shared::resolve(&catalog.messages, key);
+}
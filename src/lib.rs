@@ -1,4 +1,5 @@
 #![doc = include_str!("../.github/README.md")]
+#![allow(clippy::needless_return)]
 // localizer-rs
 // Version: 1.2.0
 
@@ -26,18 +27,197 @@
 // EXPORTS //
 /////////////
 
+pub mod arguments;
+pub mod codegen;
+#[cfg(feature = "chrono")]
+pub mod dates;
 pub mod errors;
+pub mod lists;
+pub mod numbers;
+pub mod plurals;
+pub mod relative;
+pub mod sources;
+pub mod styles;
+
+mod cache;
+mod fluent;
+mod gettext;
+mod keys;
+mod message_format;
+mod placeholders;
+
+/// Embeds the translation files of a directory into the binary at compile time.
+///
+/// The directory is relative to the directory containing the `Cargo.toml` file of the crate
+/// using the macro. All `.json`, `.ftl`, `.po` and `.mo` files of the directory are embedded, so
+/// no file system access is needed at runtime. Changes to the embedded files trigger a rebuild.
+/// The `theme.json` file of the directory is embedded as the theme of the source, which is
+/// applied by [`Config::with_source()`].
+///
+/// Requires the `macros` feature, which is enabled by default.
+///
+/// # Parameters
+///
+/// - `path`: The directory containing the translation files. Has to be a string literal.
+///
+/// # Returns
+///
+/// A [`sources::Embedded`] catalog source containing the translation files.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// let config: localizer_rs::Config = localizer_rs::Config::with_source(
+///     localizer_rs::embed!("examples/translations"),
+///     "en",
+/// );
+///
+/// assert_eq!(config.t("test", vec![]), "Something that can be translated.");
+/// ```
+///
+/// # See also
+///
+/// - [`Config::with_source()`]
+/// - [`sources::Embedded`]
+#[cfg(feature = "macros")]
+pub use localizer_rs_macros::embed;
+
+/// Translates the specified key after checking it against a reference translation file at compile
+/// time.
+///
+/// Works like [`t!`], but reads the reference file while compiling. Keys that can not be found in
+/// the reference file and argument names that the message does not contain are reported as
+/// compile errors instead of failing at runtime. Unknown keys include a suggestion for the closest
+/// existing key. Changes to the reference file trigger a rebuild.
+///
+/// The reference file is a json translation file, relative to the directory containing the
+/// `Cargo.toml` file of the crate using the macro. Only the key and the argument names are
+/// checked, the translation itself is looked up in the language of the config at runtime.
+///
+/// Requires the `macros` feature, which is enabled by default.
+///
+/// # Parameters
+///
+/// - `reference`: The path of the reference translation file. Has to be a string literal.
+/// - `config`: The config object.
+/// - `key`: The key to translate to. Has to be a string literal.
+/// - `count`: Optional parameter. The number to select the plural form for. Has to be of type
+///   `count = value` and precede the arguments.
+/// - `arguments`: Optional parameter. The arguments to replace, like in [`t!`]. Has to be of type
+///   `"name" = value`, `name = value` or `name`.
+///
+/// # Returns
+///
+/// A `String` containing the translated value.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// # let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+/// let translation: String = localizer_rs::checked_t!(
+///     "examples/translations/en.json",
+///     config,
+///     "success",
+///     "balance" = "10€",
+///     "user" = "Bob",
+/// );
+/// localizer_rs::checked_t!("examples/translations/en.json", config, "files", count = 3);
+/// ```
+///
+/// Misspelled keys do not compile:
+///
+/// ```compile_fail
+/// # use localizer_rs;
+/// # let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+/// // error: Could not find translation key "sucess", did you mean "success"?
+/// localizer_rs::checked_t!("examples/translations/en.json", config, "sucess");
+/// ```
+///
+/// Neither do arguments the message does not contain:
+///
+/// ```compile_fail
+/// # use localizer_rs;
+/// # let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+/// // error: Translation "test" does not contain the placeholder "variable"
+/// localizer_rs::checked_t!("examples/translations/en.json", config, "test", "variable" = "content");
+/// ```
+///
+/// Style placeholders are not arguments either:
+///
+/// ```compile_fail
+/// # use localizer_rs;
+/// # let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+/// // error: Translation "error" does not contain the placeholder "bold"
+/// localizer_rs::checked_t!("examples/translations/en.json", config, "error", "bold" = "content");
+/// ```
+///
+/// To avoid repeating the reference file, wrap the macro in a macro of your own:
+///
+/// ```rust
+/// # use localizer_rs;
+/// macro_rules! tr {
+///     ($($arguments:tt)*) => {
+///         localizer_rs::checked_t!("examples/translations/en.json", $($arguments)*)
+///     };
+/// }
+///
+/// let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+/// assert_eq!(tr!(config, "test"), "Something that can be translated.");
+/// ```
+///
+/// # See also
+///
+/// - [`t!`]
+/// - [`Config::t()`]
+/// - [`Config::translate_plural()`]
+#[cfg(feature = "macros")]
+pub use localizer_rs_macros::checked_t;
 
 
 ////////////////////////////////
 // IMPORTS AND USE STATEMENTS //
 ////////////////////////////////
 
-use std::fs::File;
-use std::io::BufReader;
 use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
 
-use serde_json;
+
+////////////
+// SYNTAX //
+////////////
+
+/// Syntax of the translated messages.
+///
+/// # Variants
+///
+/// - `Placeholders`: Arguments are replaced using `{{name}}` placeholders. This is the default.
+/// - `MessageFormat`: Messages are evaluated as [ICU MessageFormat](https://unicode-org.github.io/icu/userguide/format_parse/messages/)
+///   messages before the `{{name}}` placeholders are replaced.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// let mut config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+/// config.set_syntax(localizer_rs::Syntax::MessageFormat);
+///
+/// assert_eq!(
+///     config.translate("messages", vec![("count", "1")]),
+///     "You have one new message."
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Syntax {
+    /// Arguments are replaced using `{{name}}` placeholders.
+    #[default]
+    Placeholders,
+    /// Messages are evaluated as ICU MessageFormat messages, supporting `{name}` arguments as well
+    /// as `plural`, `selectordinal` and `select` arguments.
+    MessageFormat,
+}
 
 
 ///////////////////
@@ -53,27 +233,66 @@ use serde_json;
 /// - `path`: The directory containing the translation files.
 ///   The directory is relative to the path the executable was executed from.
 /// - `language`: The language to translate to.
+/// - `fallbacks`: The languages to try if a key is missing in `language`.
+/// - `syntax`: The syntax of the translated messages.
+/// - `color_mode`: Whether style placeholders are replaced with terminal formatting codes.
+/// - `color_depth`: The colors supported by the terminal.
+/// - `theme`: The styles of `{{style.name}}` placeholders.
+/// - `renderer`: How style placeholders are rendered.
 ///
 /// # Returns
 ///
 /// A new `Config` object with the specified path and language.
 ///
+/// The parsed translation files are cached per language, so every file is only read once. Use
+/// [`Config::reload()`] or [`Config::clear_cache()`] after changing the translation files.
+///
+/// Translations are read from the files in `path` unless a custom source is set using
+/// [`Config::with_source()`] or [`Config::set_source()`].
+///
+/// Keys missing in the configured language are looked up in the fallback chain returned by
+/// [`Config::fallback_chain()`].
+///
+/// Config objects contain private state and can therefore not be created using a struct literal
+/// like `Config { path, language }`, use [`Config::new()`] or [`Config::try_new()`] instead. Two
+/// config objects are equal if their public fields are equal and they use the same catalog
+/// source. The cached translation files are not compared.
+///
 /// # Examples
 ///
 /// ```rust
 /// # use localizer_rs;
-/// localizer_rs::Config {
-///     path: "path".to_owned(),
-///     language: "language".to_owned()
-/// };
+/// let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+///
+/// assert_eq!(config.path, "examples/translations");
+/// assert_eq!(config.language, "en");
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Config {
     /// The directory containing the translation files. The directory is relative to the path the
     /// executable was executed from.
     pub path: String,
     /// The language to translate to.
     pub language: String,
+    /// The languages to try, in order, if a key is missing in `language`.
+    pub fallbacks: Vec<String>,
+    /// The syntax of the translated messages.
+    pub syntax: Syntax,
+    /// Whether style placeholders like `{{bold}}` are replaced with terminal formatting codes.
+    pub color_mode: styles::ColorMode,
+    /// The colors supported by the terminal, colors that are not supported are replaced by the
+    /// closest supported color.
+    pub color_depth: styles::ColorDepth,
+    /// The styles of `{{style.name}}` placeholders. Setting the path loads the `theme.json` file in
+    /// the path, or the default theme if it does not exist.
+    pub theme: styles::Theme,
+    /// How style placeholders like `{{bold}}` are rendered, eg. as terminal formatting codes or as
+    /// html tags.
+    pub renderer: styles::Renderer,
+    /// The source of the catalogs, if it is not the translation path.
+    source: sources::SharedSource,
+    /// The parsed translation files.
+    cache: cache::Cache,
 }
 
 
@@ -108,14 +327,107 @@ impl Config {
     /// # See also
     ///
     /// - [`Config`]
+    /// - [`Config::try_new()`]
     pub fn new(path: &str, language: &str) -> Config {
+        return match Config::try_new(path, language) {
+            Ok(value) => value,
+            Err(error) => error.raise(),
+        };
+    }
+
+    /// Creates a new config object without exiting on errors.
+    ///
+    /// # Parameters
+    ///
+    /// - `path`: The directory containing the translation files.
+    ///   The directory is relative to the path the executable was executed from.
+    /// - `language`: The language to translate to.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the new `Config` object or the error that occurred while setting the
+    /// path.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// let config: localizer_rs::Config =
+    ///     localizer_rs::Config::try_new("examples/translations", "en").unwrap();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`Config::new()`]
+    pub fn try_new(path: &str, language: &str) -> Result<Config, errors::Error> {
+        let mut config: Config = Config::with_defaults(language);
+        config.try_set_path(path)?;
+
+        return Ok(config);
+    }
+
+    /// Creates a config object without a path, using the default settings.
+    ///
+    /// # Parameters
+    ///
+    /// - `language`: The language to translate to.
+    ///
+    /// # Returns
+    ///
+    /// A new `Config` object with the specified language, which has to be given a path or a
+    /// catalog source.
+    fn with_defaults(language: &str) -> Config {
         let mut config: Config = Config {
             path: "".to_string(),
             language: "".to_string(),
-        }
-        .to_owned();
-        config = config.set_language(language).to_owned();
-        config = config.set_path(path).to_owned();
+            fallbacks: vec![],
+            syntax: Syntax::default(),
+            color_mode: styles::ColorMode::default(),
+            color_depth: styles::ColorDepth::default(),
+            theme: styles::Theme::default(),
+            renderer: styles::Renderer::default(),
+            source: sources::SharedSource::default(),
+            cache: cache::Cache::default(),
+        };
+        config.set_language(language);
+
+        return config;
+    }
+
+    /// Creates a new config object loading its translations from the specified catalog source.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: The source of the catalogs.
+    /// - `language`: The language to translate to.
+    ///
+    /// # Returns
+    ///
+    /// A new `Config` object with the specified source and language. The path of the config
+    /// object is empty. The theme of the source, like the `theme.json` file of an
+    /// [`sources::Embedded`] source, replaces the default theme. Exits if that theme is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// let config: localizer_rs::Config = localizer_rs::Config::with_source(
+    ///     localizer_rs::sources::Files::new("examples/translations"),
+    ///     "en",
+    /// );
+    ///
+    /// assert_eq!(config.t("test", vec![]), "Something that can be translated.");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`Config::set_source()`]
+    /// - [`sources::CatalogSource`]
+    pub fn with_source<S: sources::CatalogSource + 'static>(source: S, language: &str) -> Config {
+        let mut config: Config = Config::with_defaults(language);
+        config.set_source(source);
 
         return config;
     }
@@ -147,32 +459,126 @@ impl Config {
     /// # See also
     ///
     /// - [`Config`]
+    /// - [`Config::try_set_path()`]
     pub fn set_path(&mut self, str_path: &str) -> &Config {
+        if let Err(error) = self.try_set_path(str_path) {
+            error.raise();
+        }
+
+        return self;
+    }
+
+    /// Sets the path for the config object without exiting on errors.
+    ///
+    /// The config object is left unchanged if an error occurs.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object. This must be mutable.
+    /// - `str_path`: The directory containing the translation files.
+    ///   The directory is relative to the path the executable was executed from.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the modified `Config` object or the error that occurred.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - The path does not exist
+    /// - The path could not be opened
+    /// - The path is not valid unicode
+    /// - The `theme.json` file in the path is not a valid theme
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// # let mut config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "language");
+    /// assert!(config.try_set_path("examples").is_ok());
+    /// assert!(config.try_set_path("does_not_exist").is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`Config::set_path()`]
+    pub fn try_set_path(&mut self, str_path: &str) -> Result<&Config, errors::Error> {
         let path: &Path = Path::new(str_path);
 
         match path.try_exists() {
             Ok(value) => {
                 if !value {
-                    let error: errors::Error =
-                        errors::Error::new("OS Error", "Translation path was not found", 1);
-                    error.raise(format!("Path: {:?}", str_path).as_str());
+                    return Err(errors::Error::PathNotFound {
+                        path: str_path.to_owned(),
+                    });
                 }
             }
             Err(_error) => {
-                let error: errors::Error = errors::Error::new("OS Error", "Could not open path", 2);
-                error.raise(format!("Path: {:?}\nDetails: {}", str_path, _error).as_str());
+                return Err(errors::Error::PathUnreadable {
+                    path: str_path.to_owned(),
+                    source: _error,
+                });
             }
         }
 
-        self.path = String::from(match path.to_owned().to_str() {
+        let str_path: &str = match path.to_str() {
             Some(value) => value,
             None => {
-                let error: errors::Error =
-                    errors::Error::new("OS Error", "Path does not seem to be valid", 3);
-                error.raise(format!("Path: {:?}", str_path).as_str());
-                ""
+                return Err(errors::Error::InvalidPath {
+                    path: str_path.to_owned(),
+                });
             }
-        });
+        };
+        let theme_path: PathBuf = path.join("theme.json");
+        let theme: styles::Theme = match theme_path.is_file() {
+            true => styles::Theme::try_from_file(&theme_path.to_string_lossy())?,
+            false => styles::Theme::default(),
+        };
+
+        self.path = String::from(str_path);
+        self.theme = theme;
+        self.source = sources::SharedSource::default();
+        self.cache.clear();
+        return Ok(self);
+    }
+
+    /// Sets the catalog source for the config object.
+    ///
+    /// The source replaces the translation files of the config path until a new path is set.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object. This must be mutable.
+    /// - `source`: The source of the catalogs.
+    ///
+    /// # Returns
+    ///
+    /// The modified `Config` object with the specified source. The theme of the source replaces
+    /// the theme of the config object, exiting if it is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// # let mut config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+    /// config.set_source(localizer_rs::sources::Files::new("tests/translations"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`Config::with_source()`]
+    /// - [`sources::CatalogSource`]
+    pub fn set_source<S: sources::CatalogSource + 'static>(&mut self, source: S) -> &Config {
+        match source.theme() {
+            Ok(Some(theme)) => self.theme = theme,
+            Ok(None) => {}
+            Err(error) => error.raise(),
+        }
+        self.source = sources::SharedSource::new(Arc::new(source));
+        self.cache.clear();
         return self;
     }
 
@@ -203,211 +609,1265 @@ impl Config {
         return self;
     }
 
-    /// Translates the specified key in the language specified in the config.
+    /// Sets the syntax of the translated messages for the config object.
     ///
     /// # Parameters
     ///
-    /// - `self`: The config object.
-    /// - `key`: The key to translate to.
-    /// - `arguments`: The arguments to replace.
+    /// - `self`: The config object. This must be mutable.
+    /// - `syntax`: The syntax of the translated messages.
     ///
     /// # Returns
     ///
-    /// A `String` containing the translated value.
+    /// The modified `Config` object with the specified syntax.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use localizer_rs;
-    /// # let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
-    /// config.t("test", vec![]);
+    /// # let mut config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+    /// config.set_syntax(localizer_rs::Syntax::MessageFormat);
     /// ```
     ///
     /// # See also
     ///
-    /// - [`t!()`]
     /// - [`Config`]
-    pub fn t(&self, key: &str, arguments: Vec<(&str, &str)>) -> String {
-        return self.translate(key, arguments);
+    /// - [`Syntax`]
+    pub fn set_syntax(&mut self, syntax: Syntax) -> &Config {
+        self.syntax = syntax;
+        return self;
     }
 
-    /// Translates the specified key in the language specified in the config.
+    /// Sets the color mode for the config object.
     ///
     /// # Parameters
     ///
-    /// - `self`: The config object.
-    /// - `key`: The key to translate to.
-    /// - `arguments`: The arguments to replace.
+    /// - `self`: The config object. This must be mutable.
+    /// - `color_mode`: Whether style placeholders are replaced with terminal formatting codes.
     ///
     /// # Returns
     ///
-    /// A `String` containing the translated value.
+    /// The modified `Config` object with the specified color mode.
     ///
-    /// # Raises
+    /// # Examples
     ///
-    /// This method throws an exception and exits if
+    /// ```rust
+    /// # use localizer_rs;
+    /// # let mut config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+    /// config.set_color_mode(localizer_rs::styles::ColorMode::Auto);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`styles::ColorMode`]
+    pub fn set_color_mode(&mut self, color_mode: styles::ColorMode) -> &Config {
+        self.color_mode = color_mode;
+        return self;
+    }
+
+    /// Sets the color depth for the config object.
+    ///
+    /// # Parameters
     ///
-    /// - The translation file could not be found
-    /// - The translation file could not be opened
-    /// - The translation file could not be parsed
-    /// - The parsed json could not be converted to a json value
-    /// - The converted json could not be indexed
+    /// - `self`: The config object. This must be mutable.
+    /// - `color_depth`: The colors supported by the terminal.
+    ///
+    /// # Returns
+    ///
+    /// The modified `Config` object with the specified color depth.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use localizer_rs;
-    /// # let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
-    /// config.translate("test", vec![]);
+    /// # let mut config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+    /// config.set_color_depth(localizer_rs::styles::ColorDepth::TrueColor);
     /// ```
     ///
     /// # See also
     ///
-    /// - [`t!()`]
     /// - [`Config`]
-    /// - [`Config::t()`]
-    /// - [`serde_json`]
-    pub fn translate(&self, key: &str, mut arguments: Vec<(&str, &str)>) -> String {
-        let mut colors: Vec<(&str, &str)> = vec![
-            // Formatting codes
-            ("end", "\x1b[0m"),
-            ("bold", "\x1b[1m"),
-            ("italic", "\x1b[3m"),
-            ("underline", "\x1b[4m"),
-            ("overline", "\x1b[53m"),
-
-            // Foreground colors
-            ("color.black", "\x1b[30m"),
-            ("color.red", "\x1b[31m"),
-            ("color.green", "\x1b[32m"),
-            ("color.yellow", "\x1b[33m"),
-            ("color.blue", "\x1b[34m"),
-            ("color.magenta", "\x1b[35m"),
-            ("color.cyan", "\x1b[36m"),
-            ("color.white", "\x1b[37m"),
-
-            // Bright foreground colors
-            ("color.bright_black", "\x1b[90m"),
-            ("color.bright_red", "\x1b[91m"),
-            ("color.bright_green", "\x1b[92m"),
-            ("color.bright_yellow", "\x1b[93m"),
-            ("color.bright_blue", "\x1b[94m"),
-            ("color.bright_magenta", "\x1b[95m"),
-            ("color.bright_cyan", "\x1b[96m"),
-            ("color.bright_white", "\x1b[97m"),
-
-            // Background colors
-            ("back.black", "\x1b[40m"),
-            ("back.red", "\x1b[41m"),
-            ("back.green", "\x1b[42m"),
-            ("back.yellow", "\x1b[43m"),
-            ("back.blue", "\x1b[44m"),
-            ("back.magenta", "\x1b[45m"),
-            ("back.cyan", "\x1b[46m"),
-            ("back.white", "\x1b[47m"),
-
-            // Bright background colors
-            ("back.bright_black", "\x1b[100m"),
-            ("back.bright_red", "\x1b[101m"),
-            ("back.bright_green", "\x1b[102m"),
-            ("back.bright_yellow", "\x1b[103m"),
-            ("back.bright_blue", "\x1b[104m"),
-            ("back.bright_magenta", "\x1b[105m"),
-            ("back.bright_cyan", "\x1b[106m"),
-            ("back.bright_white", "\x1b[107m"),
-        ];
-        arguments.append(&mut colors);
-
-        let file: File = match File::open(Path::new(
-            format!("./{}/{}.json", &self.path, &self.language).as_str(),
-        )) {
-            Ok(value) => value,
-            Err(_error) => {
-                let error: errors::Error =
-                    errors::Error::new("OS Error", "Could not open translation file", 4);
-                error.raise(
-                    format!(
-                        "File: ./{}/{}.json\nError: {}",
-                        &self.path, &self.language, _error
-                    )
-                    .as_str(),
-                );
-
-                return "".to_owned();
-            }
-        };
-        let reader: BufReader<File> = BufReader::new(file);
-
-        let json: serde_json::Value = match serde_json::to_value::<serde_json::Value>(
-            match serde_json::from_reader::<BufReader<File>, serde_json::Value>(reader) {
-                Ok(value) => value,
-                Err(_error) => {
-                    let error: errors::Error = errors::Error::new(
-                        "Parsing error",
-                        "Translation file could not be parsed",
-                        5,
-                    );
-                    error.raise(
-                        format!(
-                            "File: ./{}/{}.json\nError: {}",
-                            &self.path, &self.language, _error
-                        )
-                        .as_str(),
-                    );
-
-                    return "".to_owned();
-                }
-            },
-        ) {
-            Ok(value) => value,
-            Err(_error) => {
-                let error: errors::Error =
-                    errors::Error::new("Converting error", "Could not convert to json value", 6);
-                error.raise(
-                    format!(
-                        "File: ./{}/{}.json\nError: {}",
-                        &self.path, &self.language, _error
-                    )
-                    .as_str(),
-                );
-
-                return "".to_owned();
-            }
-        }
-        .to_owned();
-        let mut result: String = match json[key].as_str() {
-            Some(value) => value.to_string(),
-            None => {
-                let error: errors::Error =
-                    errors::Error::new("Indexing error", "Could not index json value", 6);
-                error.raise(
-                    format!(
-                        "Index: {}\nFile: ./{}/{}.json",
-                        key, &self.path, &self.language
-                    )
-                    .as_str(),
-                );
-
-                return "".to_owned();
-            }
-        };
-
-        for (key, value) in arguments {
-            result = result.replace(("{{".to_owned() + key + "}}").as_str(), value);
-        }
-
-        return result;
+    /// - [`styles::ColorDepth`]
+    pub fn set_color_depth(&mut self, color_depth: styles::ColorDepth) -> &Config {
+        self.color_depth = color_depth;
+        return self;
     }
-}
 
-
-/// Translates the specified key in the language specified in the config.
-///
-/// # Parameters
+    /// Sets the theme for the config object.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object. This must be mutable.
+    /// - `theme`: The styles of `{{style.name}}` placeholders.
+    ///
+    /// # Returns
+    ///
+    /// The modified `Config` object with the specified theme.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// # let mut config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+    /// let mut theme: localizer_rs::styles::Theme = localizer_rs::styles::Theme::new();
+    /// theme.set("error", "color.#d70000 bold");
+    ///
+    /// config.set_theme(theme);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`styles::Theme`]
+    pub fn set_theme(&mut self, theme: styles::Theme) -> &Config {
+        self.theme = theme;
+        return self;
+    }
+
+    /// Sets the renderer for the config object.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object. This must be mutable.
+    /// - `renderer`: How style placeholders are rendered.
+    ///
+    /// # Returns
+    ///
+    /// The modified `Config` object with the specified renderer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// # let mut config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+    /// config.set_renderer(localizer_rs::styles::Renderer::Html);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`Config::translate_as()`]
+    /// - [`styles::Renderer`]
+    pub fn set_renderer(&mut self, renderer: styles::Renderer) -> &Config {
+        self.renderer = renderer;
+        return self;
+    }
+
+    /// Sets the fallback languages for the config object.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object. This must be mutable.
+    /// - `fallbacks`: The languages to try, in order, if a key is missing in the configured
+    ///   language.
+    ///
+    /// # Returns
+    ///
+    /// The modified `Config` object with the specified fallback languages.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// # let mut config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "de-AT");
+    /// config.set_fallbacks(&["en"]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`Config::fallback_chain()`]
+    pub fn set_fallbacks(&mut self, fallbacks: &[&str]) -> &Config {
+        self.fallbacks = fallbacks
+            .iter()
+            .map(|fallback: &&str| fallback.to_string())
+            .collect();
+        return self;
+    }
+
+    /// Returns the languages that are searched for a key, in order.
+    ///
+    /// The chain starts with the configured language, followed by the fallback languages. Every
+    /// language is followed by its truncated BCP 47 tags, so `de-AT` is followed by `de`.
+    /// Duplicate languages are removed.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` containing the languages in the order they are searched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// let mut config: localizer_rs::Config =
+    ///     localizer_rs::Config::new("examples/translations", "de-AT");
+    /// config.set_fallbacks(&["en-GB", "de"]);
+    ///
+    /// assert_eq!(config.fallback_chain(), vec!["de-AT", "de", "en-GB", "en"]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`Config::set_fallbacks()`]
+    pub fn fallback_chain(&self) -> Vec<String> {
+        let mut chain: Vec<String> = vec![];
+
+        for language in std::iter::once(&self.language).chain(self.fallbacks.iter()) {
+            let mut tag: &str = language.as_str();
+
+            loop {
+                if !tag.is_empty() && !chain.iter().any(|value: &String| value == tag) {
+                    chain.push(tag.to_owned());
+                }
+
+                match tag.rfind(['-', '_']) {
+                    Some(index) => tag = &tag[..index],
+                    None => break,
+                }
+            }
+        }
+
+        return chain;
+    }
+
+    /// Returns the language that provides the translation of the specified key.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    /// - `key`: The key to look up.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the first language in the fallback chain that contains the key.
+    ///
+    /// # Raises
+    ///
+    /// This method throws an exception and exits if
+    ///
+    /// - None of the translation files could be found
+    /// - A translation file could not be opened
+    /// - A translation file could not be parsed
+    /// - None of the translation files contain the key
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// let mut config: localizer_rs::Config =
+    ///     localizer_rs::Config::new("examples/translations", "de-AT");
+    /// config.set_fallbacks(&["en"]);
+    ///
+    /// assert_eq!(config.language_of("test"), "en");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`Config::try_language_of()`]
+    /// - [`Config::fallback_chain()`]
+    pub fn language_of(&self, key: &str) -> String {
+        return match self.try_language_of(key) {
+            Ok(value) => value,
+            Err(error) => error.raise(),
+        };
+    }
+
+    /// Returns the language that provides the translation of the specified key without exiting
+    /// on errors.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    /// - `key`: The key to look up.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the first language in the fallback chain that contains the key or
+    /// the error that occurred.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - None of the translation files could be found
+    /// - A translation file could not be opened
+    /// - A translation file could not be parsed
+    /// - None of the translation files contain the key
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// # let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+    /// assert_eq!(config.try_language_of("test").unwrap(), "en");
+    /// assert!(config.try_language_of("does_not_exist").is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`Config::language_of()`]
+    pub fn try_language_of(&self, key: &str) -> Result<String, errors::Error> {
+        let (language, _value, _syntax) = self.lookup(key, None)?;
+        return Ok(language);
+    }
+
+    /// Returns the languages provided by the catalog source.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` containing the available languages.
+    ///
+    /// # Raises
+    ///
+    /// This method throws an exception and exits if the catalog source could not list its
+    /// languages.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// # let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+    /// assert!(config.languages().contains(&"en".to_owned()));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`Config::try_languages()`]
+    /// - [`sources::CatalogSource::languages()`]
+    pub fn languages(&self) -> Vec<String> {
+        return match self.try_languages() {
+            Ok(value) => value,
+            Err(error) => error.raise(),
+        };
+    }
+
+    /// Returns the languages provided by the catalog source without exiting on errors.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the available languages or the error that occurred.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the catalog source could not list its languages.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// # let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+    /// assert_eq!(
+    ///     config.try_languages().unwrap(),
+    ///     vec!["de", "en", "es", "fr", "pl"]
+    /// );
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`Config::languages()`]
+    pub fn try_languages(&self) -> Result<Vec<String>, errors::Error> {
+        return self.source().languages();
+    }
+
+    /// Translates the specified key in the language specified in the config.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    /// - `key`: The key to translate to.
+    /// - `arguments`: The arguments to replace, eg. an [`arguments::Args`] object or a `Vec` of
+    ///   names and values.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the translated value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// # let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+    /// config.t("test", vec![]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`t!()`]
+    /// - [`Config`]
+    pub fn t<A: Into<arguments::Args>>(&self, key: &str, arguments: A) -> String {
+        return self.translate(key, arguments);
+    }
+
+    /// Translates the specified key in the language specified in the config without exiting on
+    /// errors.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    /// - `key`: The key to translate to.
+    /// - `arguments`: The arguments to replace, eg. an [`arguments::Args`] object or a `Vec` of
+    ///   names and values.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the translated value or the error that occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// # let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+    /// assert!(config.try_t("test", vec![]).is_ok());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`Config::try_translate()`]
+    pub fn try_t<A: Into<arguments::Args>>(
+        &self,
+        key: &str,
+        arguments: A,
+    ) -> Result<String, errors::Error> {
+        return self.try_translate(key, arguments);
+    }
+
+    /// Translates the specified key in the language specified in the config.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    /// - `key`: The key to translate to.
+    /// - `arguments`: The arguments to replace, eg. an [`arguments::Args`] object or a `Vec` of
+    ///   names and values.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the translated value.
+    ///
+    /// # Raises
+    ///
+    /// This method throws an exception and exits if
+    ///
+    /// - None of the translation files could be found
+    /// - A translation file could not be opened
+    /// - A translation file could not be parsed
+    /// - None of the translation files contain the key
+    /// - The translation is not a valid ICU MessageFormat message
+    /// - The style tags of the translation are not balanced
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// # let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+    /// config.translate("test", vec![]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`t!()`]
+    /// - [`Config`]
+    /// - [`Config::t()`]
+    /// - [`Config::try_translate()`]
+    /// - [`serde_json`]
+    pub fn translate<A: Into<arguments::Args>>(&self, key: &str, arguments: A) -> String {
+        return match self.try_translate(key, arguments) {
+            Ok(value) => value,
+            Err(error) => error.raise(),
+        };
+    }
+
+    /// Translates the specified key in the language specified in the config without exiting on
+    /// errors.
+    ///
+    /// Keys of nested json objects are separated by dots, eg. `menu.file.open`. Dots that are part
+    /// of a key have to be escaped with a backslash, eg. `menu.file.save\.as`.
+    ///
+    /// Plural forms are selected using the `count` argument, see [`Config::try_translate_plural()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    /// - `key`: The key to translate to.
+    /// - `arguments`: The arguments to replace, eg. an [`arguments::Args`] object or a `Vec` of
+    ///   names and values.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the translated value or the error that occurred.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - None of the translation files could be found
+    /// - A translation file could not be opened
+    /// - A translation file could not be parsed
+    /// - None of the translation files contain the key
+    /// - The translation is not a valid ICU MessageFormat message
+    /// - The style tags of the translation are not balanced
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// # let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+    /// assert!(config.try_translate("test", vec![]).is_ok());
+    /// assert!(config.try_translate("does_not_exist", vec![]).is_err());
+    /// assert_eq!(
+    ///     config.try_translate("files", localizer_rs::arguments::Args::new().with("count", 2)).unwrap(),
+    ///     "2 files"
+    /// );
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`Config::translate()`]
+    /// - [`serde_json`]
+    pub fn try_translate<A: Into<arguments::Args>>(
+        &self,
+        key: &str,
+        arguments: A,
+    ) -> Result<String, errors::Error> {
+        let arguments: arguments::Args = arguments.into();
+        let count: Option<plurals::Operands> =
+            arguments.get("count").and_then(arguments::Value::operands);
+
+        return self.render(key, count.as_ref(), arguments, self.renderer);
+    }
+
+    /// Translates the specified key using the specified renderer instead of the renderer of the
+    /// config.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    /// - `key`: The key to translate to.
+    /// - `arguments`: The arguments to replace, eg. an [`arguments::Args`] object or a `Vec` of
+    ///   names and values.
+    /// - `renderer`: How style placeholders are rendered.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the translated value.
+    ///
+    /// # Raises
+    ///
+    /// This method throws an exception and exits if
+    ///
+    /// - None of the translation files could be found
+    /// - A translation file could not be opened
+    /// - A translation file could not be parsed
+    /// - None of the translation files contain the key
+    /// - The translation is not a valid ICU MessageFormat message
+    /// - The style tags of the translation are not balanced
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// # let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+    /// assert_eq!(
+    ///     config.translate_as(
+    ///         "error",
+    ///         vec![("details", "Something went wrong")],
+    ///         localizer_rs::styles::Renderer::Markdown
+    ///     ),
+    ///     "**Error:** Something went wrong"
+    /// );
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`Config::translate()`]
+    /// - [`Config::try_translate_as()`]
+    /// - [`styles::Renderer`]
+    pub fn translate_as<A: Into<arguments::Args>>(
+        &self,
+        key: &str,
+        arguments: A,
+        renderer: styles::Renderer,
+    ) -> String {
+        return match self.try_translate_as(key, arguments, renderer) {
+            Ok(value) => value,
+            Err(error) => error.raise(),
+        };
+    }
+
+    /// Translates the specified key using the specified renderer instead of the renderer of the
+    /// config, without exiting on errors.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    /// - `key`: The key to translate to.
+    /// - `arguments`: The arguments to replace, eg. an [`arguments::Args`] object or a `Vec` of
+    ///   names and values.
+    /// - `renderer`: How style placeholders are rendered.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the translated value or the error that occurred.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - None of the translation files could be found
+    /// - A translation file could not be opened
+    /// - A translation file could not be parsed
+    /// - None of the translation files contain the key
+    /// - The translation is not a valid ICU MessageFormat message
+    /// - The style tags of the translation are not balanced
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// # let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+    /// assert_eq!(
+    ///     config
+    ///         .try_translate_as("error", vec![("details", "A & B")], localizer_rs::styles::Renderer::Plain)
+    ///         .unwrap(),
+    ///     "Error: A & B"
+    /// );
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`Config::try_translate()`]
+    /// - [`Config::translate_as()`]
+    pub fn try_translate_as<A: Into<arguments::Args>>(
+        &self,
+        key: &str,
+        arguments: A,
+        renderer: styles::Renderer,
+    ) -> Result<String, errors::Error> {
+        let arguments: arguments::Args = arguments.into();
+        let count: Option<plurals::Operands> =
+            arguments.get("count").and_then(arguments::Value::operands);
+
+        return self.render(key, count.as_ref(), arguments, renderer);
+    }
+
+    /// Translates the plural form of the specified key for the specified count.
+    ///
+    /// The plural form is selected using the CLDR plural rules of the language providing the
+    /// translation. The count is available as the `count` argument.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    /// - `key`: The key to translate to.
+    /// - `count`: The number to select the plural form for.
+    /// - `arguments`: The arguments to replace, eg. an [`arguments::Args`] object or a `Vec` of
+    ///   names and values.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the translated value.
+    ///
+    /// # Raises
+    ///
+    /// This method throws an exception and exits if
+    ///
+    /// - None of the translation files could be found
+    /// - A translation file could not be opened
+    /// - A translation file could not be parsed
+    /// - None of the translation files contain the key
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// # let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+    /// assert_eq!(config.translate_plural("files", 1, vec![]), "1 file");
+    /// assert_eq!(config.translate_plural("files", 3, vec![]), "3 files");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`t!()`]
+    /// - [`Config`]
+    /// - [`Config::try_translate_plural()`]
+    /// - [`plurals`]
+    pub fn translate_plural<C: Into<plurals::Operands>, A: Into<arguments::Args>>(
+        &self,
+        key: &str,
+        count: C,
+        arguments: A,
+    ) -> String {
+        return match self.try_translate_plural(key, count, arguments) {
+            Ok(value) => value,
+            Err(error) => error.raise(),
+        };
+    }
+
+    /// Translates the plural form of the specified key for the specified count without exiting on
+    /// errors.
+    ///
+    /// The plural form is selected using the CLDR plural rules of the language providing the
+    /// translation. The count is available as the `count` argument.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    /// - `key`: The key to translate to.
+    /// - `count`: The number to select the plural form for.
+    /// - `arguments`: The arguments to replace, eg. an [`arguments::Args`] object or a `Vec` of
+    ///   names and values.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the translated value or the error that occurred.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - None of the translation files could be found
+    /// - A translation file could not be opened
+    /// - A translation file could not be parsed
+    /// - None of the translation files contain the key
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// # let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+    /// assert_eq!(config.try_translate_plural("files", 0, vec![]).unwrap(), "No files");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`Config::translate_plural()`]
+    /// - [`plurals`]
+    pub fn try_translate_plural<C: Into<plurals::Operands>, A: Into<arguments::Args>>(
+        &self,
+        key: &str,
+        count: C,
+        arguments: A,
+    ) -> Result<String, errors::Error> {
+        let count: plurals::Operands = count.into();
+        let mut arguments: arguments::Args = arguments.into();
+        if arguments.get("count").is_none() {
+            arguments.set("count", count.to_string());
+        }
+
+        return self.render(key, Some(&count), arguments, self.renderer);
+    }
+
+    /// Formats a number for the language specified in the config.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    /// - `value`: The number to format. Values that are not numeric are returned unchanged.
+    /// - `format`: The number format to use.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the formatted number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// use localizer_rs::numbers::NumberFormat;
+    ///
+    /// let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "de");
+    ///
+    /// assert_eq!(config.format_number(1234567.5, &NumberFormat::decimal()), "1.234.567,5");
+    /// assert_eq!(config.format_number(0.25, &NumberFormat::percent()), "25\u{a0}%");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`numbers::format()`]
+    pub fn format_number<V: Into<arguments::Value>>(
+        &self,
+        value: V,
+        format: &numbers::NumberFormat,
+    ) -> String {
+        return numbers::format(&self.language, value, format);
+    }
+
+    /// Joins a list for the language specified in the config.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    /// - `value`: The list to join, eg. a `Vec<&str>`. Values that are not lists are formatted as
+    ///   lists containing a single item.
+    /// - `style`: The conjunction of the list.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the joined list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// use localizer_rs::lists::ListStyle;
+    ///
+    /// let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "de");
+    ///
+    /// assert_eq!(config.format_list(vec!["a", "b", "c"], ListStyle::And), "a, b und c");
+    /// assert_eq!(config.format_list(vec!["a", "b"], ListStyle::Or), "a oder b");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`lists::format()`]
+    pub fn format_list<V: Into<arguments::Value>>(
+        &self,
+        value: V,
+        style: lists::ListStyle,
+    ) -> String {
+        return lists::format(&self.language, value, style);
+    }
+
+    /// Formats a relative time for the language specified in the config.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    /// - `value`: The relative time, negative values are in the past. Values that are not numeric
+    ///   are returned unchanged.
+    /// - `format`: The relative format to use.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the formatted relative time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// use localizer_rs::relative::{RelativeFormat, TimeUnit};
+    ///
+    /// let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "de");
+    ///
+    /// assert_eq!(config.format_relative(-2, &RelativeFormat::unit(TimeUnit::Day)), "vor 2 Tagen");
+    /// assert_eq!(config.format_relative(180, &RelativeFormat::auto()), "in 3 Minuten");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`relative::format()`]
+    pub fn format_relative<V: Into<arguments::Value>>(
+        &self,
+        value: V,
+        format: &relative::RelativeFormat,
+    ) -> String {
+        return relative::format(&self.language, value, format);
+    }
+
+    /// Formats a date or time for the language specified in the config.
+    ///
+    /// Requires the `chrono` feature.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    /// - `value`: The date or time to format. Values that are not dates or times are returned
+    ///   unchanged.
+    /// - `format`: The date format to use.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the formatted date or time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// use localizer_rs::dates::{DateFormat, DateStyle};
+    ///
+    /// let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "de");
+    /// let date = chrono::NaiveDate::from_ymd_opt(2023, 10, 5).unwrap();
+    ///
+    /// assert_eq!(
+    ///     config.format_date(date, &DateFormat::date(DateStyle::Full)),
+    ///     "Donnerstag, 5. Oktober 2023"
+    /// );
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`dates::format()`]
+    #[cfg(feature = "chrono")]
+    pub fn format_date<V: Into<arguments::Value>>(
+        &self,
+        value: V,
+        format: &dates::DateFormat,
+    ) -> String {
+        return dates::format(&self.language, value, format);
+    }
+
+    /// Translates the specified key and replaces the arguments.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    /// - `key`: The key to translate to.
+    /// - `count`: The number to select the plural form for.
+    /// - `arguments`: The arguments to replace.
+    /// - `renderer`: How style placeholders are rendered.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the translated value or the error that occurred.
+    fn render(
+        &self,
+        key: &str,
+        count: Option<&plurals::Operands>,
+        arguments: arguments::Args,
+        renderer: styles::Renderer,
+    ) -> Result<String, errors::Error> {
+        let (language, result, syntax) = self.lookup(key, count)?;
+        // Both syntaxes format values for the language of the translation, keeping the regional
+        // formats of the configured language if the translation is in its base language
+        let locale: &str =
+            match plurals::base_language(&language) == plurals::base_language(&self.language) {
+                true => &self.language,
+                false => &language,
+            };
+        let styling: styles::Styling =
+            styles::Styling::new(self.color_mode, self.color_depth, &self.theme, renderer);
+
+        let rendered: Result<String, (usize, String)> = if syntax == Syntax::MessageFormat {
+            let parts: Vec<message_format::Part> = match message_format::parse(&result) {
+                Ok(value) => value,
+                Err((position, reason)) => {
+                    return Err(errors::Error::InvalidMessage {
+                        key: key.to_owned(),
+                        language,
+                        position,
+                        reason,
+                    });
+                }
+            };
+            // Styles like `{bold}` are kept as placeholders, formatted arguments are never parsed
+            let tokens: Vec<placeholders::Token> =
+                message_format::format(&parts, locale, &arguments, |name: &str| {
+                    let name: &str = name.strip_prefix('/').unwrap_or(name);
+                    return styles::is_style_of(name, &self.theme);
+                });
+
+            placeholders::replace_tokens(&tokens, locale, &arguments, &styling)
+        } else {
+            placeholders::replace(&result, locale, &arguments, &styling)
+        };
+
+        return match rendered {
+            Ok(value) => Ok(value),
+            Err((position, reason)) => Err(errors::Error::UnbalancedTags {
+                key: key.to_owned(),
+                language,
+                position,
+                reason,
+            }),
+        };
+    }
+
+    /// Reloads the translation files of the languages in the fallback chain.
+    ///
+    /// All cached translation files are discarded, other languages are loaded again on their next
+    /// use.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    ///
+    /// # Returns
+    ///
+    /// The `Config` object.
+    ///
+    /// # Raises
+    ///
+    /// This method throws an exception and exits if the translation files could not be loaded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// # let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+    /// config.reload();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`Config::try_reload()`]
+    /// - [`Config::clear_cache()`]
+    pub fn reload(&self) -> &Config {
+        if let Err(error) = self.try_reload() {
+            error.raise();
+        }
+
+        return self;
+    }
+
+    /// Reloads the translation files of the languages in the fallback chain without exiting on
+    /// errors.
+    ///
+    /// All cached translation files are discarded, other languages are loaded again on their next
+    /// use.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Config` object or the error that occurred.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - None of the translation files could be found
+    /// - A translation file could not be opened
+    /// - A translation file could not be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// # let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+    /// assert!(config.try_reload().is_ok());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`Config::reload()`]
+    /// - [`Config::clear_cache()`]
+    pub fn try_reload(&self) -> Result<&Config, errors::Error> {
+        self.cache.clear();
+        self.catalogs()?;
+
+        return Ok(self);
+    }
+
+    /// Discards all cached translation files.
+    ///
+    /// The translation files are loaded again on their next use.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    ///
+    /// # Returns
+    ///
+    /// The `Config` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// # let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+    /// config.clear_cache();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config`]
+    /// - [`Config::reload()`]
+    pub fn clear_cache(&self) -> &Config {
+        self.cache.clear();
+        return self;
+    }
+
+    /// Looks up the specified key in the languages of the fallback chain.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    /// - `key`: The key to look up.
+    /// - `count`: The number to select the plural form for. The `other` form is used if this is
+    ///   `None`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the language that provides the key, its untranslated value and the
+    /// syntax of the value or the error that occurred.
+    fn lookup(
+        &self,
+        key: &str,
+        count: Option<&plurals::Operands>,
+    ) -> Result<(String, String, Syntax), errors::Error> {
+        let mut not_found: Option<errors::Error> = None;
+        let mut found_catalog: bool = false;
+        let segments: Vec<String> = keys::split(key);
+        let mut depth: usize = 0;
+
+        for language in self.fallback_chain() {
+            let catalog: Arc<sources::Catalog> = match self.catalog(&language) {
+                Ok(value) => value,
+                Err(error) if error.is_not_found() => {
+                    not_found.get_or_insert(error);
+                    continue;
+                }
+                Err(error) => return Err(error),
+            };
+            found_catalog = true;
+
+            let value: &serde_json::Value = match keys::resolve(&catalog, key) {
+                Ok(serde_json::Value::Array(forms)) => match &catalog.plural_forms {
+                    Some(plural_forms) => forms.get(plural_forms.index(count)),
+                    None => None,
+                }
+                .unwrap_or(&serde_json::Value::Null),
+                Ok(value) if plurals::is_plural(value) => match count {
+                    Some(operands) => plurals::select(&language, operands, value),
+                    None => value.get(plurals::Category::Other.name()),
+                }
+                .unwrap_or(&serde_json::Value::Null),
+                Ok(value) => value,
+                Err(index) => {
+                    depth = depth.max(index);
+                    continue;
+                }
+            };
+
+            match value.as_str() {
+                Some(value) => {
+                    let syntax: Syntax = catalog.syntax.unwrap_or(self.syntax);
+                    return Ok((language, value.to_owned(), syntax));
+                }
+                None => depth = segments.len() - 1,
+            }
+        }
+
+        if let (false, Some(error)) = (found_catalog, not_found) {
+            return Err(error);
+        }
+
+        return Err(errors::Error::KeyNotFound {
+            key: key.to_owned(),
+            segment: segments[depth].to_owned(),
+            language: self.language.to_owned(),
+        });
+    }
+
+    /// Returns the catalogs of the languages in the fallback chain.
+    ///
+    /// Languages without a translation file are skipped.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the languages and their catalogs or the error that occurred. If no
+    /// translation file could be found, the error of the configured language is returned.
+    fn catalogs(&self) -> Result<Vec<(String, Arc<sources::Catalog>)>, errors::Error> {
+        let mut catalogs: Vec<(String, Arc<sources::Catalog>)> = vec![];
+        let mut not_found: Option<errors::Error> = None;
+
+        for language in self.fallback_chain() {
+            match self.catalog(&language) {
+                Ok(catalog) => catalogs.push((language, catalog)),
+                Err(error) if error.is_not_found() => {
+                    not_found.get_or_insert(error);
+                }
+                Err(error) => return Err(error),
+            }
+        }
+
+        if catalogs.is_empty() {
+            if let Some(error) = not_found {
+                return Err(error);
+            }
+        }
+
+        return Ok(catalogs);
+    }
+
+    /// Returns the catalog of the specified language, loading it from the catalog source if it is
+    /// not cached yet.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    /// - `language`: The language of the catalog.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the catalog or the error that occurred while loading it.
+    fn catalog(&self, language: &str) -> Result<Arc<sources::Catalog>, errors::Error> {
+        if let Some(catalog) = self.cache.get(&self.path, language) {
+            return Ok(catalog);
+        }
+
+        let catalog: sources::Catalog = self.source().load(language)?;
+        return Ok(self.cache.insert(&self.path, language, catalog));
+    }
+
+    /// Returns the catalog source of the config object.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The config object.
+    ///
+    /// # Returns
+    ///
+    /// The source set using [`Config::set_source()`] or a [`sources::Files`] source reading the
+    /// config path.
+    fn source(&self) -> Arc<dyn sources::CatalogSource> {
+        return match self.source.get() {
+            Some(source) => source,
+            None => Arc::new(sources::Files::new(&self.path)),
+        };
+    }
+}
+
+
+/// Translates the specified key in the language specified in the config.
+///
+/// Argument values can be any expression implementing [`std::fmt::Display`]. Values convertible
+/// into an [`arguments::Value`] keep their type, all other values are converted using
+/// [`ToString::to_string()`].
+///
+/// # Parameters
 ///
 /// - `config`: The config object.
 /// - `key`: The key to translate to.
-/// - `arguments`: Optional parameter. The arguments to replace. Has to be of type `"name" = "value"`.
+/// - `count`: Optional parameter. The number to select the plural form for. Has to be of type
+///   `count = value` and precede the arguments.
+/// - `arguments`: Optional parameter. The arguments to replace. Has to be of type
+///   `"name" = value` or `name = value`. A variable can be passed using its name only, `name` is
+///   short for `name = name`.
 ///
 /// # Returns
 ///
@@ -418,31 +1878,80 @@ impl Config {
 /// ```rust
 /// # use localizer_rs;
 /// # let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+/// let user: &str = "John Doe";
+/// let balance: u32 = 10;
+///
 /// localizer_rs::t!(config, "test");
 /// localizer_rs::t!(config, "test", "variable" = "content");
+/// localizer_rs::t!(config, "files", count = 3);
+///
+/// assert_eq!(
+///     localizer_rs::t!(config, "success", balance = format!("${}", balance), user),
+///     localizer_rs::t!(config, "success", "balance" = "$10", "user" = "John Doe"),
+/// );
 /// ```
 ///
 /// # See also
 ///
 /// - [`Config`]
 /// - [`Config::t()`]
+/// - [`Config::translate_plural()`]
 #[macro_export]
 macro_rules! t {
-    ($config:expr, $key:expr) => {
+    ($config:expr, $key:expr $(,)?) => {
         {
             $config.t($key, vec![])
         }
     };
 
-    ($config:expr, $key:expr, $($argument_name:literal = $argument_value:literal),* $(,)?) => {
+    ($config:expr, $key:expr, count = $count:expr $(, $($arguments:tt)*)?) => {
         {
-            let mut arguments: Vec<(&str, &str)> = vec![];
+            #[allow(unused_imports)]
+            use $crate::arguments::__private::{ViaDisplay, ViaInto};
+
+            $config.translate_plural($key, $count, $crate::__t_arguments!([] $($($arguments)*)?))
+        }
+    };
 
-            $(
-                arguments.push(($argument_name, $argument_value));
-            )*
+    ($config:expr, $key:expr, $($arguments:tt)+) => {
+        {
+            #[allow(unused_imports)]
+            use $crate::arguments::__private::{ViaDisplay, ViaInto};
 
-            $config.t($key, arguments)
+            $config.t($key, $crate::__t_arguments!([] $($arguments)+))
         }
     };
 }
+
+/// Collects the arguments of the [`t!`] macro into an [`arguments::Args`] object.
+///
+/// Values implementing `Into<Value>` keep their type, all other values are formatted using their
+/// [`std::fmt::Display`] implementation.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __t_arguments {
+    ([$($arguments:tt)*] $(,)?) => {
+        $crate::arguments::Args::new() $($arguments)*
+    };
+
+    ([$($arguments:tt)*] $name:literal = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__t_arguments!(
+            [$($arguments)* .with($name, (&$crate::arguments::__private::Wrap(&$value)).value())]
+            $($($rest)*)?
+        )
+    };
+
+    ([$($arguments:tt)*] $name:ident = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__t_arguments!(
+            [$($arguments)* .with(::std::stringify!($name), (&$crate::arguments::__private::Wrap(&$value)).value())]
+            $($($rest)*)?
+        )
+    };
+
+    ([$($arguments:tt)*] $name:ident $(, $($rest:tt)*)?) => {
+        $crate::__t_arguments!(
+            [$($arguments)* .with(::std::stringify!($name), (&$crate::arguments::__private::Wrap(&$name)).value())]
+            $($($rest)*)?
+        )
+    };
+}
@@ -0,0 +1,281 @@
+#![doc = include_str!("../.github/lists.md")]
+// localizer-rs lists
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use crate::arguments;
+use crate::plurals;
+
+
+//////////////
+// PATTERNS //
+//////////////
+
+/// Patterns joining the items of a list, `{0}` and `{1}` are replaced by the joined parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ListPatterns {
+    /// The pattern joining the first two items of longer lists.
+    pub(crate) start: &'static str,
+    /// The pattern joining the items in the middle of longer lists.
+    pub(crate) middle: &'static str,
+    /// The pattern joining the last two items of longer lists.
+    pub(crate) end: &'static str,
+    /// The pattern joining lists of two items.
+    pub(crate) two: &'static str,
+}
+
+/// Creates the patterns of a list, using the same pattern at the start and in the middle.
+const fn patterns(start: &'static str, end: &'static str, two: &'static str) -> ListPatterns {
+    return ListPatterns {
+        start,
+        middle: start,
+        end,
+        two,
+    };
+}
+
+/// List patterns of a locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Lists {
+    /// The language tag of the locale.
+    pub(crate) language: &'static str,
+    /// The patterns of lists joined by `and`.
+    pub(crate) and: ListPatterns,
+    /// The patterns of lists joined by `or`.
+    pub(crate) or: ListPatterns,
+}
+
+/// List patterns of the bundled locales, taken from the CLDR.
+const LISTS: [Lists; 13] = [
+    Lists {
+        language: "en",
+        and: patterns("{0}, {1}", "{0}, and {1}", "{0} and {1}"),
+        or: patterns("{0}, {1}", "{0}, or {1}", "{0} or {1}"),
+    },
+    Lists {
+        language: "en-GB",
+        and: patterns("{0}, {1}", "{0} and {1}", "{0} and {1}"),
+        or: patterns("{0}, {1}", "{0} or {1}", "{0} or {1}"),
+    },
+    Lists {
+        language: "de",
+        and: patterns("{0}, {1}", "{0} und {1}", "{0} und {1}"),
+        or: patterns("{0}, {1}", "{0} oder {1}", "{0} oder {1}"),
+    },
+    Lists {
+        language: "fr",
+        and: patterns("{0}, {1}", "{0} et {1}", "{0} et {1}"),
+        or: patterns("{0}, {1}", "{0} ou {1}", "{0} ou {1}"),
+    },
+    Lists {
+        language: "es",
+        and: patterns("{0}, {1}", "{0} y {1}", "{0} y {1}"),
+        or: patterns("{0}, {1}", "{0} o {1}", "{0} o {1}"),
+    },
+    Lists {
+        language: "it",
+        and: patterns("{0}, {1}", "{0} e {1}", "{0} e {1}"),
+        or: patterns("{0}, {1}", "{0} o {1}", "{0} o {1}"),
+    },
+    Lists {
+        language: "pt",
+        and: patterns("{0}, {1}", "{0} e {1}", "{0} e {1}"),
+        or: patterns("{0}, {1}", "{0} ou {1}", "{0} ou {1}"),
+    },
+    Lists {
+        language: "nl",
+        and: patterns("{0}, {1}", "{0} en {1}", "{0} en {1}"),
+        or: patterns("{0}, {1}", "{0} of {1}", "{0} of {1}"),
+    },
+    Lists {
+        language: "pl",
+        and: patterns("{0}, {1}", "{0} i {1}", "{0} i {1}"),
+        or: patterns("{0}, {1}", "{0} lub {1}", "{0} lub {1}"),
+    },
+    Lists {
+        language: "ru",
+        and: patterns("{0}, {1}", "{0} и {1}", "{0} и {1}"),
+        or: patterns("{0}, {1}", "{0} или {1}", "{0} или {1}"),
+    },
+    Lists {
+        language: "sv",
+        and: patterns("{0}, {1}", "{0} och {1}", "{0} och {1}"),
+        or: patterns("{0}, {1}", "{0} eller {1}", "{0} eller {1}"),
+    },
+    Lists {
+        language: "ja",
+        and: patterns("{0}、{1}", "{0}、{1}", "{0}、{1}"),
+        or: patterns("{0}、{1}", "{0}、または{1}", "{0}または{1}"),
+    },
+    Lists {
+        language: "zh",
+        and: patterns("{0}、{1}", "{0}和{1}", "{0}和{1}"),
+        or: patterns("{0}、{1}", "{0}或{1}", "{0}或{1}"),
+    },
+];
+
+
+////////////////
+// LIST STYLE //
+////////////////
+
+/// Conjunction of a list.
+///
+/// # Variants
+///
+/// - `And`: All items, eg. `a, b, and c`. The default.
+/// - `Or`: One of the items, eg. `a, b, or c`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum ListStyle {
+    /// All items.
+    #[default]
+    And,
+    /// One of the items.
+    Or,
+}
+
+impl ListStyle {
+    /// Parses the options of a list placeholder.
+    ///
+    /// # Parameters
+    ///
+    /// - `style`: The style of the placeholder, `list`.
+    /// - `option`: The conjunction, `and` or `or`. Defaults to `and`.
+    ///
+    /// # Returns
+    ///
+    /// The list style or `None` if the style or the option is not valid.
+    pub(crate) fn parse(style: &str, option: Option<&str>) -> Option<ListStyle> {
+        if style != "list" {
+            return None;
+        }
+
+        return match option {
+            None | Some("and") => Some(ListStyle::And),
+            Some("or") => Some(ListStyle::Or),
+            Some(_option) => None,
+        };
+    }
+}
+
+
+////////////////
+// FORMATTING //
+////////////////
+
+/// Replaces the `{0}` and `{1}` placeholders of a list pattern.
+///
+/// Both placeholders are replaced in a single pass, so the items can contain placeholders
+/// themselves.
+///
+/// # Parameters
+///
+/// - `pattern`: The list pattern.
+/// - `first`: The replacement of `{0}`.
+/// - `second`: The replacement of `{1}`.
+///
+/// # Returns
+///
+/// The pattern with the placeholders replaced.
+fn apply(pattern: &str, first: &str, second: &str) -> String {
+    let mut output: String = String::new();
+    let mut rest: &str = pattern;
+
+    while let Some(start) = rest.find('{') {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        if let Some(tail) = rest.strip_prefix("{0}") {
+            output.push_str(first);
+            rest = tail;
+        } else if let Some(tail) = rest.strip_prefix("{1}") {
+            output.push_str(second);
+            rest = tail;
+        } else {
+            output.push('{');
+            rest = &rest[1..];
+        }
+    }
+    output.push_str(rest);
+
+    return output;
+}
+
+/// Joins a list for a language.
+///
+/// Values that are not lists are formatted as lists containing a single item.
+///
+/// # Parameters
+///
+/// - `language`: The language to format the list for, eg. `de`.
+/// - `value`: The list to join, eg. a `Vec<&str>`.
+/// - `style`: The conjunction of the list.
+///
+/// # Returns
+///
+/// A `String` containing the joined list.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// use localizer_rs::lists::ListStyle;
+///
+/// assert_eq!(localizer_rs::lists::format("en", ["a", "b", "c"], ListStyle::And), "a, b, and c");
+/// assert_eq!(localizer_rs::lists::format("de", ["a", "b", "c"], ListStyle::And), "a, b und c");
+/// assert_eq!(localizer_rs::lists::format("fr", ["a", "b"], ListStyle::Or), "a ou b");
+/// assert_eq!(localizer_rs::lists::format("ja", ["a", "b", "c"], ListStyle::And), "a、b、c");
+/// ```
+///
+/// # See also
+///
+/// - [`ListStyle`]
+/// - [`crate::Config::format_list()`]
+pub fn format<V: Into<arguments::Value>>(language: &str, value: V, style: ListStyle) -> String {
+    let items: Vec<String> = match value.into() {
+        arguments::Value::List(values) => values.iter().map(ToString::to_string).collect(),
+        value => vec![value.to_string()],
+    };
+
+    let lists: &Lists = plurals::locale_entry(&LISTS, language, |lists: &Lists| lists.language);
+    let patterns: &ListPatterns = match style {
+        ListStyle::And => &lists.and,
+        ListStyle::Or => &lists.or,
+    };
+
+    return match items.as_slice() {
+        [] => String::new(),
+        [item] => item.to_owned(),
+        [first, second] => apply(patterns.two, first, second),
+        [first, middle @ .., second_last, last] => {
+            let mut result: String = apply(patterns.end, second_last, last);
+            for item in middle.iter().rev() {
+                result = apply(patterns.middle, item, &result);
+            }
+            apply(patterns.start, first, &result)
+        }
+    };
+}
@@ -0,0 +1,732 @@
+// localizer-rs message format
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::borrow::Cow;
+
+use crate::arguments;
+#[cfg(feature = "chrono")]
+use crate::dates;
+use crate::numbers;
+use crate::placeholders;
+use crate::plurals;
+
+
+//////////////
+// MESSAGES //
+//////////////
+
+/// Part of a parsed message.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Part {
+    /// Literal text.
+    Text(String),
+    /// A `{{name}}` placeholder, which is kept as it is.
+    Placeholder(String),
+    /// A simple argument like `{name}` or `{name, number}`.
+    Argument {
+        /// The name of the argument.
+        name: String,
+        /// The format type, eg. `number`.
+        format: Option<String>,
+        /// The format style, eg. `integer`.
+        style: Option<String>,
+    },
+    /// A `plural` or `selectordinal` argument.
+    Plural {
+        /// The name of the argument.
+        name: String,
+        /// Whether the ordinal plural rules are used.
+        ordinal: bool,
+        /// The offset subtracted from the argument.
+        offset: f64,
+        /// The selectors and their messages.
+        arms: Vec<(String, Vec<Part>)>,
+    },
+    /// A `select` argument.
+    Select {
+        /// The name of the argument.
+        name: String,
+        /// The selectors and their messages.
+        arms: Vec<(String, Vec<Part>)>,
+    },
+    /// The `#` placeholder inside of plural messages.
+    Pound,
+}
+
+
+////////////
+// PARSER //
+////////////
+
+/// Error produced while parsing a message.
+///
+/// Contains the character position of the error and its description.
+pub(crate) type ParseError = (usize, String);
+
+/// Message parser.
+struct Parser {
+    /// The characters of the message.
+    characters: Vec<char>,
+    /// The current position.
+    position: usize,
+}
+
+impl Parser {
+    /// Returns the current character.
+    fn peek(&self) -> Option<char> {
+        return self.characters.get(self.position).copied();
+    }
+
+    /// Returns the character following the current character.
+    fn peek_next(&self) -> Option<char> {
+        return self.characters.get(self.position + 1).copied();
+    }
+
+    /// Returns a parse error at the current position.
+    fn error(&self, description: &str) -> ParseError {
+        return (self.position, description.to_owned());
+    }
+
+    /// Skips whitespace.
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position += 1;
+        }
+    }
+
+    /// Consumes the expected character.
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        if self.peek() != Some(expected) {
+            return Err(self.error(format!("Expected {:?}", expected).as_str()));
+        }
+        self.position += 1;
+
+        return Ok(());
+    }
+
+    /// Parses a name, selector or format type.
+    fn identifier(&mut self) -> Result<String, ParseError> {
+        let start: usize = self.position;
+
+        while let Some(character) = self.peek() {
+            if character.is_whitespace() || "{},:#'".contains(character) {
+                break;
+            }
+            self.position += 1;
+        }
+
+        if start == self.position {
+            return Err(self.error("Expected an identifier"));
+        }
+
+        return Ok(self.characters[start..self.position].iter().collect());
+    }
+
+    /// Parses a message until the end of the input or the closing brace of a sub message.
+    fn message(&mut self, depth: usize, in_plural: bool) -> Result<Vec<Part>, ParseError> {
+        let mut parts: Vec<Part> = vec![];
+        let mut text: String = String::new();
+
+        while let Some(character) = self.peek() {
+            match character {
+                '{' if self.peek_next() == Some('{') => {
+                    let start: usize = self.position;
+                    while self.position < self.characters.len()
+                        && !(self.peek() == Some('}') && self.peek_next() == Some('}'))
+                    {
+                        self.position += 1;
+                    }
+                    if self.position >= self.characters.len() {
+                        self.position = start;
+                        return Err(self.error("Unclosed placeholder"));
+                    }
+                    if !text.is_empty() {
+                        parts.push(Part::Text(std::mem::take(&mut text)));
+                    }
+                    parts.push(Part::Placeholder(
+                        self.characters[start + 2..self.position].iter().collect(),
+                    ));
+                    self.position += 2;
+                }
+                '{' => {
+                    if !text.is_empty() {
+                        parts.push(Part::Text(std::mem::take(&mut text)));
+                    }
+                    parts.push(self.argument()?);
+                }
+                '}' => {
+                    if depth == 0 {
+                        return Err(self.error("Unmatched closing brace"));
+                    }
+                    break;
+                }
+                '#' if in_plural => {
+                    if !text.is_empty() {
+                        parts.push(Part::Text(std::mem::take(&mut text)));
+                    }
+                    parts.push(Part::Pound);
+                    self.position += 1;
+                }
+                '\'' => {
+                    self.position += 1;
+                    match self.peek() {
+                        Some('\'') => {
+                            text.push('\'');
+                            self.position += 1;
+                        }
+                        Some(next) if "{}|".contains(next) || (next == '#' && in_plural) => loop {
+                            match self.peek() {
+                                Some('\'') if self.peek_next() == Some('\'') => {
+                                    text.push('\'');
+                                    self.position += 2;
+                                }
+                                Some('\'') => {
+                                    self.position += 1;
+                                    break;
+                                }
+                                Some(quoted) => {
+                                    text.push(quoted);
+                                    self.position += 1;
+                                }
+                                None => break,
+                            }
+                        },
+                        _ => text.push('\''),
+                    }
+                }
+                _ => {
+                    text.push(character);
+                    self.position += 1;
+                }
+            }
+        }
+
+        if !text.is_empty() {
+            parts.push(Part::Text(text));
+        }
+
+        return Ok(parts);
+    }
+
+    /// Parses an argument, starting at its opening brace.
+    fn argument(&mut self) -> Result<Part, ParseError> {
+        self.expect('{')?;
+        self.skip_whitespace();
+        let name: String = self.identifier()?;
+        self.skip_whitespace();
+
+        if self.peek() == Some('}') {
+            self.position += 1;
+            return Ok(Part::Argument {
+                name,
+                format: None,
+                style: None,
+            });
+        }
+
+        self.expect(',')?;
+        self.skip_whitespace();
+        let format: String = self.identifier()?;
+        self.skip_whitespace();
+
+        let part: Part = match format.as_str() {
+            "plural" | "selectordinal" => {
+                self.expect(',')?;
+                self.skip_whitespace();
+                let mut offset: f64 = 0.0;
+
+                if self.characters[self.position..]
+                    .starts_with(&['o', 'f', 'f', 's', 'e', 't', ':'])
+                {
+                    self.position += 7;
+                    self.skip_whitespace();
+                    let value: String = self.identifier()?;
+                    offset = match value.parse::<f64>() {
+                        Ok(value) => value,
+                        Err(_error) => return Err(self.error("Invalid plural offset")),
+                    };
+                }
+
+                Part::Plural {
+                    name,
+                    ordinal: format == "selectordinal",
+                    offset,
+                    arms: self.arms(true)?,
+                }
+            }
+            "select" => {
+                self.expect(',')?;
+                Part::Select {
+                    name,
+                    arms: self.arms(false)?,
+                }
+            }
+            _ => {
+                let mut style: Option<String> = None;
+
+                if self.peek() == Some(',') {
+                    self.position += 1;
+                    let start: usize = self.position;
+                    while self.peek().is_some_and(|character: char| character != '}') {
+                        self.position += 1;
+                    }
+                    style = Some(
+                        self.characters[start..self.position]
+                            .iter()
+                            .collect::<String>()
+                            .trim()
+                            .to_owned(),
+                    );
+                }
+
+                Part::Argument {
+                    name,
+                    format: Some(format),
+                    style,
+                }
+            }
+        };
+
+        self.skip_whitespace();
+        self.expect('}')?;
+
+        return Ok(part);
+    }
+
+    /// Parses the selectors and messages of a `plural`, `selectordinal` or `select` argument.
+    fn arms(&mut self, in_plural: bool) -> Result<Vec<(String, Vec<Part>)>, ParseError> {
+        let mut arms: Vec<(String, Vec<Part>)> = vec![];
+
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some('}') || self.peek().is_none() {
+                break;
+            }
+
+            let selector: String = self.identifier()?;
+            self.skip_whitespace();
+            self.expect('{')?;
+            let message: Vec<Part> = self.message(1, in_plural)?;
+            self.expect('}')?;
+            arms.push((selector, message));
+        }
+
+        if !arms.iter().any(|(selector, _message)| selector == "other") {
+            return Err(self.error("Missing the \"other\" selector"));
+        }
+
+        return Ok(arms);
+    }
+}
+
+/// Parses a message.
+///
+/// # Parameters
+///
+/// - `message`: The message to parse.
+///
+/// # Returns
+///
+/// A `Result` containing the parts of the message or the position and description of the syntax
+/// error.
+pub(crate) fn parse(message: &str) -> Result<Vec<Part>, ParseError> {
+    let mut parser: Parser = Parser {
+        characters: message.chars().collect(),
+        position: 0,
+    };
+
+    return parser.message(0, false);
+}
+
+
+////////////////
+// EVALUATION //
+////////////////
+
+/// Returns the number format of a `number` argument.
+///
+/// # Parameters
+///
+/// - `format`: The format type of the argument, eg. `number`.
+/// - `style`: The format style of the argument, eg. `integer` or `percent`.
+///
+/// # Returns
+///
+/// The number format or `None` if the argument is not a number argument.
+fn number_format(format: Option<&str>, style: Option<&str>) -> Option<numbers::NumberFormat> {
+    if format != Some("number") {
+        return None;
+    }
+
+    return match style {
+        Some("integer") => Some(numbers::NumberFormat::decimal().fraction_digits(0, 0)),
+        Some("percent") => Some(numbers::NumberFormat::percent()),
+        _ => Some(numbers::NumberFormat::decimal()),
+    };
+}
+
+/// Formats the value of an argument.
+///
+/// Values of `number` arguments are formatted as numbers. With the `chrono` feature, values of
+/// `date` and `time` arguments are formatted as dates and times, eg. `{when, date, short}`.
+///
+/// # Parameters
+///
+/// - `language`: The language of the message.
+/// - `value`: The value of the argument.
+/// - `format`: The format type of the argument, eg. `number` or `date`.
+/// - `style`: The format style of the argument, eg. `percent` or `short`.
+///
+/// # Returns
+///
+/// A `String` containing the formatted value.
+fn format_value(
+    language: &str,
+    value: &arguments::Value,
+    format: Option<&str>,
+    style: Option<&str>,
+) -> String {
+    if let Some(number_format) = number_format(format, style) {
+        return numbers::format(language, value.clone(), &number_format);
+    }
+    #[cfg(feature = "chrono")]
+    if let Some(date_format) =
+        format.and_then(|format: &str| dates::DateFormat::parse(format, style))
+    {
+        return dates::format(language, value.clone(), &date_format);
+    }
+
+    return value.to_string();
+}
+
+/// Selects the arm of a plural argument.
+///
+/// # Parameters
+///
+/// - `language`: The language of the message.
+/// - `value`: The value of the argument.
+/// - `ordinal`: Whether the ordinal plural rules are used.
+/// - `offset`: The offset subtracted from the argument.
+/// - `arms`: The selectors and their messages.
+///
+/// # Returns
+///
+/// The selected message and the number replacing `#`.
+fn select_plural<'a>(
+    language: &str,
+    value: Option<&arguments::Value>,
+    ordinal: bool,
+    offset: f64,
+    arms: &'a [(String, Vec<Part>)],
+) -> (&'a [Part], String) {
+    let operands: Option<plurals::Operands> = value.and_then(arguments::Value::operands);
+    let find = |selector: &str| {
+        arms.iter()
+            .find(|(arm_selector, _message)| arm_selector == selector)
+            .map(|(_selector, message)| message.as_slice())
+    };
+    let other: &[Part] = find("other").unwrap_or(&[]);
+
+    let operands: plurals::Operands = match operands {
+        Some(operands) => operands,
+        None => {
+            return (
+                other,
+                value
+                    .map(|value: &arguments::Value| value.to_string())
+                    .unwrap_or_default(),
+            );
+        }
+    };
+
+    let shifted: plurals::Operands = if offset == 0.0 {
+        operands.clone()
+    } else {
+        let signed: f64 = if operands.negative {
+            -operands.n
+        } else {
+            operands.n
+        };
+        plurals::Operands::from(signed - offset)
+    };
+
+    let exact: Option<&[Part]> = arms
+        .iter()
+        .find(|(selector, _message)| match selector.strip_prefix('=') {
+            Some(number) => {
+                plurals::Operands::parse(number).is_some_and(|number: plurals::Operands| {
+                    number.n == operands.n && number.negative == operands.negative
+                })
+            }
+            None => false,
+        })
+        .map(|(_selector, message)| message.as_slice());
+
+    let category: plurals::Category = if ordinal {
+        plurals::ordinal(language, &shifted)
+    } else {
+        plurals::cardinal(language, &shifted)
+    };
+
+    return (
+        exact.or_else(|| find(category.name())).unwrap_or(other),
+        shifted.to_string(),
+    );
+}
+
+/// Appends text to the tokens of a formatted message.
+///
+/// # Parameters
+///
+/// - `output`: The tokens to append to.
+/// - `text`: The text to append.
+fn push_text(output: &mut Vec<placeholders::Token<'static>>, text: &str) {
+    match output.last_mut() {
+        Some(placeholders::Token::Text(last)) => last.to_mut().push_str(text),
+        _ => output.push(placeholders::Token::Text(Cow::Owned(text.to_owned()))),
+    }
+}
+
+/// Formats the parts of a message.
+///
+/// Arguments that do not exist are kept as they are, unless they are styles like `{bold}`.
+///
+/// # Parameters
+///
+/// - `parts`: The parts of the message.
+/// - `language`: The language of the message.
+/// - `arguments`: The arguments to replace.
+/// - `is_style`: Whether the name of an argument that does not exist is a style.
+/// - `pound`: The number replacing `#`.
+/// - `output`: The tokens to write to.
+fn write(
+    parts: &[Part],
+    language: &str,
+    arguments: &arguments::Args,
+    is_style: &dyn Fn(&str) -> bool,
+    pound: Option<&str>,
+    output: &mut Vec<placeholders::Token<'static>>,
+) {
+    for part in parts {
+        match part {
+            Part::Text(text) => push_text(output, text),
+            Part::Placeholder(name) => {
+                output.push(placeholders::Token::Placeholder(Cow::Owned(
+                    name.to_owned(),
+                )));
+            }
+            Part::Argument {
+                name,
+                format,
+                style,
+            } => match arguments.get(name) {
+                Some(value) => push_text(
+                    output,
+                    &format_value(language, value, format.as_deref(), style.as_deref()),
+                ),
+                None if format.is_none() && is_style(name) => {
+                    output.push(placeholders::Token::Placeholder(Cow::Owned(
+                        name.to_owned(),
+                    )));
+                }
+                None => push_text(output, &format!("{{{}}}", name)),
+            },
+            Part::Plural {
+                name,
+                ordinal,
+                offset,
+                arms,
+            } => {
+                let (message, number) =
+                    select_plural(language, arguments.get(name), *ordinal, *offset, arms);
+                write(
+                    message,
+                    language,
+                    arguments,
+                    is_style,
+                    Some(&number),
+                    output,
+                );
+            }
+            Part::Select { name, arms } => {
+                let value: String = arguments
+                    .get(name)
+                    .map(|value: &arguments::Value| value.to_string())
+                    .unwrap_or_else(|| "other".to_owned());
+                let message: Option<&Vec<Part>> = arms
+                    .iter()
+                    .find(|(selector, _message)| *selector == value)
+                    .or_else(|| arms.iter().find(|(selector, _message)| selector == "other"))
+                    .map(|(_selector, message)| message);
+
+                if let Some(message) = message {
+                    write(message, language, arguments, is_style, pound, output);
+                }
+            }
+            Part::Pound => push_text(output, pound.unwrap_or("#")),
+        }
+    }
+}
+
+/// Formats a message.
+///
+/// Styles like `{bold}` and `{{bold}}` placeholders are kept as placeholder tokens, while the
+/// formatted arguments are text tokens, so their values are never parsed as placeholders.
+///
+/// # Parameters
+///
+/// - `parts`: The parts of the message.
+/// - `language`: The language of the message.
+/// - `arguments`: The arguments to replace.
+/// - `is_style`: Whether the name of an argument that does not exist is a style.
+///
+/// # Returns
+///
+/// The text and placeholder tokens of the formatted message.
+pub(crate) fn format(
+    parts: &[Part],
+    language: &str,
+    arguments: &arguments::Args,
+    is_style: impl Fn(&str) -> bool,
+) -> Vec<placeholders::Token<'static>> {
+    let mut output: Vec<placeholders::Token> = vec![];
+    write(parts, language, arguments, &is_style, None, &mut output);
+
+    return output;
+}
+
+
+///////////////////
+// SERIALIZATION //
+///////////////////
+
+/// Writes the parts of a message using the ICU MessageFormat syntax.
+///
+/// # Parameters
+///
+/// - `parts`: The parts of the message.
+/// - `in_plural`: Whether the parts are part of a plural message, where `#` has to be quoted.
+/// - `output`: The string to write to.
+fn write_source(parts: &[Part], in_plural: bool, output: &mut String) {
+    for part in parts {
+        match part {
+            Part::Text(text) => {
+                for character in text.chars() {
+                    match character {
+                        '\'' => output.push_str("''"),
+                        '{' | '}' => {
+                            output.push('\'');
+                            output.push(character);
+                            output.push('\'');
+                        }
+                        '#' if in_plural => output.push_str("'#'"),
+                        _ => output.push(character),
+                    }
+                }
+            }
+            Part::Placeholder(name) => {
+                output.push_str("{{");
+                output.push_str(name);
+                output.push_str("}}");
+            }
+            Part::Argument {
+                name,
+                format,
+                style,
+            } => {
+                output.push('{');
+                output.push_str(name);
+                if let Some(format) = format {
+                    output.push_str(", ");
+                    output.push_str(format);
+                }
+                if let Some(style) = style {
+                    output.push_str(", ");
+                    output.push_str(style);
+                }
+                output.push('}');
+            }
+            Part::Plural {
+                name,
+                ordinal,
+                offset,
+                arms,
+            } => {
+                output.push('{');
+                output.push_str(name);
+                output.push_str(if *ordinal {
+                    ", selectordinal,"
+                } else {
+                    ", plural,"
+                });
+                if *offset != 0.0 {
+                    output.push_str(format!(" offset:{}", offset).as_str());
+                }
+                for (selector, message) in arms {
+                    output.push(' ');
+                    output.push_str(selector);
+                    output.push_str(" {");
+                    write_source(message, true, output);
+                    output.push('}');
+                }
+                output.push('}');
+            }
+            Part::Select { name, arms } => {
+                output.push('{');
+                output.push_str(name);
+                output.push_str(", select,");
+                for (selector, message) in arms {
+                    output.push(' ');
+                    output.push_str(selector);
+                    output.push_str(" {");
+                    write_source(message, in_plural, output);
+                    output.push('}');
+                }
+                output.push('}');
+            }
+            Part::Pound => output.push('#'),
+        }
+    }
+}
+
+/// Serializes the parts of a message using the ICU MessageFormat syntax.
+///
+/// Parsing the serialized message results in the same parts.
+///
+/// # Parameters
+///
+/// - `parts`: The parts of the message.
+///
+/// # Returns
+///
+/// A `String` containing the message.
+pub(crate) fn serialize(parts: &[Part]) -> String {
+    let mut output: String = String::new();
+    write_source(parts, false, &mut output);
+
+    return output;
+}
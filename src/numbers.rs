@@ -0,0 +1,739 @@
+#![doc = include_str!("../.github/numbers.md")]
+// localizer-rs numbers
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use crate::arguments;
+use crate::plurals;
+
+
+/////////////
+// SYMBOLS //
+/////////////
+
+/// Number symbols and patterns of a locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Symbols {
+    /// The language tag of the locale.
+    pub(crate) language: &'static str,
+    /// The decimal separator.
+    pub(crate) decimal: &'static str,
+    /// The grouping separator.
+    pub(crate) group: &'static str,
+    /// The minus sign.
+    pub(crate) minus: &'static str,
+    /// The text preceding percentages.
+    pub(crate) percent_prefix: &'static str,
+    /// The text following percentages.
+    pub(crate) percent_suffix: &'static str,
+    /// Whether the currency symbol precedes the number.
+    pub(crate) currency_prefix: bool,
+    /// The text between the currency symbol and the number.
+    pub(crate) currency_spacing: &'static str,
+    /// The size of all groups except the last one, 2 for the Indian numbering system.
+    pub(crate) secondary_grouping: usize,
+    /// The minimum number of digits in front of the first grouping separator.
+    pub(crate) minimum_grouping: usize,
+}
+
+/// Creates the symbols of a locale, using the defaults for the grouping sizes, the minus sign and
+/// the currency pattern.
+const fn symbols(
+    language: &'static str,
+    decimal: &'static str,
+    group: &'static str,
+    percent_prefix: &'static str,
+    percent_suffix: &'static str,
+) -> Symbols {
+    return Symbols {
+        language,
+        decimal,
+        group,
+        minus: "-",
+        percent_prefix,
+        percent_suffix,
+        currency_prefix: true,
+        currency_spacing: "",
+        secondary_grouping: 3,
+        minimum_grouping: 1,
+    };
+}
+
+/// Number symbols of the bundled locales, taken from the CLDR.
+const SYMBOLS: [Symbols; 33] = [
+    symbols("en", ".", ",", "", "%"),
+    symbols("en-IN", ".", ",", "", "%").indian(),
+    symbols("de", ",", ".", "", "\u{a0}%").currency_suffix(),
+    symbols("de-AT", ",", "\u{a0}", "", "\u{a0}%").currency_spaced(),
+    symbols("de-CH", ".", "’", "", "%").currency_spaced(),
+    symbols("fr", ",", "\u{202f}", "", "\u{202f}%").currency_suffix(),
+    symbols("fr-CH", ",", "\u{202f}", "", "%").currency_suffix(),
+    symbols("es", ",", ".", "", "\u{a0}%")
+        .minimum_grouping(2)
+        .currency_suffix(),
+    symbols("es-MX", ".", ",", "", "\u{a0}%"),
+    symbols("it", ",", ".", "", "%").currency_suffix(),
+    symbols("it-CH", ".", "’", "", "%").currency_spaced(),
+    symbols("pt", ",", ".", "", "%").currency_spaced(),
+    symbols("pt-PT", ",", "\u{a0}", "", "%")
+        .minimum_grouping(2)
+        .currency_suffix(),
+    symbols("nl", ",", ".", "", "%").currency_spaced(),
+    symbols("pl", ",", "\u{a0}", "", "%")
+        .minimum_grouping(2)
+        .currency_suffix(),
+    symbols("cs", ",", "\u{a0}", "", "\u{a0}%").currency_suffix(),
+    symbols("sk", ",", "\u{a0}", "", "\u{a0}%").currency_suffix(),
+    symbols("ru", ",", "\u{a0}", "", "\u{a0}%").currency_suffix(),
+    symbols("uk", ",", "\u{a0}", "", "%").currency_suffix(),
+    symbols("sv", ",", "\u{a0}", "", "\u{a0}%")
+        .minus("−")
+        .currency_suffix(),
+    symbols("nb", ",", "\u{a0}", "", "\u{a0}%")
+        .minus("−")
+        .currency_suffix(),
+    symbols("no", ",", "\u{a0}", "", "\u{a0}%")
+        .minus("−")
+        .currency_suffix(),
+    symbols("da", ",", ".", "", "\u{a0}%").currency_suffix(),
+    symbols("fi", ",", "\u{a0}", "", "\u{a0}%")
+        .minus("−")
+        .currency_suffix(),
+    symbols("hu", ",", "\u{a0}", "", "%").currency_suffix(),
+    symbols("ro", ",", ".", "", "\u{a0}%").currency_suffix(),
+    symbols("el", ",", ".", "", "%").currency_suffix(),
+    symbols("tr", ",", ".", "%", ""),
+    symbols("ja", ".", ",", "", "%"),
+    symbols("zh", ".", ",", "", "%"),
+    symbols("ko", ".", ",", "", "%"),
+    symbols("hi", ".", ",", "", "%").indian(),
+    symbols("he", ".", ",", "", "%")
+        .minus("\u{200e}-")
+        .currency_suffix(),
+];
+
+impl Symbols {
+    /// Uses the Indian numbering system, which groups all digits but the last three in pairs.
+    const fn indian(mut self) -> Symbols {
+        self.secondary_grouping = 2;
+        return self;
+    }
+
+    /// Sets the minimum number of digits in front of the first grouping separator.
+    const fn minimum_grouping(mut self, digits: usize) -> Symbols {
+        self.minimum_grouping = digits;
+        return self;
+    }
+
+    /// Places the currency symbol after the number, separated by a space.
+    const fn currency_suffix(mut self) -> Symbols {
+        self.currency_prefix = false;
+        self.currency_spacing = "\u{a0}";
+        return self;
+    }
+
+    /// Separates the currency symbol preceding the number by a space.
+    const fn currency_spaced(mut self) -> Symbols {
+        self.currency_spacing = "\u{a0}";
+        return self;
+    }
+
+    /// Sets the minus sign.
+    const fn minus(mut self, minus: &'static str) -> Symbols {
+        self.minus = minus;
+        return self;
+    }
+}
+
+/// Currencies with their symbol and number of fraction digits, taken from the CLDR.
+///
+/// Currencies not listed here use their code as symbol and two fraction digits.
+const CURRENCIES: [(&str, &str, usize); 22] = [
+    ("USD", "$", 2),
+    ("EUR", "€", 2),
+    ("GBP", "£", 2),
+    ("JPY", "¥", 0),
+    ("CNY", "CN¥", 2),
+    ("INR", "₹", 2),
+    ("CHF", "CHF", 2),
+    ("CAD", "CA$", 2),
+    ("AUD", "A$", 2),
+    ("BRL", "R$", 2),
+    ("MXN", "MX$", 2),
+    ("KRW", "₩", 0),
+    ("RUB", "RUB", 2),
+    ("UAH", "UAH", 2),
+    ("PLN", "PLN", 2),
+    ("SEK", "SEK", 2),
+    ("NOK", "NOK", 2),
+    ("DKK", "DKK", 2),
+    ("CZK", "CZK", 2),
+    ("HUF", "HUF", 2),
+    ("TRY", "TRY", 2),
+    ("ILS", "₪", 2),
+];
+
+/// Currency symbols that differ in a locale, taken from the CLDR.
+const LOCAL_CURRENCIES: [(&str, &str, &str); 24] = [
+    ("en-AU", "AUD", "$"),
+    ("en-AU", "USD", "US$"),
+    ("en-CA", "CAD", "$"),
+    ("en-CA", "USD", "US$"),
+    ("fr", "USD", "$US"),
+    ("es", "USD", "US$"),
+    ("es-MX", "MXN", "$"),
+    ("es-MX", "USD", "USD"),
+    ("pt", "USD", "US$"),
+    ("ja", "JPY", "￥"),
+    ("ja", "CNY", "元"),
+    ("zh", "CNY", "¥"),
+    ("zh", "JPY", "JP¥"),
+    ("ru", "RUB", "₽"),
+    ("uk", "UAH", "₴"),
+    ("pl", "PLN", "zł"),
+    ("sv", "SEK", "kr"),
+    ("nb", "NOK", "kr"),
+    ("no", "NOK", "kr"),
+    ("da", "DKK", "kr."),
+    ("cs", "CZK", "Kč"),
+    ("hu", "HUF", "Ft"),
+    ("tr", "TRY", "₺"),
+    ("he", "ILS", "₪"),
+];
+
+/// Returns the symbol of a currency in a language.
+///
+/// # Parameters
+///
+/// - `language`: The language tag, eg. `de-AT`.
+/// - `code`: The ISO 4217 code of the currency, eg. `EUR`.
+///
+/// # Returns
+///
+/// The symbol used by the language tag or its base language, the default symbol or the code.
+fn currency_symbol(language: &str, code: &str) -> String {
+    let language: String = language.replace('_', "-");
+    let base: String = plurals::base_language(&language);
+    let local = |tag: &str| {
+        LOCAL_CURRENCIES
+            .iter()
+            .find(|(locale, currency, _symbol)| {
+                locale.eq_ignore_ascii_case(tag) && *currency == code
+            })
+            .map(|(_locale, _currency, symbol)| *symbol)
+    };
+
+    return match local(&language).or_else(|| local(&base)) {
+        Some(symbol) => symbol.to_owned(),
+        None => match CURRENCIES
+            .iter()
+            .find(|(currency, _symbol, _digits)| *currency == code)
+        {
+            Some((_currency, symbol, _digits)) => (*symbol).to_owned(),
+            None => code.to_owned(),
+        },
+    };
+}
+
+/// Returns the number of fraction digits of a currency.
+///
+/// # Parameters
+///
+/// - `code`: The ISO 4217 code of the currency, eg. `EUR`.
+///
+/// # Returns
+///
+/// The number of fraction digits, 2 for unknown currencies.
+fn currency_digits(code: &str) -> usize {
+    return CURRENCIES
+        .iter()
+        .find(|(currency, _symbol, _digits)| *currency == code)
+        .map_or(2, |(_currency, _symbol, digits)| *digits);
+}
+
+
+////////////
+// FORMAT //
+////////////
+
+/// Style of a formatted number.
+///
+/// # Variants
+///
+/// - `Decimal`: A decimal number, eg. `1,234.5`.
+/// - `Percent`: A percentage, the value is multiplied by 100, eg. `50%` for `0.5`.
+/// - `Currency`: An amount of money in the currency with the ISO 4217 code, eg. `€1,234.50`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NumberStyle {
+    /// A decimal number.
+    #[default]
+    Decimal,
+    /// A percentage, the value is multiplied by 100.
+    Percent,
+    /// An amount of money in the currency with the specified ISO 4217 code.
+    Currency(String),
+}
+
+/// Options for formatting numbers.
+///
+/// Use [`NumberFormat::decimal()`], [`NumberFormat::percent()`] or [`NumberFormat::currency()`]
+/// to create number formats.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// let format: localizer_rs::numbers::NumberFormat =
+///     localizer_rs::numbers::NumberFormat::decimal().fraction_digits(2, 2);
+///
+/// assert_eq!(localizer_rs::numbers::format("en", 1234.5, &format), "1,234.50");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberFormat {
+    /// The style of the number.
+    pub style: NumberStyle,
+    /// The minimum number of fraction digits, missing digits are filled with zeros.
+    pub minimum_fraction_digits: usize,
+    /// The maximum number of fraction digits, the number is rounded to this precision.
+    pub maximum_fraction_digits: usize,
+    /// Whether grouping separators are inserted.
+    pub grouping: bool,
+}
+
+impl NumberFormat {
+    /// Creates the format of decimal numbers.
+    ///
+    /// # Returns
+    ///
+    /// A `NumberFormat` with up to three fraction digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// let format = localizer_rs::numbers::NumberFormat::decimal();
+    ///
+    /// assert_eq!(localizer_rs::numbers::format("de", 1234567.5, &format), "1.234.567,5");
+    /// ```
+    pub fn decimal() -> NumberFormat {
+        return NumberFormat {
+            style: NumberStyle::Decimal,
+            minimum_fraction_digits: 0,
+            maximum_fraction_digits: 3,
+            grouping: true,
+        };
+    }
+
+    /// Creates the format of percentages.
+    ///
+    /// # Returns
+    ///
+    /// A `NumberFormat` without fraction digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// let format = localizer_rs::numbers::NumberFormat::percent();
+    ///
+    /// assert_eq!(localizer_rs::numbers::format("en", 0.256, &format), "26%");
+    /// ```
+    pub fn percent() -> NumberFormat {
+        return NumberFormat {
+            style: NumberStyle::Percent,
+            minimum_fraction_digits: 0,
+            maximum_fraction_digits: 0,
+            grouping: true,
+        };
+    }
+
+    /// Creates the format of amounts of money.
+    ///
+    /// The position and spacing of the currency symbol depend on the language the amount is
+    /// formatted for.
+    ///
+    /// # Parameters
+    ///
+    /// - `code`: The ISO 4217 code of the currency, eg. `EUR`.
+    ///
+    /// # Returns
+    ///
+    /// A `NumberFormat` using the fraction digits of the currency.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// let format = localizer_rs::numbers::NumberFormat::currency("EUR");
+    ///
+    /// assert_eq!(localizer_rs::numbers::format("en", 1234.5, &format), "€1,234.50");
+    /// assert_eq!(localizer_rs::numbers::format("de", 1234.5, &format), "1.234,50\u{a0}€");
+    /// ```
+    pub fn currency(code: &str) -> NumberFormat {
+        let code: String = code.to_uppercase();
+        let digits: usize = currency_digits(&code);
+
+        return NumberFormat {
+            style: NumberStyle::Currency(code),
+            minimum_fraction_digits: digits,
+            maximum_fraction_digits: digits,
+            grouping: true,
+        };
+    }
+
+    /// Sets the number of fraction digits.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The number format.
+    /// - `minimum`: The minimum number of fraction digits.
+    /// - `maximum`: The maximum number of fraction digits. Raised to `minimum` if it is smaller.
+    ///
+    /// # Returns
+    ///
+    /// The `NumberFormat` using the fraction digits.
+    pub fn fraction_digits(mut self, minimum: usize, maximum: usize) -> NumberFormat {
+        self.minimum_fraction_digits = minimum;
+        self.maximum_fraction_digits = maximum.max(minimum);
+        return self;
+    }
+
+    /// Parses the options of a number placeholder.
+    ///
+    /// # Parameters
+    ///
+    /// - `style`: The style of the placeholder, `number`, `percent` or `currency`.
+    /// - `option`: The fraction digits, eg. `2` or `0-2`, or the currency code, eg. `EUR`.
+    ///
+    /// # Returns
+    ///
+    /// The number format or `None` if the style or the option is not valid.
+    pub(crate) fn parse(style: &str, option: Option<&str>) -> Option<NumberFormat> {
+        let format: NumberFormat = match (style, option) {
+            ("number", _) => NumberFormat::decimal(),
+            ("percent", _) => NumberFormat::percent(),
+            ("currency", Some(code)) => {
+                let valid: bool =
+                    code.len() == 3 && code.chars().all(|c: char| c.is_ascii_alphabetic());
+                return valid.then(|| NumberFormat::currency(code));
+            }
+            _ => return None,
+        };
+
+        return match option {
+            None => Some(format),
+            Some(digits) => {
+                match digits.split_once('-') {
+                    Some((minimum, maximum)) => Some(format.fraction_digits(
+                        minimum.trim().parse().ok()?,
+                        maximum.trim().parse().ok()?,
+                    )),
+                    None => {
+                        let digits: usize = digits.trim().parse().ok()?;
+                        Some(format.fraction_digits(digits, digits))
+                    }
+                }
+            }
+        };
+    }
+}
+
+impl Default for NumberFormat {
+    fn default() -> NumberFormat {
+        return NumberFormat::decimal();
+    }
+}
+
+/// Decimal number split into its parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Decimal {
+    /// Whether the number is negative.
+    pub(crate) negative: bool,
+    /// The digits of the integer part, without leading zeros.
+    pub(crate) integer: String,
+    /// The digits of the fraction part.
+    pub(crate) fraction: String,
+}
+
+impl Decimal {
+    /// Parses a decimal number.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The number, eg. `-1234.5`.
+    ///
+    /// # Returns
+    ///
+    /// The parsed number or `None` if the value is not a decimal number.
+    pub(crate) fn parse(value: &str) -> Option<Decimal> {
+        let value: &str = value.trim();
+        let (negative, digits) = match value.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, value.strip_prefix('+').unwrap_or(value)),
+        };
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+
+        let valid = |part: &str| {
+            part.chars()
+                .all(|character: char| character.is_ascii_digit())
+        };
+        if (integer.is_empty() && fraction.is_empty()) || !valid(integer) || !valid(fraction) {
+            return None;
+        }
+
+        return Some(Decimal {
+            negative,
+            integer: integer.trim_start_matches('0').to_owned(),
+            fraction: fraction.to_owned(),
+        });
+    }
+
+    /// Converts a typed value into a decimal number.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The value to convert.
+    ///
+    /// # Returns
+    ///
+    /// The number or `None` if the value is not numeric.
+    pub(crate) fn from_value(value: &arguments::Value) -> Option<Decimal> {
+        return match value {
+            arguments::Value::Float(number) if !number.is_finite() => None,
+            arguments::Value::Bool(_value) => None,
+            arguments::Value::List(_values) => None,
+            _ => Decimal::parse(&value.to_string()),
+        };
+    }
+
+    /// Multiplies the number by 100.
+    fn percent(mut self) -> Decimal {
+        let mut fraction: String = std::mem::take(&mut self.fraction);
+        while fraction.len() < 2 {
+            fraction.push('0');
+        }
+
+        self.integer.push_str(&fraction[..2]);
+        self.integer = self.integer.trim_start_matches('0').to_owned();
+        self.fraction = fraction[2..].to_owned();
+        return self;
+    }
+
+    /// Rounds the number half to even and pads it with zeros.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The number.
+    /// - `minimum`: The minimum number of fraction digits.
+    /// - `maximum`: The maximum number of fraction digits.
+    ///
+    /// # Returns
+    ///
+    /// The rounded number.
+    pub(crate) fn round(mut self, minimum: usize, maximum: usize) -> Decimal {
+        if self.fraction.len() > maximum {
+            let rest: String = self.fraction.split_off(maximum);
+            let first: u8 = rest.as_bytes()[0] - b'0';
+            let last: u8 = match self
+                .fraction
+                .as_bytes()
+                .last()
+                .or(self.integer.as_bytes().last())
+            {
+                Some(digit) => digit - b'0',
+                None => 0,
+            };
+            let round_up: bool = first > 5
+                || (first == 5
+                    && (rest[1..].bytes().any(|digit: u8| digit != b'0') || last % 2 == 1));
+
+            if round_up {
+                let mut digits: Vec<u8> = format!("{}{}", self.integer, self.fraction).into_bytes();
+                let mut index: usize = digits.len();
+                loop {
+                    if index == 0 {
+                        digits.insert(0, b'1');
+                        break;
+                    }
+                    index -= 1;
+                    if digits[index] == b'9' {
+                        digits[index] = b'0';
+                    } else {
+                        digits[index] += 1;
+                        break;
+                    }
+                }
+
+                let digits: String = String::from_utf8(digits).unwrap_or_default();
+                let split: usize = digits.len() - self.fraction.len();
+                self.integer = digits[..split].to_owned();
+                self.fraction = digits[split..].to_owned();
+            }
+        }
+
+        while self.fraction.len() > minimum && self.fraction.ends_with('0') {
+            self.fraction.pop();
+        }
+        while self.fraction.len() < minimum {
+            self.fraction.push('0');
+        }
+
+        return self;
+    }
+
+    /// Returns whether the number is zero.
+    fn is_zero(&self) -> bool {
+        return self.integer.is_empty() && self.fraction.bytes().all(|digit: u8| digit == b'0');
+    }
+
+    /// Formats the number using the symbols of a locale.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The rounded number.
+    /// - `symbols`: The symbols of the locale.
+    /// - `grouping`: Whether grouping separators are inserted.
+    ///
+    /// # Returns
+    ///
+    /// The formatted number, without the minus sign.
+    pub(crate) fn write(&self, symbols: &Symbols, grouping: bool) -> String {
+        let integer: &str = if self.integer.is_empty() {
+            "0"
+        } else {
+            &self.integer
+        };
+        let mut output: String = String::new();
+
+        if grouping && integer.len() >= 3 + symbols.minimum_grouping {
+            let (head, last) = integer.split_at(integer.len() - 3);
+            let first: usize = match head.len() % symbols.secondary_grouping {
+                0 => symbols.secondary_grouping,
+                length => length,
+            };
+
+            output.push_str(&head[..first]);
+            for chunk in head.as_bytes()[first..].chunks(symbols.secondary_grouping) {
+                output.push_str(symbols.group);
+                output.push_str(std::str::from_utf8(chunk).unwrap_or_default());
+            }
+            output.push_str(symbols.group);
+            output.push_str(last);
+        } else {
+            output.push_str(integer);
+        }
+
+        if !self.fraction.is_empty() {
+            output.push_str(symbols.decimal);
+            output.push_str(&self.fraction);
+        }
+
+        return output;
+    }
+}
+
+/// Formats a number for a language.
+///
+/// Integers are formatted exactly, floating point numbers using their shortest representation.
+/// Values that are not numeric are returned unchanged.
+///
+/// # Parameters
+///
+/// - `language`: The language to format the number for, eg. `de-AT`.
+/// - `value`: The number to format.
+/// - `format`: The number format to use.
+///
+/// # Returns
+///
+/// A `String` containing the formatted number.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// use localizer_rs::numbers::NumberFormat;
+///
+/// assert_eq!(localizer_rs::numbers::format("en", 1234567.5, &NumberFormat::decimal()), "1,234,567.5");
+/// assert_eq!(localizer_rs::numbers::format("de", 1234567.5, &NumberFormat::decimal()), "1.234.567,5");
+/// assert_eq!(localizer_rs::numbers::format("hi", 1234567, &NumberFormat::decimal()), "12,34,567");
+/// assert_eq!(localizer_rs::numbers::format("fr", 0.5, &NumberFormat::percent()), "50\u{202f}%");
+/// ```
+///
+/// # See also
+///
+/// - [`NumberFormat`]
+/// - [`crate::Config::format_number()`]
+pub fn format<V: Into<arguments::Value>>(
+    language: &str,
+    value: V,
+    format: &NumberFormat,
+) -> String {
+    let value: arguments::Value = value.into();
+
+    let decimal: Decimal = match Decimal::from_value(&value) {
+        Some(decimal) => decimal,
+        None => return value.to_string(),
+    };
+    let decimal: Decimal = match format.style {
+        NumberStyle::Decimal | NumberStyle::Currency(_) => decimal,
+        NumberStyle::Percent => decimal.percent(),
+    }
+    .round(
+        format.minimum_fraction_digits,
+        format.maximum_fraction_digits,
+    );
+
+    let symbols: &Symbols =
+        plurals::locale_entry(&SYMBOLS, language, |symbols: &Symbols| symbols.language);
+    let minus: &str = if decimal.negative && !decimal.is_zero() {
+        symbols.minus
+    } else {
+        ""
+    };
+    let number: String = decimal.write(symbols, format.grouping);
+
+    return match &format.style {
+        NumberStyle::Decimal => format!("{}{}", minus, number),
+        NumberStyle::Percent => format!(
+            "{}{}{}{}",
+            minus, symbols.percent_prefix, number, symbols.percent_suffix
+        ),
+        NumberStyle::Currency(code) => {
+            let symbol: String = currency_symbol(language, code);
+            // Letters are always separated from the digits, eg. `CHF 10.00`
+            let spacing: &str = match (symbols.currency_spacing, symbol.chars().last()) {
+                ("", Some(last)) if last.is_alphabetic() => "\u{a0}",
+                (spacing, _last) => spacing,
+            };
+
+            match symbols.currency_prefix {
+                true => format!("{}{}{}{}", minus, symbol, spacing, number),
+                false => format!("{}{}{}{}", minus, number, spacing, symbol),
+            }
+        }
+    };
+}
@@ -0,0 +1,341 @@
+// localizer-rs placeholders
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::borrow::Cow;
+
+use crate::arguments;
+#[cfg(feature = "chrono")]
+use crate::dates;
+use crate::lists;
+use crate::numbers;
+use crate::relative;
+use crate::styles;
+
+
+//////////////////
+// PLACEHOLDERS //
+//////////////////
+
+/// Formats the value of a placeholder.
+///
+/// # Parameters
+///
+/// - `language`: The language to format the value for.
+/// - `value`: The value of the argument.
+/// - `style`: The style of the placeholder, eg. `number`, `list` or `relative`.
+/// - `option`: The option of the style, eg. the fraction digits, the conjunction or the unit.
+///
+/// # Returns
+///
+/// The formatted value or `None` if the style is not supported.
+fn format(
+    language: &str,
+    value: &arguments::Value,
+    style: &str,
+    option: Option<&str>,
+) -> Option<String> {
+    if let Some(number_format) = numbers::NumberFormat::parse(style, option) {
+        return Some(numbers::format(language, value.clone(), &number_format));
+    }
+    if let Some(list_style) = lists::ListStyle::parse(style, option) {
+        return Some(lists::format(language, value.clone(), list_style));
+    }
+    if let Some(relative_format) = relative::RelativeFormat::parse(style, option) {
+        return Some(relative::format(language, value.clone(), &relative_format));
+    }
+    #[cfg(feature = "chrono")]
+    if let Some(date_format) = dates::DateFormat::parse(style, option) {
+        return Some(dates::format(language, value.clone(), &date_format));
+    }
+
+    return None;
+}
+
+/// A piece of a message before the style tags are matched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Token<'a> {
+    /// Text written as it is, eg. a formatted argument.
+    Text(Cow<'a, str>),
+    /// A `{{name}}` placeholder without the braces, eg. `bold` or `count, number`.
+    Placeholder(Cow<'a, str>),
+}
+
+impl Token<'_> {
+    /// Returns the number of characters the token takes up in the message.
+    fn length(&self) -> usize {
+        return match self {
+            Token::Text(text) => text.chars().count(),
+            Token::Placeholder(placeholder) => placeholder.chars().count() + 4,
+        };
+    }
+}
+
+/// A part of a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Node<'a> {
+    /// Text written as it is.
+    Text(&'a str),
+    /// A `{{name}}` placeholder, replaced with an argument or a style.
+    Placeholder(&'a str),
+    /// A style tag like `{{bold}}...{{/bold}}` and the parts it contains.
+    Tag {
+        /// The name of the style.
+        name: &'a str,
+        /// The parts between the opening and the closing tag.
+        children: Vec<Node<'a>>,
+    },
+}
+
+/// Splits a message into text and `{{name}}` placeholders.
+///
+/// # Parameters
+///
+/// - `message`: The message containing the placeholders.
+///
+/// # Returns
+///
+/// The text and placeholders of the message.
+fn tokenize(message: &str) -> Vec<Token<'_>> {
+    let mut tokens: Vec<Token> = vec![];
+    let mut offset: usize = 0;
+
+    while let Some(start) = message[offset..]
+        .find("{{")
+        .map(|start: usize| offset + start)
+    {
+        let end: usize = match message[start + 2..].find("}}") {
+            Some(end) => start + 2 + end,
+            None => break,
+        };
+        if start > offset {
+            tokens.push(Token::Text(Cow::Borrowed(&message[offset..start])));
+        }
+        tokens.push(Token::Placeholder(Cow::Borrowed(&message[start + 2..end])));
+        offset = end + 2;
+    }
+
+    if offset < message.len() {
+        tokens.push(Token::Text(Cow::Borrowed(&message[offset..])));
+    }
+
+    return tokens;
+}
+
+/// Parses the tokens of a message into a tree of parts.
+///
+/// Style tags are only parsed if the message contains a closing tag like `{{/bold}}`. The style
+/// placeholders of such messages are opening tags, which have to be closed in reverse order.
+/// Messages without closing tags keep the flat form, eg. `{{bold}}...{{end}}`.
+///
+/// # Parameters
+///
+/// - `tokens`: The text and placeholders of the message.
+/// - `is_tag`: Whether a placeholder is a style that can be used as a tag.
+///
+/// # Returns
+///
+/// A `Result` containing the parts of the message or the character position and the description
+/// of the unbalanced tag.
+fn parse<'a>(
+    tokens: &'a [Token],
+    is_tag: impl Fn(&str) -> bool,
+) -> Result<Vec<Node<'a>>, (usize, String)> {
+    let tags: bool = tokens.iter().any(|token: &Token| {
+        matches!(token, Token::Placeholder(placeholder) if placeholder.starts_with('/'))
+    });
+
+    let mut nodes: Vec<Node> = vec![];
+    // The open tags, their positions and the parts of the message before them
+    let mut stack: Vec<(&str, usize, Vec<Node>)> = vec![];
+    let mut position: usize = 0;
+
+    for token in tokens {
+        let start: usize = position;
+        position += token.length();
+
+        let placeholder: &str = match token {
+            Token::Text(text) => {
+                nodes.push(Node::Text(text));
+                continue;
+            }
+            Token::Placeholder(placeholder) => placeholder,
+        };
+
+        if !tags || !(placeholder.starts_with('/') || is_tag(placeholder)) {
+            nodes.push(Node::Placeholder(placeholder));
+            continue;
+        }
+        if placeholder == "end" {
+            return Err((
+                start,
+                "{{end}} cannot be used together with closing tags".to_owned(),
+            ));
+        }
+
+        let name: &str = match placeholder.strip_prefix('/') {
+            Some(name) => name.trim(),
+            None => {
+                stack.push((placeholder, start, std::mem::take(&mut nodes)));
+                continue;
+            }
+        };
+        let (open, _start, parent) = match stack.pop() {
+            Some(value) => value,
+            None => {
+                return Err((start, format!("{{{{/{}}}}} has no opening tag", name)));
+            }
+        };
+        if open != name {
+            return Err((
+                start,
+                format!("Expected {{{{/{}}}}}, found {{{{/{}}}}}", open, name),
+            ));
+        }
+        let children: Vec<Node> = std::mem::replace(&mut nodes, parent);
+        nodes.push(Node::Tag {
+            name: open,
+            children,
+        });
+    }
+
+    if let Some((name, start, _parent)) = stack.pop() {
+        return Err((start, format!("{{{{{}}}}} is never closed", name)));
+    }
+
+    return Ok(nodes);
+}
+
+/// Renders the parts of a message.
+///
+/// # Parameters
+///
+/// - `nodes`: The parts of the message.
+/// - `language`: The language to format the values for.
+/// - `arguments`: The arguments to replace.
+/// - `output`: The rendered message.
+fn render<'a>(
+    nodes: &[Node<'a>],
+    language: &str,
+    arguments: &arguments::Args,
+    output: &mut styles::Output<'a>,
+) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => output.text(text),
+            Node::Placeholder(placeholder) => {
+                let (name, style) = match placeholder.split_once(',') {
+                    Some((name, style)) => (name.trim(), Some(style.trim())),
+                    None => (*placeholder, None),
+                };
+
+                let replacement: Option<String> = match (arguments.get(name), style) {
+                    (Some(value), None) => Some(value.to_string()),
+                    (Some(value), Some(style)) => match style.split_once(':') {
+                        Some((style, option)) => {
+                            format(language, value, style.trim(), Some(option.trim()))
+                        }
+                        None => format(language, value, style, None),
+                    },
+                    (None, _style) => None,
+                };
+
+                match replacement {
+                    Some(value) => output.text(&value),
+                    // Colors like `{{back.rgb(10,20,30)}}` contain commas
+                    None if output.style(placeholder) => {}
+                    None => output.text(&format!("{{{{{}}}}}", placeholder)),
+                }
+            }
+            Node::Tag { name, children } => {
+                output.open(name);
+                render(children, language, arguments, output);
+                output.close();
+            }
+        }
+    }
+}
+
+/// Replaces the `{{name}}` placeholders of a message.
+///
+/// Placeholders can specify a style, eg. `{{name, number}}` or `{{name, number:2}}`. Placeholders
+/// without arguments and unsupported styles are kept as they are. Style tags like
+/// `{{bold}}...{{/bold}}` are closed automatically, keeping the styles of the outer tags.
+///
+/// # Parameters
+///
+/// - `message`: The message containing the placeholders.
+/// - `language`: The language to format the values for.
+/// - `arguments`: The arguments to replace.
+/// - `styling`: How style placeholders like `{{bold}}` are rendered.
+///
+/// # Returns
+///
+/// A `Result` containing the message with the placeholders replaced or the character position
+/// and the description of an unbalanced style tag.
+pub(crate) fn replace(
+    message: &str,
+    language: &str,
+    arguments: &arguments::Args,
+    styling: &styles::Styling,
+) -> Result<String, (usize, String)> {
+    return replace_tokens(&tokenize(message), language, arguments, styling);
+}
+
+/// Replaces the placeholders of a message that is already split into tokens.
+///
+/// Text tokens are never parsed for placeholders, so formatted arguments containing `{{bold}}`
+/// are written as they are.
+///
+/// # Parameters
+///
+/// - `tokens`: The text and placeholders of the message.
+/// - `language`: The language to format the values for.
+/// - `arguments`: The arguments to replace.
+/// - `styling`: How style placeholders like `{{bold}}` are rendered.
+///
+/// # Returns
+///
+/// A `Result` containing the message with the placeholders replaced or the character position
+/// and the description of an unbalanced style tag.
+///
+/// # See also
+///
+/// - [`replace()`]
+pub(crate) fn replace_tokens(
+    tokens: &[Token],
+    language: &str,
+    arguments: &arguments::Args,
+    styling: &styles::Styling,
+) -> Result<String, (usize, String)> {
+    let nodes: Vec<Node> = parse(tokens, |name: &str| {
+        arguments.get(name).is_none() && styles::is_style_of(name, styling.theme)
+    })?;
+    let mut output: styles::Output = styles::Output::new(styling);
+    render(&nodes, language, arguments, &mut output);
+
+    return Ok(output.finish());
+}
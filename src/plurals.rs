@@ -0,0 +1,800 @@
+#![doc = include_str!("../.github/plurals.md")]
+// localizer-rs plurals
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::fmt;
+
+
+//////////////
+// CATEGORY //
+//////////////
+
+/// CLDR plural category.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// let category = localizer_rs::plurals::Category::from_name("few");
+///
+/// assert_eq!(category, Some(localizer_rs::plurals::Category::Few));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Category {
+    /// The `zero` category.
+    Zero,
+    /// The `one` category.
+    One,
+    /// The `two` category.
+    Two,
+    /// The `few` category.
+    Few,
+    /// The `many` category.
+    Many,
+    /// The `other` category. Every language uses this category.
+    Other,
+}
+
+impl Category {
+    /// Returns the CLDR name of the category.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The category.
+    ///
+    /// # Returns
+    ///
+    /// The name of the category, eg. `few`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// assert_eq!(localizer_rs::plurals::Category::One.name(), "one");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        return match self {
+            Category::Zero => "zero",
+            Category::One => "one",
+            Category::Two => "two",
+            Category::Few => "few",
+            Category::Many => "many",
+            Category::Other => "other",
+        };
+    }
+
+    /// Returns the category with the specified CLDR name.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the category, eg. `few`.
+    ///
+    /// # Returns
+    ///
+    /// The category or `None` if the name is not a CLDR plural category.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// assert_eq!(
+    ///     localizer_rs::plurals::Category::from_name("other"),
+    ///     Some(localizer_rs::plurals::Category::Other)
+    /// );
+    /// assert_eq!(localizer_rs::plurals::Category::from_name("several"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Category> {
+        return match name {
+            "zero" => Some(Category::Zero),
+            "one" => Some(Category::One),
+            "two" => Some(Category::Two),
+            "few" => Some(Category::Few),
+            "many" => Some(Category::Many),
+            "other" => Some(Category::Other),
+            _ => None,
+        };
+    }
+}
+
+/// Display implementation for the category.
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+
+//////////////
+// OPERANDS //
+//////////////
+
+/// CLDR plural operands of a number.
+///
+/// Operands are created from integers, floats or decimal strings. Decimal strings keep their
+/// trailing zeros, which some languages take into account.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// let operands = localizer_rs::plurals::Operands::parse("1.50").unwrap();
+///
+/// assert_eq!(operands.i, 1);
+/// assert_eq!(operands.v, 2);
+/// assert_eq!(operands.f, 50);
+/// assert_eq!(operands.t, 5);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Operands {
+    /// The absolute value of the number.
+    pub n: f64,
+    /// The integer digits of the number.
+    pub i: u64,
+    /// The number of visible fraction digits, with trailing zeros.
+    pub v: usize,
+    /// The number of visible fraction digits, without trailing zeros.
+    pub w: usize,
+    /// The visible fraction digits, with trailing zeros.
+    pub f: u64,
+    /// The visible fraction digits, without trailing zeros.
+    pub t: u64,
+    /// Whether the number is negative.
+    pub negative: bool,
+}
+
+impl Operands {
+    /// Parses the operands of a decimal string.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The decimal string, eg. `-1.50`.
+    ///
+    /// # Returns
+    ///
+    /// The operands or `None` if the string is not a decimal number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// assert!(localizer_rs::plurals::Operands::parse("12").is_some());
+    /// assert!(localizer_rs::plurals::Operands::parse("twelve").is_none());
+    /// ```
+    pub fn parse(value: &str) -> Option<Operands> {
+        let value: &str = value.trim();
+        let (negative, digits) = match value.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, value.strip_prefix('+').unwrap_or(value)),
+        };
+        let (integer, fraction) = match digits.split_once('.') {
+            Some((integer, fraction)) => (integer, fraction),
+            None => (digits, ""),
+        };
+
+        if integer.is_empty()
+            || !integer
+                .chars()
+                .all(|character: char| character.is_ascii_digit())
+            || !fraction
+                .chars()
+                .all(|character: char| character.is_ascii_digit())
+            || (digits.contains('.') && fraction.is_empty())
+        {
+            return None;
+        }
+
+        let trimmed: &str = fraction.trim_end_matches('0');
+
+        return Some(Operands {
+            n: digits.parse::<f64>().ok()?,
+            i: integer.parse::<u64>().ok()?,
+            v: fraction.len(),
+            w: trimmed.len(),
+            f: if fraction.is_empty() {
+                0
+            } else {
+                fraction.parse::<u64>().ok()?
+            },
+            t: if trimmed.is_empty() {
+                0
+            } else {
+                trimmed.parse::<u64>().ok()?
+            },
+            negative,
+        });
+    }
+}
+
+/// Display implementation for the operands, formatting the number they were created from.
+impl fmt::Display for Operands {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", self.i)?;
+        if self.v > 0 {
+            write!(f, ".{:0width$}", self.f, width = self.v)?;
+        }
+
+        return Ok(());
+    }
+}
+
+macro_rules! operands_from_integer {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for Operands {
+                fn from(value: $type) -> Operands {
+                    let signed: i128 = value as i128;
+                    let absolute: u64 = signed.unsigned_abs() as u64;
+
+                    return Operands {
+                        n: absolute as f64,
+                        i: absolute,
+                        v: 0,
+                        w: 0,
+                        f: 0,
+                        t: 0,
+                        negative: signed < 0,
+                    };
+                }
+            }
+        )*
+    };
+}
+
+operands_from_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl From<f64> for Operands {
+    fn from(value: f64) -> Operands {
+        return match Operands::parse(format!("{}", value).as_str()) {
+            Some(operands) => operands,
+            None => Operands {
+                n: value.abs(),
+                i: 0,
+                v: 0,
+                w: 0,
+                f: 0,
+                t: 0,
+                negative: value.is_sign_negative(),
+            },
+        };
+    }
+}
+
+impl From<f32> for Operands {
+    fn from(value: f32) -> Operands {
+        return Operands::from(format!("{}", value).parse::<f64>().unwrap_or(value as f64));
+    }
+}
+
+
+///////////
+// RULES //
+///////////
+
+/// Returns the base language of a language tag.
+///
+/// # Parameters
+///
+/// - `language`: The language tag, eg. `de-AT`.
+///
+/// # Returns
+///
+/// The lowercase base language, eg. `de`.
+pub(crate) fn base_language(language: &str) -> String {
+    return language
+        .split(['-', '_'])
+        .next()
+        .unwrap_or("")
+        .to_lowercase();
+}
+
+/// Returns the entry of a table of bundled locales for a language.
+///
+/// Language tags are compared case-insensitively, with `_` treated as `-`. Languages without an
+/// entry of their own use the entry of their base language, eg. `de` for `de-AT`, or the first
+/// entry, which is English, if the base language is not listed either.
+///
+/// # Parameters
+///
+/// - `table`: The entries of the bundled locales, starting with English.
+/// - `language`: The language tag, eg. `de-AT`.
+/// - `tag`: Returns the language tag of an entry.
+///
+/// # Returns
+///
+/// The entry of the language tag, its base language or English.
+pub(crate) fn locale_entry<'a, T>(
+    table: &'a [T],
+    language: &str,
+    tag: impl Fn(&T) -> &str,
+) -> &'a T {
+    let language: String = language.replace('_', "-");
+    let base: String = base_language(&language);
+
+    return table
+        .iter()
+        .find(|entry: &&T| tag(entry).eq_ignore_ascii_case(&language))
+        .or_else(|| table.iter().find(|entry: &&T| tag(entry) == base))
+        .unwrap_or(&table[0]);
+}
+
+/// Returns whether the value is an integer in the specified range.
+///
+/// # Parameters
+///
+/// - `value`: The value to check.
+/// - `start`: The start of the range.
+/// - `end`: The inclusive end of the range.
+///
+/// # Returns
+///
+/// `true` if the value is an integer between `start` and `end`.
+fn in_range(value: f64, start: u64, end: u64) -> bool {
+    return value.fract() == 0.0 && value >= start as f64 && value <= end as f64;
+}
+
+/// Returns the CLDR cardinal plural category of a number.
+///
+/// Unknown languages use the English rules.
+///
+/// # Parameters
+///
+/// - `language`: The language tag, eg. `pl` or `pt-PT`.
+/// - `operands`: The operands of the number.
+///
+/// # Returns
+///
+/// The plural category of the number in the specified language.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// use localizer_rs::plurals::{cardinal, Category, Operands};
+///
+/// assert_eq!(cardinal("en", &Operands::from(1)), Category::One);
+/// assert_eq!(cardinal("pl", &Operands::from(3)), Category::Few);
+/// assert_eq!(cardinal("pl", &Operands::from(5)), Category::Many);
+/// assert_eq!(cardinal("ar", &Operands::from(100)), Category::Other);
+/// ```
+pub fn cardinal(language: &str, operands: &Operands) -> Category {
+    let n: f64 = operands.n;
+    let i: u64 = operands.i;
+    let v: usize = operands.v;
+    let f: u64 = operands.f;
+    let t: u64 = operands.t;
+    let million: bool = i != 0 && i % 1_000_000 == 0 && v == 0;
+
+    return match base_language(language).as_str() {
+        // Languages without plural forms
+        "bo" | "dz" | "id" | "ig" | "ja" | "jv" | "km" | "ko" | "lo" | "ms" | "my" | "sah"
+        | "th" | "to" | "vi" | "wo" | "yo" | "yue" | "zh" => Category::Other,
+
+        // one: n = 1
+        "af" | "az" | "bg" | "el" | "eu" | "hu" | "ka" | "kk" | "ky" | "lb" | "ml" | "mn"
+        | "mr" | "nb" | "ne" | "nn" | "no" | "or" | "ps" | "sq" | "ta" | "te" | "tk" | "tr"
+        | "ug" | "uz" => {
+            if n == 1.0 {
+                Category::One
+            } else {
+                Category::Other
+            }
+        }
+
+        // one: i = 0 or n = 1
+        "am" | "as" | "bn" | "fa" | "gu" | "hi" | "kn" | "zu" => {
+            if i == 0 || n == 1.0 {
+                Category::One
+            } else {
+                Category::Other
+            }
+        }
+
+        "da" => {
+            if n == 1.0 || (t != 0 && (i == 0 || i == 1)) {
+                Category::One
+            } else {
+                Category::Other
+            }
+        }
+
+        "is" => {
+            if (t == 0 && i % 10 == 1 && i % 100 != 11) || (t % 10 == 1 && t % 100 != 11) {
+                Category::One
+            } else {
+                Category::Other
+            }
+        }
+
+        "fr" => {
+            if i == 0 || i == 1 {
+                Category::One
+            } else if million {
+                Category::Many
+            } else {
+                Category::Other
+            }
+        }
+
+        "es" => {
+            if n == 1.0 {
+                Category::One
+            } else if million {
+                Category::Many
+            } else {
+                Category::Other
+            }
+        }
+
+        "pt" => {
+            let portugal: bool = language.to_lowercase().replace('_', "-") == "pt-pt";
+
+            if (portugal && i == 1 && v == 0) || (!portugal && (i == 0 || i == 1)) {
+                Category::One
+            } else if million {
+                Category::Many
+            } else {
+                Category::Other
+            }
+        }
+
+        "ca" | "it" => {
+            if i == 1 && v == 0 {
+                Category::One
+            } else if million {
+                Category::Many
+            } else {
+                Category::Other
+            }
+        }
+
+        "be" | "ru" | "uk" => {
+            if v == 0 && i % 10 == 1 && i % 100 != 11 {
+                Category::One
+            } else if v == 0 && (2..=4).contains(&(i % 10)) && !(12..=14).contains(&(i % 100)) {
+                Category::Few
+            } else if v == 0
+                && (i % 10 == 0 || (5..=9).contains(&(i % 10)) || (11..=14).contains(&(i % 100)))
+            {
+                Category::Many
+            } else {
+                Category::Other
+            }
+        }
+
+        "pl" => {
+            if i == 1 && v == 0 {
+                Category::One
+            } else if v == 0 && (2..=4).contains(&(i % 10)) && !(12..=14).contains(&(i % 100)) {
+                Category::Few
+            } else if v == 0
+                && ((i != 1 && i % 10 <= 1)
+                    || (5..=9).contains(&(i % 10))
+                    || (12..=14).contains(&(i % 100)))
+            {
+                Category::Many
+            } else {
+                Category::Other
+            }
+        }
+
+        "cs" | "sk" => {
+            if i == 1 && v == 0 {
+                Category::One
+            } else if (2..=4).contains(&i) && v == 0 {
+                Category::Few
+            } else if v != 0 {
+                Category::Many
+            } else {
+                Category::Other
+            }
+        }
+
+        "bs" | "hr" | "sr" => {
+            if (v == 0 && i % 10 == 1 && i % 100 != 11) || (f % 10 == 1 && f % 100 != 11) {
+                Category::One
+            } else if (v == 0 && (2..=4).contains(&(i % 10)) && !(12..=14).contains(&(i % 100)))
+                || ((2..=4).contains(&(f % 10)) && !(12..=14).contains(&(f % 100)))
+            {
+                Category::Few
+            } else {
+                Category::Other
+            }
+        }
+
+        "mk" => {
+            if (v == 0 && i % 10 == 1 && i % 100 != 11) || (f % 10 == 1 && f % 100 != 11) {
+                Category::One
+            } else {
+                Category::Other
+            }
+        }
+
+        "sl" => {
+            if v == 0 && i % 100 == 1 {
+                Category::One
+            } else if v == 0 && i % 100 == 2 {
+                Category::Two
+            } else if v != 0 || (3..=4).contains(&(i % 100)) {
+                Category::Few
+            } else {
+                Category::Other
+            }
+        }
+
+        "lt" => {
+            if n % 10.0 == 1.0 && !in_range(n % 100.0, 11, 19) {
+                Category::One
+            } else if in_range(n % 10.0, 2, 9) && !in_range(n % 100.0, 11, 19) {
+                Category::Few
+            } else if f != 0 {
+                Category::Many
+            } else {
+                Category::Other
+            }
+        }
+
+        "lv" => {
+            if n % 10.0 == 0.0
+                || in_range(n % 100.0, 11, 19)
+                || (v == 2 && (11..=19).contains(&(f % 100)))
+            {
+                Category::Zero
+            } else if (n % 10.0 == 1.0 && n % 100.0 != 11.0)
+                || (f % 10 == 1 && (v != 2 || f % 100 != 11))
+            {
+                Category::One
+            } else {
+                Category::Other
+            }
+        }
+
+        "ro" => {
+            if i == 1 && v == 0 {
+                Category::One
+            } else if v != 0 || n == 0.0 || (n != 1.0 && in_range(n % 100.0, 1, 19)) {
+                Category::Few
+            } else {
+                Category::Other
+            }
+        }
+
+        "he" => {
+            if (i == 1 && v == 0) || (i == 0 && v != 0) {
+                Category::One
+            } else if i == 2 && v == 0 {
+                Category::Two
+            } else {
+                Category::Other
+            }
+        }
+
+        "ar" => {
+            if n == 0.0 {
+                Category::Zero
+            } else if n == 1.0 {
+                Category::One
+            } else if n == 2.0 {
+                Category::Two
+            } else if in_range(n % 100.0, 3, 10) {
+                Category::Few
+            } else if in_range(n % 100.0, 11, 99) {
+                Category::Many
+            } else {
+                Category::Other
+            }
+        }
+
+        "ga" => {
+            if n == 1.0 {
+                Category::One
+            } else if n == 2.0 {
+                Category::Two
+            } else if in_range(n, 3, 6) {
+                Category::Few
+            } else if in_range(n, 7, 10) {
+                Category::Many
+            } else {
+                Category::Other
+            }
+        }
+
+        "cy" => {
+            if n == 0.0 {
+                Category::Zero
+            } else if n == 1.0 {
+                Category::One
+            } else if n == 2.0 {
+                Category::Two
+            } else if n == 3.0 {
+                Category::Few
+            } else if n == 6.0 {
+                Category::Many
+            } else {
+                Category::Other
+            }
+        }
+
+        "fil" | "tl" => {
+            if (v == 0 && ((1..=3).contains(&i) || ![4, 6, 9].contains(&(i % 10))))
+                || (v != 0 && ![4, 6, 9].contains(&(f % 10)))
+            {
+                Category::One
+            } else {
+                Category::Other
+            }
+        }
+
+        // one: i = 1 and v = 0 (English, German, Dutch, Swedish, ...)
+        _ => {
+            if i == 1 && v == 0 {
+                Category::One
+            } else {
+                Category::Other
+            }
+        }
+    };
+}
+
+/// Returns the CLDR ordinal plural category of a number.
+///
+/// Unknown languages only use the `other` category.
+///
+/// # Parameters
+///
+/// - `language`: The language tag, eg. `en`.
+/// - `operands`: The operands of the number.
+///
+/// # Returns
+///
+/// The ordinal plural category of the number in the specified language.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// use localizer_rs::plurals::{ordinal, Category, Operands};
+///
+/// assert_eq!(ordinal("en", &Operands::from(1)), Category::One);
+/// assert_eq!(ordinal("en", &Operands::from(22)), Category::Two);
+/// assert_eq!(ordinal("en", &Operands::from(13)), Category::Other);
+/// assert_eq!(ordinal("de", &Operands::from(1)), Category::Other);
+/// ```
+pub fn ordinal(language: &str, operands: &Operands) -> Category {
+    let n: f64 = operands.n;
+
+    return match base_language(language).as_str() {
+        "en" => {
+            if n % 10.0 == 1.0 && n % 100.0 != 11.0 {
+                Category::One
+            } else if n % 10.0 == 2.0 && n % 100.0 != 12.0 {
+                Category::Two
+            } else if n % 10.0 == 3.0 && n % 100.0 != 13.0 {
+                Category::Few
+            } else {
+                Category::Other
+            }
+        }
+
+        "fil" | "fr" | "ga" | "hy" | "lo" | "ms" | "ro" | "tl" | "vi" => {
+            if n == 1.0 {
+                Category::One
+            } else {
+                Category::Other
+            }
+        }
+
+        "hu" => {
+            if n == 1.0 || n == 5.0 {
+                Category::One
+            } else {
+                Category::Other
+            }
+        }
+
+        "it" => {
+            if [8.0, 11.0, 80.0, 800.0].contains(&n) {
+                Category::Many
+            } else {
+                Category::Other
+            }
+        }
+
+        "sv" => {
+            if (n % 10.0 == 1.0 || n % 10.0 == 2.0) && n % 100.0 != 11.0 && n % 100.0 != 12.0 {
+                Category::One
+            } else {
+                Category::Other
+            }
+        }
+
+        "ca" => {
+            if n == 1.0 || n == 3.0 {
+                Category::One
+            } else if n == 2.0 {
+                Category::Two
+            } else if n == 4.0 {
+                Category::Few
+            } else {
+                Category::Other
+            }
+        }
+
+        _ => Category::Other,
+    };
+}
+
+
+///////////////
+// SELECTION //
+///////////////
+
+/// Returns whether the json value contains plural forms.
+///
+/// Plural forms are json objects whose keys are all CLDR plural categories or exact matches like
+/// `=0`.
+///
+/// # Parameters
+///
+/// - `value`: The json value to check.
+///
+/// # Returns
+///
+/// `true` if the value is an object containing plural forms.
+pub(crate) fn is_plural(value: &serde_json::Value) -> bool {
+    return localizer_rs_shared::is_plural(value);
+}
+
+/// Selects the plural form of a number.
+///
+/// Exact matches like `=0` take precedence over the plural category, the `other` form is used if
+/// the language specific category is missing.
+///
+/// # Parameters
+///
+/// - `language`: The language of the plural forms.
+/// - `operands`: The operands of the number.
+/// - `forms`: The json object containing the plural forms.
+///
+/// # Returns
+///
+/// The selected plural form or `None` if neither the category nor `other` is present.
+pub(crate) fn select<'a>(
+    language: &str,
+    operands: &Operands,
+    forms: &'a serde_json::Value,
+) -> Option<&'a serde_json::Value> {
+    if let Some(value) = forms.get(format!("={}", operands).as_str()) {
+        return Some(value);
+    }
+
+    return forms
+        .get(cardinal(language, operands).name())
+        .or_else(|| forms.get(Category::Other.name()));
+}
@@ -0,0 +1,730 @@
+#![doc = include_str!("../.github/relative.md")]
+// localizer-rs relative
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use crate::arguments;
+use crate::numbers;
+use crate::plurals;
+
+
+//////////////
+// PATTERNS //
+//////////////
+
+/// Patterns of a relative time for the plural categories, `{0}` is replaced by the number.
+///
+/// Empty patterns fall back to the `other` pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Forms {
+    /// The pattern of the `one` category.
+    pub(crate) one: &'static str,
+    /// The pattern of the `few` category.
+    pub(crate) few: &'static str,
+    /// The pattern of the `many` category.
+    pub(crate) many: &'static str,
+    /// The pattern of all other categories.
+    pub(crate) other: &'static str,
+}
+
+impl Forms {
+    /// Returns the pattern of a plural category.
+    fn get(&self, category: plurals::Category) -> &'static str {
+        let pattern: &'static str = match category {
+            plurals::Category::One => self.one,
+            plurals::Category::Few => self.few,
+            plurals::Category::Many => self.many,
+            _ => self.other,
+        };
+
+        return match pattern.is_empty() {
+            true => self.other,
+            false => pattern,
+        };
+    }
+}
+
+/// Creates the patterns of a language distinguishing `one` and `other`.
+const fn forms(one: &'static str, other: &'static str) -> Forms {
+    return Forms {
+        one,
+        few: "",
+        many: "",
+        other,
+    };
+}
+
+/// Creates the patterns of a language distinguishing `one`, `few`, `many` and `other`.
+const fn slavic(
+    one: &'static str,
+    few: &'static str,
+    many: &'static str,
+    other: &'static str,
+) -> Forms {
+    return Forms {
+        one,
+        few,
+        many,
+        other,
+    };
+}
+
+/// Relative time patterns of a locale, ordered like the variants of [`TimeUnit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Patterns {
+    /// The language tag of the locale.
+    pub(crate) language: &'static str,
+    /// The patterns of times in the future.
+    pub(crate) future: [Forms; 7],
+    /// The patterns of times in the past.
+    pub(crate) past: [Forms; 7],
+}
+
+/// Relative time patterns of the bundled locales, taken from the CLDR.
+const PATTERNS: [Patterns; 11] = [
+    Patterns {
+        language: "en",
+        future: [
+            forms("in {0} second", "in {0} seconds"),
+            forms("in {0} minute", "in {0} minutes"),
+            forms("in {0} hour", "in {0} hours"),
+            forms("in {0} day", "in {0} days"),
+            forms("in {0} week", "in {0} weeks"),
+            forms("in {0} month", "in {0} months"),
+            forms("in {0} year", "in {0} years"),
+        ],
+        past: [
+            forms("{0} second ago", "{0} seconds ago"),
+            forms("{0} minute ago", "{0} minutes ago"),
+            forms("{0} hour ago", "{0} hours ago"),
+            forms("{0} day ago", "{0} days ago"),
+            forms("{0} week ago", "{0} weeks ago"),
+            forms("{0} month ago", "{0} months ago"),
+            forms("{0} year ago", "{0} years ago"),
+        ],
+    },
+    Patterns {
+        language: "de",
+        future: [
+            forms("in {0} Sekunde", "in {0} Sekunden"),
+            forms("in {0} Minute", "in {0} Minuten"),
+            forms("in {0} Stunde", "in {0} Stunden"),
+            forms("in {0} Tag", "in {0} Tagen"),
+            forms("in {0} Woche", "in {0} Wochen"),
+            forms("in {0} Monat", "in {0} Monaten"),
+            forms("in {0} Jahr", "in {0} Jahren"),
+        ],
+        past: [
+            forms("vor {0} Sekunde", "vor {0} Sekunden"),
+            forms("vor {0} Minute", "vor {0} Minuten"),
+            forms("vor {0} Stunde", "vor {0} Stunden"),
+            forms("vor {0} Tag", "vor {0} Tagen"),
+            forms("vor {0} Woche", "vor {0} Wochen"),
+            forms("vor {0} Monat", "vor {0} Monaten"),
+            forms("vor {0} Jahr", "vor {0} Jahren"),
+        ],
+    },
+    Patterns {
+        language: "fr",
+        future: [
+            forms("dans {0} seconde", "dans {0} secondes"),
+            forms("dans {0} minute", "dans {0} minutes"),
+            forms("dans {0} heure", "dans {0} heures"),
+            forms("dans {0} jour", "dans {0} jours"),
+            forms("dans {0} semaine", "dans {0} semaines"),
+            forms("dans {0} mois", "dans {0} mois"),
+            forms("dans {0} an", "dans {0} ans"),
+        ],
+        past: [
+            forms("il y a {0} seconde", "il y a {0} secondes"),
+            forms("il y a {0} minute", "il y a {0} minutes"),
+            forms("il y a {0} heure", "il y a {0} heures"),
+            forms("il y a {0} jour", "il y a {0} jours"),
+            forms("il y a {0} semaine", "il y a {0} semaines"),
+            forms("il y a {0} mois", "il y a {0} mois"),
+            forms("il y a {0} an", "il y a {0} ans"),
+        ],
+    },
+    Patterns {
+        language: "es",
+        future: [
+            forms("dentro de {0} segundo", "dentro de {0} segundos"),
+            forms("dentro de {0} minuto", "dentro de {0} minutos"),
+            forms("dentro de {0} hora", "dentro de {0} horas"),
+            forms("dentro de {0} día", "dentro de {0} días"),
+            forms("dentro de {0} semana", "dentro de {0} semanas"),
+            forms("dentro de {0} mes", "dentro de {0} meses"),
+            forms("dentro de {0} año", "dentro de {0} años"),
+        ],
+        past: [
+            forms("hace {0} segundo", "hace {0} segundos"),
+            forms("hace {0} minuto", "hace {0} minutos"),
+            forms("hace {0} hora", "hace {0} horas"),
+            forms("hace {0} día", "hace {0} días"),
+            forms("hace {0} semana", "hace {0} semanas"),
+            forms("hace {0} mes", "hace {0} meses"),
+            forms("hace {0} año", "hace {0} años"),
+        ],
+    },
+    Patterns {
+        language: "it",
+        future: [
+            forms("tra {0} secondo", "tra {0} secondi"),
+            forms("tra {0} minuto", "tra {0} minuti"),
+            forms("tra {0} ora", "tra {0} ore"),
+            forms("tra {0} giorno", "tra {0} giorni"),
+            forms("tra {0} settimana", "tra {0} settimane"),
+            forms("tra {0} mese", "tra {0} mesi"),
+            forms("tra {0} anno", "tra {0} anni"),
+        ],
+        past: [
+            forms("{0} secondo fa", "{0} secondi fa"),
+            forms("{0} minuto fa", "{0} minuti fa"),
+            forms("{0} ora fa", "{0} ore fa"),
+            forms("{0} giorno fa", "{0} giorni fa"),
+            forms("{0} settimana fa", "{0} settimane fa"),
+            forms("{0} mese fa", "{0} mesi fa"),
+            forms("{0} anno fa", "{0} anni fa"),
+        ],
+    },
+    Patterns {
+        language: "pt",
+        future: [
+            forms("em {0} segundo", "em {0} segundos"),
+            forms("em {0} minuto", "em {0} minutos"),
+            forms("em {0} hora", "em {0} horas"),
+            forms("em {0} dia", "em {0} dias"),
+            forms("em {0} semana", "em {0} semanas"),
+            forms("em {0} mês", "em {0} meses"),
+            forms("em {0} ano", "em {0} anos"),
+        ],
+        past: [
+            forms("há {0} segundo", "há {0} segundos"),
+            forms("há {0} minuto", "há {0} minutos"),
+            forms("há {0} hora", "há {0} horas"),
+            forms("há {0} dia", "há {0} dias"),
+            forms("há {0} semana", "há {0} semanas"),
+            forms("há {0} mês", "há {0} meses"),
+            forms("há {0} ano", "há {0} anos"),
+        ],
+    },
+    Patterns {
+        language: "nl",
+        future: [
+            forms("over {0} seconde", "over {0} seconden"),
+            forms("over {0} minuut", "over {0} minuten"),
+            forms("over {0} uur", "over {0} uur"),
+            forms("over {0} dag", "over {0} dagen"),
+            forms("over {0} week", "over {0} weken"),
+            forms("over {0} maand", "over {0} maanden"),
+            forms("over {0} jaar", "over {0} jaar"),
+        ],
+        past: [
+            forms("{0} seconde geleden", "{0} seconden geleden"),
+            forms("{0} minuut geleden", "{0} minuten geleden"),
+            forms("{0} uur geleden", "{0} uur geleden"),
+            forms("{0} dag geleden", "{0} dagen geleden"),
+            forms("{0} week geleden", "{0} weken geleden"),
+            forms("{0} maand geleden", "{0} maanden geleden"),
+            forms("{0} jaar geleden", "{0} jaar geleden"),
+        ],
+    },
+    Patterns {
+        language: "pl",
+        future: [
+            slavic(
+                "za {0} sekundę",
+                "za {0} sekundy",
+                "za {0} sekund",
+                "za {0} sekundy",
+            ),
+            slavic(
+                "za {0} minutę",
+                "za {0} minuty",
+                "za {0} minut",
+                "za {0} minuty",
+            ),
+            slavic(
+                "za {0} godzinę",
+                "za {0} godziny",
+                "za {0} godzin",
+                "za {0} godziny",
+            ),
+            slavic("za {0} dzień", "za {0} dni", "za {0} dni", "za {0} dnia"),
+            slavic(
+                "za {0} tydzień",
+                "za {0} tygodnie",
+                "za {0} tygodni",
+                "za {0} tygodnia",
+            ),
+            slavic(
+                "za {0} miesiąc",
+                "za {0} miesiące",
+                "za {0} miesięcy",
+                "za {0} miesiąca",
+            ),
+            slavic("za {0} rok", "za {0} lata", "za {0} lat", "za {0} roku"),
+        ],
+        past: [
+            slavic(
+                "{0} sekundę temu",
+                "{0} sekundy temu",
+                "{0} sekund temu",
+                "{0} sekundy temu",
+            ),
+            slavic(
+                "{0} minutę temu",
+                "{0} minuty temu",
+                "{0} minut temu",
+                "{0} minuty temu",
+            ),
+            slavic(
+                "{0} godzinę temu",
+                "{0} godziny temu",
+                "{0} godzin temu",
+                "{0} godziny temu",
+            ),
+            slavic(
+                "{0} dzień temu",
+                "{0} dni temu",
+                "{0} dni temu",
+                "{0} dnia temu",
+            ),
+            slavic(
+                "{0} tydzień temu",
+                "{0} tygodnie temu",
+                "{0} tygodni temu",
+                "{0} tygodnia temu",
+            ),
+            slavic(
+                "{0} miesiąc temu",
+                "{0} miesiące temu",
+                "{0} miesięcy temu",
+                "{0} miesiąca temu",
+            ),
+            slavic(
+                "{0} rok temu",
+                "{0} lata temu",
+                "{0} lat temu",
+                "{0} roku temu",
+            ),
+        ],
+    },
+    Patterns {
+        language: "ru",
+        future: [
+            slavic(
+                "через {0} секунду",
+                "через {0} секунды",
+                "через {0} секунд",
+                "через {0} секунды",
+            ),
+            slavic(
+                "через {0} минуту",
+                "через {0} минуты",
+                "через {0} минут",
+                "через {0} минуты",
+            ),
+            slavic(
+                "через {0} час",
+                "через {0} часа",
+                "через {0} часов",
+                "через {0} часа",
+            ),
+            slavic(
+                "через {0} день",
+                "через {0} дня",
+                "через {0} дней",
+                "через {0} дня",
+            ),
+            slavic(
+                "через {0} неделю",
+                "через {0} недели",
+                "через {0} недель",
+                "через {0} недели",
+            ),
+            slavic(
+                "через {0} месяц",
+                "через {0} месяца",
+                "через {0} месяцев",
+                "через {0} месяца",
+            ),
+            slavic(
+                "через {0} год",
+                "через {0} года",
+                "через {0} лет",
+                "через {0} года",
+            ),
+        ],
+        past: [
+            slavic(
+                "{0} секунду назад",
+                "{0} секунды назад",
+                "{0} секунд назад",
+                "{0} секунды назад",
+            ),
+            slavic(
+                "{0} минуту назад",
+                "{0} минуты назад",
+                "{0} минут назад",
+                "{0} минуты назад",
+            ),
+            slavic(
+                "{0} час назад",
+                "{0} часа назад",
+                "{0} часов назад",
+                "{0} часа назад",
+            ),
+            slavic(
+                "{0} день назад",
+                "{0} дня назад",
+                "{0} дней назад",
+                "{0} дня назад",
+            ),
+            slavic(
+                "{0} неделю назад",
+                "{0} недели назад",
+                "{0} недель назад",
+                "{0} недели назад",
+            ),
+            slavic(
+                "{0} месяц назад",
+                "{0} месяца назад",
+                "{0} месяцев назад",
+                "{0} месяца назад",
+            ),
+            slavic(
+                "{0} год назад",
+                "{0} года назад",
+                "{0} лет назад",
+                "{0} года назад",
+            ),
+        ],
+    },
+    Patterns {
+        language: "ja",
+        future: [
+            forms("", "{0} 秒後"),
+            forms("", "{0} 分後"),
+            forms("", "{0} 時間後"),
+            forms("", "{0} 日後"),
+            forms("", "{0} 週間後"),
+            forms("", "{0} か月後"),
+            forms("", "{0} 年後"),
+        ],
+        past: [
+            forms("", "{0} 秒前"),
+            forms("", "{0} 分前"),
+            forms("", "{0} 時間前"),
+            forms("", "{0} 日前"),
+            forms("", "{0} 週間前"),
+            forms("", "{0} か月前"),
+            forms("", "{0} 年前"),
+        ],
+    },
+    Patterns {
+        language: "zh",
+        future: [
+            forms("", "{0}秒钟后"),
+            forms("", "{0}分钟后"),
+            forms("", "{0}小时后"),
+            forms("", "{0}天后"),
+            forms("", "{0}周后"),
+            forms("", "{0}个月后"),
+            forms("", "{0}年后"),
+        ],
+        past: [
+            forms("", "{0}秒钟前"),
+            forms("", "{0}分钟前"),
+            forms("", "{0}小时前"),
+            forms("", "{0}天前"),
+            forms("", "{0}周前"),
+            forms("", "{0}个月前"),
+            forms("", "{0}年前"),
+        ],
+    },
+];
+
+
+/////////////////////
+// RELATIVE FORMAT //
+/////////////////////
+
+/// Unit of a relative time.
+///
+/// # Variants
+///
+/// - `Second`: Seconds.
+/// - `Minute`: Minutes.
+/// - `Hour`: Hours.
+/// - `Day`: Days.
+/// - `Week`: Weeks.
+/// - `Month`: Months.
+/// - `Year`: Years.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum TimeUnit {
+    /// Seconds.
+    Second,
+    /// Minutes.
+    Minute,
+    /// Hours.
+    Hour,
+    /// Days.
+    Day,
+    /// Weeks.
+    Week,
+    /// Months.
+    Month,
+    /// Years.
+    Year,
+}
+
+impl TimeUnit {
+    /// Parses the option of a relative time placeholder.
+    ///
+    /// # Parameters
+    ///
+    /// - `option`: The name of the unit, eg. `day`.
+    ///
+    /// # Returns
+    ///
+    /// The unit or `None` if the name is not valid.
+    pub(crate) fn parse(option: &str) -> Option<TimeUnit> {
+        return match option {
+            "second" => Some(TimeUnit::Second),
+            "minute" => Some(TimeUnit::Minute),
+            "hour" => Some(TimeUnit::Hour),
+            "day" => Some(TimeUnit::Day),
+            "week" => Some(TimeUnit::Week),
+            "month" => Some(TimeUnit::Month),
+            "year" => Some(TimeUnit::Year),
+            _ => None,
+        };
+    }
+
+    /// Returns the number of seconds of the unit. Months have 30 and years 365 days.
+    fn seconds(self) -> f64 {
+        return match self {
+            TimeUnit::Second => 1.0,
+            TimeUnit::Minute => 60.0,
+            TimeUnit::Hour => 3600.0,
+            TimeUnit::Day => 86400.0,
+            TimeUnit::Week => 604800.0,
+            TimeUnit::Month => 2592000.0,
+            TimeUnit::Year => 31536000.0,
+        };
+    }
+
+    /// Selects the largest unit fitting into a number of seconds.
+    ///
+    /// Weeks are only used for less than a month.
+    ///
+    /// # Parameters
+    ///
+    /// - `seconds`: The number of seconds, regardless of the sign.
+    ///
+    /// # Returns
+    ///
+    /// The unit to show the seconds in.
+    fn select(seconds: f64) -> TimeUnit {
+        return [
+            TimeUnit::Year,
+            TimeUnit::Month,
+            TimeUnit::Week,
+            TimeUnit::Day,
+            TimeUnit::Hour,
+            TimeUnit::Minute,
+        ]
+        .into_iter()
+        .find(|unit| seconds.abs() >= unit.seconds())
+        .unwrap_or(TimeUnit::Second);
+    }
+}
+
+/// Format of a relative time.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// use localizer_rs::relative::{RelativeFormat, TimeUnit};
+///
+/// let days: RelativeFormat = RelativeFormat::unit(TimeUnit::Day);
+///
+/// assert_eq!(localizer_rs::relative::format("en", -3, &days), "3 days ago");
+/// assert_eq!(localizer_rs::relative::format("en", 7200, &RelativeFormat::auto()), "in 2 hours");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelativeFormat {
+    /// The unit of the value. Values without a unit are seconds shown in the largest fitting unit.
+    pub unit: Option<TimeUnit>,
+}
+
+impl RelativeFormat {
+    /// Creates the format of relative times in seconds, selecting the largest fitting unit.
+    ///
+    /// # Returns
+    ///
+    /// A `RelativeFormat` without a unit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// let format = localizer_rs::relative::RelativeFormat::auto();
+    ///
+    /// assert_eq!(localizer_rs::relative::format("de", -180, &format), "vor 3 Minuten");
+    /// ```
+    pub fn auto() -> RelativeFormat {
+        return RelativeFormat { unit: None };
+    }
+
+    /// Creates the format of relative times in a unit.
+    ///
+    /// # Parameters
+    ///
+    /// - `unit`: The unit of the values.
+    ///
+    /// # Returns
+    ///
+    /// A `RelativeFormat` using the unit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// use localizer_rs::relative::{RelativeFormat, TimeUnit};
+    ///
+    /// let format = RelativeFormat::unit(TimeUnit::Day);
+    ///
+    /// assert_eq!(localizer_rs::relative::format("de", -2, &format), "vor 2 Tagen");
+    /// ```
+    pub fn unit(unit: TimeUnit) -> RelativeFormat {
+        return RelativeFormat { unit: Some(unit) };
+    }
+
+    /// Parses the options of a relative time placeholder.
+    ///
+    /// # Parameters
+    ///
+    /// - `style`: The style of the placeholder, `relative`.
+    /// - `option`: The unit of the value, eg. `day`. Values without a unit are seconds.
+    ///
+    /// # Returns
+    ///
+    /// The relative format or `None` if the style or the option is not valid.
+    pub(crate) fn parse(style: &str, option: Option<&str>) -> Option<RelativeFormat> {
+        if style != "relative" {
+            return None;
+        }
+
+        return match option {
+            Some(option) => Some(RelativeFormat::unit(TimeUnit::parse(option)?)),
+            None => Some(RelativeFormat::auto()),
+        };
+    }
+}
+
+impl Default for RelativeFormat {
+    fn default() -> RelativeFormat {
+        return RelativeFormat::auto();
+    }
+}
+
+
+////////////////
+// FORMATTING //
+////////////////
+
+/// Formats a relative time for a language.
+///
+/// Negative values are in the past and all other values in the future. The plural form of the
+/// unit is selected using the plural rules of the language. Values that are not numeric are
+/// returned unchanged.
+///
+/// # Parameters
+///
+/// - `language`: The language to format the value for, eg. `de`.
+/// - `value`: The relative time in the unit of the format.
+/// - `format`: The relative format to use.
+///
+/// # Returns
+///
+/// A `String` containing the formatted relative time.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// use localizer_rs::relative::{RelativeFormat, TimeUnit};
+///
+/// let hours: RelativeFormat = RelativeFormat::unit(TimeUnit::Hour);
+///
+/// assert_eq!(localizer_rs::relative::format("en", -1, &hours), "1 hour ago");
+/// assert_eq!(localizer_rs::relative::format("fr", 2, &hours), "dans 2 heures");
+/// assert_eq!(localizer_rs::relative::format("ru", -5, &hours), "5 часов назад");
+/// ```
+///
+/// # See also
+///
+/// - [`RelativeFormat`]
+/// - [`crate::Config::format_relative()`]
+pub fn format<V: Into<arguments::Value>>(
+    language: &str,
+    value: V,
+    format: &RelativeFormat,
+) -> String {
+    let value: arguments::Value = value.into();
+
+    let operands: plurals::Operands = match value.operands() {
+        Some(operands) if operands.n.is_finite() => operands,
+        _ => return value.to_string(),
+    };
+
+    let (unit, amount) = match format.unit {
+        Some(unit) => (unit, operands.n),
+        None => {
+            let unit: TimeUnit = TimeUnit::select(operands.n);
+            // Rounding can reach the next unit, eg. 59.6 seconds are shown as a minute
+            let rounded: f64 = (operands.n / unit.seconds()).round() * unit.seconds();
+            let unit: TimeUnit = TimeUnit::select(rounded);
+            (unit, (operands.n / unit.seconds()).round())
+        }
+    };
+    // Rounded like the formatted number, so the plural form matches the digits
+    let amount: f64 = (amount * 1000.0).round() / 1000.0;
+
+    let patterns: &Patterns =
+        plurals::locale_entry(&PATTERNS, language, |patterns: &Patterns| patterns.language);
+    let forms: &Forms = match operands.negative {
+        true => &patterns.past[unit as usize],
+        false => &patterns.future[unit as usize],
+    };
+    let category: plurals::Category = plurals::cardinal(language, &plurals::Operands::from(amount));
+    let number: String = numbers::format(language, amount, &numbers::NumberFormat::decimal());
+
+    return forms.get(category).replace("{0}", &number);
+}
@@ -0,0 +1,1029 @@
+#![doc = include_str!("../.github/sources.md")]
+// localizer-rs sources
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::errors;
+use crate::fluent;
+use crate::gettext;
+use crate::styles;
+use crate::Syntax;
+
+
+/////////////
+// CATALOG //
+/////////////
+
+/// Translations of a single language.
+///
+/// Catalogs map keys to json values. Values can be strings, nested objects containing further
+/// keys or plural forms as described in the [`crate::plurals`] module.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// # use std::collections::HashMap;
+/// let mut messages: HashMap<String, serde_json::Value> = HashMap::new();
+/// messages.insert("test".to_owned(), serde_json::json!("Something that can be translated."));
+///
+/// let catalog: localizer_rs::sources::Catalog = localizer_rs::sources::Catalog::new(messages);
+///
+/// assert_eq!(catalog.messages().len(), 1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Catalog {
+    /// The top level keys mapped to their json values.
+    pub(crate) messages: HashMap<String, serde_json::Value>,
+    /// The syntax of the messages, overriding the syntax of the config if set.
+    pub(crate) syntax: Option<Syntax>,
+    /// The plural forms of gettext catalogs, selecting the translation of plural messages stored
+    /// as json arrays.
+    pub(crate) plural_forms: Option<gettext::PluralForms>,
+}
+
+impl Catalog {
+    /// Creates a new catalog containing the specified messages.
+    ///
+    /// # Parameters
+    ///
+    /// - `messages`: The top level keys mapped to their json values.
+    ///
+    /// # Returns
+    ///
+    /// A new `Catalog` object using the syntax of the config.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// # use std::collections::HashMap;
+    /// let catalog: localizer_rs::sources::Catalog = localizer_rs::sources::Catalog::new(HashMap::new());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Catalog`]
+    pub fn new(messages: HashMap<String, serde_json::Value>) -> Catalog {
+        return Catalog {
+            messages,
+            syntax: None,
+            plural_forms: None,
+        };
+    }
+
+    /// Returns the messages of the catalog.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The catalog object.
+    ///
+    /// # Returns
+    ///
+    /// The top level keys mapped to their json values.
+    ///
+    /// # See also
+    ///
+    /// - [`Catalog`]
+    pub fn messages(&self) -> &HashMap<String, serde_json::Value> {
+        return &self.messages;
+    }
+
+    /// Returns the syntax of the messages.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The catalog object.
+    ///
+    /// # Returns
+    ///
+    /// The syntax of the messages or `None` if the syntax of the config is used.
+    ///
+    /// # See also
+    ///
+    /// - [`Catalog`]
+    /// - [`Catalog::set_syntax()`]
+    pub fn syntax(&self) -> Option<Syntax> {
+        return self.syntax;
+    }
+
+    /// Sets the syntax of the messages, overriding the syntax of the config.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The catalog object. This must be mutable.
+    /// - `syntax`: The syntax of the messages.
+    ///
+    /// # Returns
+    ///
+    /// The modified `Catalog` object with the specified syntax.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// # use std::collections::HashMap;
+    /// let mut catalog: localizer_rs::sources::Catalog =
+    ///     localizer_rs::sources::Catalog::new(HashMap::new());
+    /// catalog.set_syntax(localizer_rs::Syntax::MessageFormat);
+    ///
+    /// assert_eq!(catalog.syntax(), Some(localizer_rs::Syntax::MessageFormat));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Catalog`]
+    /// - [`Syntax`]
+    pub fn set_syntax(&mut self, syntax: Syntax) -> &Catalog {
+        self.syntax = Some(syntax);
+        return self;
+    }
+}
+
+/// Creates a catalog from string messages.
+impl From<HashMap<String, String>> for Catalog {
+    fn from(messages: HashMap<String, String>) -> Catalog {
+        return Catalog::new(
+            messages
+                .into_iter()
+                .map(|(key, value)| (key, serde_json::Value::String(value)))
+                .collect(),
+        );
+    }
+}
+
+/// Creates a catalog from an array of keys and string messages.
+impl<const N: usize> From<[(&str, &str); N]> for Catalog {
+    fn from(messages: [(&str, &str); N]) -> Catalog {
+        return Catalog::new(
+            messages
+                .into_iter()
+                .map(|(key, value)| (key.to_owned(), serde_json::Value::String(value.to_owned())))
+                .collect(),
+        );
+    }
+}
+
+
+////////////////////
+// CATALOG SOURCE //
+////////////////////
+
+/// Source providing the catalogs of the available languages.
+///
+/// [`Files`] is the default source, reading the translation files of a directory. [`Embedded`]
+/// reads files embedded into the binary and [`Memory`] keeps catalogs created at runtime.
+/// Implement this trait to load translations from databases or custom formats and use it with
+/// [`crate::Config::with_source()`].
+///
+/// Catalogs are cached by the config object, so [`CatalogSource::load()`] is only called once
+/// per language until the cache is cleared.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// # use std::collections::HashMap;
+/// #[derive(Debug)]
+/// struct Greetings;
+///
+/// impl localizer_rs::sources::CatalogSource for Greetings {
+///     fn languages(&self) -> Result<Vec<String>, localizer_rs::errors::Error> {
+///         return Ok(vec!["en".to_owned()]);
+///     }
+///
+///     fn load(
+///         &self,
+///         language: &str,
+///     ) -> Result<localizer_rs::sources::Catalog, localizer_rs::errors::Error> {
+///         if language != "en" {
+///             return Err(localizer_rs::errors::Error::LanguageNotFound {
+///                 language: language.to_owned(),
+///             });
+///         }
+///
+///         let mut messages: HashMap<String, String> = HashMap::new();
+///         messages.insert("hello".to_owned(), "Hello {{name}}!".to_owned());
+///         return Ok(messages.into());
+///     }
+/// }
+///
+/// let config: localizer_rs::Config = localizer_rs::Config::with_source(Greetings, "en");
+///
+/// assert_eq!(config.t("hello", vec![("name", "World")]), "Hello World!");
+/// ```
+pub trait CatalogSource: fmt::Debug + Send + Sync {
+    /// Returns the languages provided by the source.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The source object.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the available languages or the error that occurred.
+    fn languages(&self) -> Result<Vec<String>, errors::Error>;
+
+    /// Loads the catalog of the specified language.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The source object.
+    /// - `language`: The language of the catalog.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the catalog or the error that occurred.
+    ///
+    /// # Errors
+    ///
+    /// Sources must return [`errors::Error::LanguageNotFound`] or
+    /// [`errors::Error::FileNotFound`] if they do not provide the language, so the config object
+    /// continues with the next language of the fallback chain. All other errors are returned to
+    /// the caller.
+    fn load(&self, language: &str) -> Result<Catalog, errors::Error>;
+
+    /// Returns the theme provided by the source.
+    ///
+    /// The theme replaces the theme of the config object when the source is set. Sources don't
+    /// provide a theme by default.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The source object.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the theme, `None` if the source does not provide one, or the error
+    /// that occurred while loading it.
+    fn theme(&self) -> Result<Option<styles::Theme>, errors::Error> {
+        return Ok(None);
+    }
+}
+
+/// Shared catalog source of a config object.
+///
+/// Like the cache, the source is an implementation detail of [`crate::Config`]. Two shared
+/// sources are equal if they are both unset or point to the same source object.
+#[derive(Clone, Default)]
+pub(crate) struct SharedSource {
+    /// The source, or `None` if the translation files of the config path are used.
+    source: Option<Arc<dyn CatalogSource>>,
+}
+
+impl SharedSource {
+    /// Creates a new shared source.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: The source to share.
+    ///
+    /// # Returns
+    ///
+    /// A new `SharedSource` object containing the source.
+    pub(crate) fn new(source: Arc<dyn CatalogSource>) -> SharedSource {
+        return SharedSource {
+            source: Some(source),
+        };
+    }
+
+    /// Returns the shared source.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The shared source object.
+    ///
+    /// # Returns
+    ///
+    /// The source or `None` if the translation files of the config path are used.
+    pub(crate) fn get(&self) -> Option<Arc<dyn CatalogSource>> {
+        return self.source.clone();
+    }
+
+    /// Returns the address of the source, used to check whether two configs share a source.
+    fn address(&self) -> usize {
+        return match &self.source {
+            Some(source) => Arc::as_ptr(source) as *const () as usize,
+            None => 0,
+        };
+    }
+}
+
+impl fmt::Debug for SharedSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match &self.source {
+            Some(source) => source.fmt(f),
+            None => f.write_str("Files"),
+        };
+    }
+}
+
+impl PartialEq for SharedSource {
+    fn eq(&self, other: &SharedSource) -> bool {
+        return self.address() == other.address();
+    }
+}
+
+impl Eq for SharedSource {}
+
+
+///////////
+// FILES //
+///////////
+
+/// Separator between the `msgctxt` and the `msgid` of gettext messages with a context.
+///
+/// Like in gettext, the end of transmission character is used, as it cannot be part of a message
+/// id. Messages with a context are looked up by joining both using the separator:
+///
+/// ```rust
+/// # use localizer_rs;
+/// let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "es");
+/// let key: String = format!("menu{}Open", localizer_rs::sources::CONTEXT_SEPARATOR);
+///
+/// assert_eq!(config.t(&key, vec![]), "Abrir");
+/// assert_eq!(config.t("menu\u{4}Open", vec![]), "Abrir");
+/// ```
+pub const CONTEXT_SEPARATOR: char = '\u{4}';
+
+/// Catalog source reading the translation files of a directory.
+///
+/// The catalog of a language is loaded from the first existing file of `<language>.json`, the
+/// Fluent resource `<language>.ftl` and the gettext catalogs `<language>.po` and
+/// `<language>.mo`.
+///
+/// This is the source used by [`crate::Config::new()`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// use localizer_rs::sources::CatalogSource;
+///
+/// let files: localizer_rs::sources::Files = localizer_rs::sources::Files::new("examples/translations");
+///
+/// assert!(files.languages().unwrap().contains(&"en".to_owned()));
+/// assert!(files.load("en").is_ok());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Files {
+    /// The directory containing the translation files. The directory is relative to the path the
+    /// executable was executed from.
+    pub path: String,
+}
+
+impl Files {
+    /// Creates a new file source.
+    ///
+    /// # Parameters
+    ///
+    /// - `path`: The directory containing the translation files.
+    ///
+    /// # Returns
+    ///
+    /// A new `Files` object reading the specified directory.
+    ///
+    /// # See also
+    ///
+    /// - [`Files`]
+    pub fn new(path: &str) -> Files {
+        return Files {
+            path: path.to_owned(),
+        };
+    }
+}
+
+impl CatalogSource for Files {
+    /// Returns the languages of the translation files in the directory.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The source object.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the sorted languages or the error that occurred while reading the
+    /// directory.
+    fn languages(&self) -> Result<Vec<String>, errors::Error> {
+        let entries: fs::ReadDir = match fs::read_dir(Path::new(self.path.as_str())) {
+            Ok(value) => value,
+            Err(_error) => {
+                if _error.kind() == io::ErrorKind::NotFound {
+                    return Err(errors::Error::PathNotFound {
+                        path: self.path.to_owned(),
+                    });
+                }
+                return Err(errors::Error::PathUnreadable {
+                    path: self.path.to_owned(),
+                    source: _error,
+                });
+            }
+        };
+        let mut languages: Vec<String> = vec![];
+
+        for entry in entries {
+            let path: PathBuf = match entry {
+                Ok(value) => value.path(),
+                Err(_error) => {
+                    return Err(errors::Error::PathUnreadable {
+                        path: self.path.to_owned(),
+                        source: _error,
+                    });
+                }
+            };
+            let supported: bool = path.is_file()
+                && path.extension().is_some_and(|extension: &OsStr| {
+                    PARSERS
+                        .iter()
+                        .any(|(supported, _parser)| extension == *supported)
+                });
+
+            let language: Option<&str> = path
+                .file_stem()
+                .and_then(OsStr::to_str)
+                // The theme file is stored next to the translation files
+                .filter(|language: &&str| *language != "theme");
+
+            if let (true, Some(language)) = (supported, language) {
+                languages.push(language.to_owned());
+            }
+        }
+        languages.sort();
+        languages.dedup();
+
+        return Ok(languages);
+    }
+
+    /// Loads the catalog of the specified language from its translation file.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The source object.
+    /// - `language`: The language of the catalog.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the catalog or the error that occurred while loading it. If none of
+    /// the files exist, the error of the json file is returned.
+    fn load(&self, language: &str) -> Result<Catalog, errors::Error> {
+        let mut not_found: Option<errors::Error> = None;
+
+        for (extension, parser) in PARSERS {
+            let file_path: String = format!("./{}/{}.{}", &self.path, language, extension);
+
+            match read_file(&file_path) {
+                Ok(content) => return parser(file_path, &content),
+                Err(error @ errors::Error::FileNotFound { .. }) => {
+                    not_found.get_or_insert(error);
+                }
+                Err(error) => return Err(error),
+            }
+        }
+
+        return Err(not_found.expect("at least one loader was tried"));
+    }
+}
+
+
+//////////////
+// EMBEDDED //
+//////////////
+
+/// Translation file embedded into the binary.
+///
+/// Embedded files are created by the [`crate::embed!()`] macro.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EmbeddedFile {
+    /// The language of the translation file.
+    pub language: &'static str,
+    /// The extension of the translation file, eg. `json`.
+    pub extension: &'static str,
+    /// The content of the translation file.
+    pub content: &'static [u8],
+}
+
+/// Catalog source reading translation files embedded into the binary.
+///
+/// Use the [`crate::embed!()`] macro to embed the translation files of a directory at compile
+/// time. The files are parsed like the files of a [`Files`] source, without any file system
+/// access at runtime.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// let config: localizer_rs::Config = localizer_rs::Config::with_source(
+///     localizer_rs::sources::Embedded::new(&[localizer_rs::sources::EmbeddedFile {
+///         language: "en",
+///         extension: "json",
+///         content: br#"{"hello": "Hello!"}"#,
+///     }]),
+///     "en",
+/// );
+///
+/// assert_eq!(config.t("hello", vec![]), "Hello!");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Embedded {
+    /// The embedded translation files.
+    files: &'static [EmbeddedFile],
+    /// The content of the embedded `theme.json` file.
+    theme: Option<&'static [u8]>,
+}
+
+impl Embedded {
+    /// Creates a new embedded source.
+    ///
+    /// # Parameters
+    ///
+    /// - `files`: The embedded translation files.
+    ///
+    /// # Returns
+    ///
+    /// A new `Embedded` object containing the specified files.
+    ///
+    /// # See also
+    ///
+    /// - [`Embedded`]
+    /// - [`crate::embed!()`]
+    pub const fn new(files: &'static [EmbeddedFile]) -> Embedded {
+        return Embedded { files, theme: None };
+    }
+
+    /// Sets the embedded theme file of the source.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The source object.
+    /// - `content`: The content of the `theme.json` file.
+    ///
+    /// # Returns
+    ///
+    /// The modified `Embedded` object with the specified theme.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// let config: localizer_rs::Config = localizer_rs::Config::with_source(
+    ///     localizer_rs::sources::Embedded::new(&[]).with_theme(br#"{"brand": "bold"}"#),
+    ///     "en",
+    /// );
+    ///
+    /// assert!(config.theme.get("brand").is_some());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Embedded`]
+    /// - [`crate::embed!()`]
+    pub const fn with_theme(mut self, content: &'static [u8]) -> Embedded {
+        self.theme = Some(content);
+        return self;
+    }
+
+    /// Returns the embedded translation files.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The source object.
+    ///
+    /// # Returns
+    ///
+    /// The embedded translation files.
+    ///
+    /// # See also
+    ///
+    /// - [`Embedded`]
+    pub fn files(&self) -> &'static [EmbeddedFile] {
+        return self.files;
+    }
+}
+
+impl CatalogSource for Embedded {
+    /// Returns the languages of the embedded translation files.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The source object.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the sorted languages. This never fails.
+    fn languages(&self) -> Result<Vec<String>, errors::Error> {
+        let mut languages: Vec<String> = self
+            .files
+            .iter()
+            .map(|file: &EmbeddedFile| file.language.to_owned())
+            .collect();
+        languages.sort();
+        languages.dedup();
+
+        return Ok(languages);
+    }
+
+    /// Loads the catalog of the specified language from its embedded translation file.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The source object.
+    /// - `language`: The language of the catalog.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the catalog or the error that occurred while parsing it.
+    fn load(&self, language: &str) -> Result<Catalog, errors::Error> {
+        for (extension, parser) in PARSERS {
+            let embedded: Option<&EmbeddedFile> = self.files.iter().find(|file: &&EmbeddedFile| {
+                file.language == language && file.extension == extension
+            });
+
+            if let Some(file) = embedded {
+                return parser(format!("{}.{}", language, extension), file.content);
+            }
+        }
+
+        return Err(errors::Error::LanguageNotFound {
+            language: language.to_owned(),
+        });
+    }
+
+    /// Parses the embedded `theme.json` file.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The source object.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the theme, `None` if no theme file is embedded, or the error that
+    /// occurred while parsing it.
+    fn theme(&self) -> Result<Option<styles::Theme>, errors::Error> {
+        return match self.theme {
+            Some(content) => styles::Theme::parse("theme.json", content).map(Some),
+            None => Ok(None),
+        };
+    }
+}
+
+
+////////////
+// MEMORY //
+////////////
+
+/// Catalog source keeping the catalogs of all languages in memory.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// let memory: localizer_rs::sources::Memory = localizer_rs::sources::Memory::new()
+///     .with("en", [("hello", "Hello {{name}}!")])
+///     .with("de", [("hello", "Hallo {{name}}!")]);
+///
+/// let config: localizer_rs::Config = localizer_rs::Config::with_source(memory, "de");
+///
+/// assert_eq!(config.t("hello", vec![("name", "Welt")]), "Hallo Welt!");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Memory {
+    /// The catalogs, indexed by language.
+    catalogs: BTreeMap<String, Catalog>,
+}
+
+impl Memory {
+    /// Creates a new memory source without catalogs.
+    ///
+    /// # Returns
+    ///
+    /// A new `Memory` object without languages.
+    ///
+    /// # See also
+    ///
+    /// - [`Memory`]
+    /// - [`Memory::with()`]
+    pub fn new() -> Memory {
+        return Memory::default();
+    }
+
+    /// Adds the catalog of a language, replacing its previous catalog.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The source object.
+    /// - `language`: The language of the catalog.
+    /// - `catalog`: The catalog, eg. an array of keys and messages.
+    ///
+    /// # Returns
+    ///
+    /// The `Memory` object containing the catalog.
+    ///
+    /// # See also
+    ///
+    /// - [`Memory::set()`]
+    pub fn with<C: Into<Catalog>>(mut self, language: &str, catalog: C) -> Memory {
+        self.set(language, catalog);
+        return self;
+    }
+
+    /// Sets the catalog of a language, replacing its previous catalog.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The source object. This must be mutable.
+    /// - `language`: The language of the catalog.
+    /// - `catalog`: The catalog, eg. an array of keys and messages.
+    ///
+    /// # Returns
+    ///
+    /// The modified `Memory` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// use localizer_rs::sources::CatalogSource;
+    ///
+    /// let mut memory: localizer_rs::sources::Memory = localizer_rs::sources::Memory::new();
+    /// memory.set("en", [("hello", "Hello!")]);
+    ///
+    /// assert_eq!(memory.languages().unwrap(), vec!["en"]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Memory::with()`]
+    pub fn set<C: Into<Catalog>>(&mut self, language: &str, catalog: C) -> &Memory {
+        self.catalogs.insert(language.to_owned(), catalog.into());
+        return self;
+    }
+}
+
+impl CatalogSource for Memory {
+    /// Returns the languages of the catalogs.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The source object.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the sorted languages. This never fails.
+    fn languages(&self) -> Result<Vec<String>, errors::Error> {
+        return Ok(self.catalogs.keys().cloned().collect());
+    }
+
+    /// Returns a copy of the catalog of the specified language.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The source object.
+    /// - `language`: The language of the catalog.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the catalog or [`errors::Error::LanguageNotFound`].
+    fn load(&self, language: &str) -> Result<Catalog, errors::Error> {
+        return match self.catalogs.get(language) {
+            Some(catalog) => Ok(catalog.clone()),
+            None => Err(errors::Error::LanguageNotFound {
+                language: language.to_owned(),
+            }),
+        };
+    }
+}
+
+
+/////////////
+// PARSERS //
+/////////////
+
+/// Function parsing the content of the translation file at the specified path into a catalog.
+type Parser = fn(String, &[u8]) -> Result<Catalog, errors::Error>;
+
+/// Supported file extensions and their parsers, in the order they are tried.
+const PARSERS: [(&str, Parser); 4] = [
+    ("json", json_catalog),
+    ("ftl", fluent_catalog),
+    ("po", po_catalog),
+    ("mo", mo_catalog),
+];
+
+/// Reads a translation file.
+///
+/// # Parameters
+///
+/// - `file_path`: The path of the translation file.
+///
+/// # Returns
+///
+/// A `Result` containing the content of the file or the error that occurred while reading it.
+pub(crate) fn read_file(file_path: &str) -> Result<Vec<u8>, errors::Error> {
+    return match fs::read(Path::new(file_path)) {
+        Ok(value) => Ok(value),
+        Err(_error) => {
+            if _error.kind() == io::ErrorKind::NotFound {
+                return Err(errors::Error::FileNotFound {
+                    path: file_path.to_owned(),
+                    source: _error,
+                });
+            }
+            Err(errors::Error::FileUnreadable {
+                path: file_path.to_owned(),
+                source: _error,
+            })
+        }
+    };
+}
+
+/// Decodes the content of a translation file containing UTF-8 text.
+///
+/// # Parameters
+///
+/// - `file_path`: The path of the translation file.
+/// - `content`: The content of the translation file.
+///
+/// # Returns
+///
+/// A `Result` containing the text or the error that occurred while decoding it.
+fn text<'a>(file_path: &str, content: &'a [u8]) -> Result<&'a str, errors::Error> {
+    return match std::str::from_utf8(content) {
+        Ok(value) => Ok(value),
+        Err(_error) => Err(errors::Error::FileUnreadable {
+            path: file_path.to_owned(),
+            source: io::Error::new(io::ErrorKind::InvalidData, _error),
+        }),
+    };
+}
+
+/// Converts a character position into a line and column, both starting at 1.
+///
+/// # Parameters
+///
+/// - `source`: The content of the file.
+/// - `position`: The character position.
+///
+/// # Returns
+///
+/// A tuple containing the line and column of the position.
+fn line_and_column(source: &str, position: usize) -> (usize, usize) {
+    let before: Vec<char> = source.chars().take(position).collect();
+    let line: usize = before.iter().filter(|c: &&char| **c == '\n').count() + 1;
+    let column: usize = before
+        .iter()
+        .rev()
+        .take_while(|c: &&char| **c != '\n')
+        .count()
+        + 1;
+
+    return (line, column);
+}
+
+/// Parses a catalog from a json file.
+///
+/// # Parameters
+///
+/// - `file_path`: The path of the json file.
+/// - `content`: The content of the json file.
+///
+/// # Returns
+///
+/// A `Result` containing the catalog or the error that occurred while parsing it.
+fn json_catalog(file_path: String, content: &[u8]) -> Result<Catalog, errors::Error> {
+    let messages: HashMap<String, serde_json::Value> =
+        match serde_json::from_slice::<HashMap<String, serde_json::Value>>(content) {
+            Ok(value) => value,
+            Err(_error) => {
+                return Err(errors::Error::Parse {
+                    path: file_path,
+                    line: _error.line(),
+                    column: _error.column(),
+                    source: _error,
+                });
+            }
+        };
+
+    return Ok(Catalog {
+        messages,
+        syntax: None,
+        plural_forms: None,
+    });
+}
+
+/// Parses a catalog from a Fluent resource.
+///
+/// The messages are compiled to the ICU MessageFormat syntax, which is used for the catalog
+/// regardless of the syntax of the config.
+///
+/// # Parameters
+///
+/// - `file_path`: The path of the Fluent resource.
+/// - `content`: The content of the Fluent resource.
+///
+/// # Returns
+///
+/// A `Result` containing the catalog or the error that occurred while parsing it.
+fn fluent_catalog(file_path: String, content: &[u8]) -> Result<Catalog, errors::Error> {
+    let source: &str = text(&file_path, content)?;
+
+    let messages: Vec<(String, String)> = match fluent::parse(source) {
+        Ok(value) => value,
+        Err((position, reason)) => {
+            let (line, column) = line_and_column(source, position);
+
+            return Err(errors::Error::InvalidFile {
+                path: file_path,
+                line,
+                column,
+                reason,
+            });
+        }
+    };
+
+    return Ok(Catalog {
+        messages: messages
+            .into_iter()
+            .map(|(id, message)| (id, serde_json::Value::String(message)))
+            .collect(),
+        syntax: Some(Syntax::MessageFormat),
+        plural_forms: None,
+    });
+}
+
+/// Parses a catalog from a gettext `.po` file.
+///
+/// # Parameters
+///
+/// - `file_path`: The path of the `.po` file.
+/// - `content`: The content of the `.po` file.
+///
+/// # Returns
+///
+/// A `Result` containing the catalog or the error that occurred while parsing it.
+fn po_catalog(file_path: String, content: &[u8]) -> Result<Catalog, errors::Error> {
+    return match gettext::parse_po(text(&file_path, content)?) {
+        Ok(value) => Ok(value),
+        Err((line, column, reason)) => Err(errors::Error::InvalidFile {
+            path: file_path,
+            line,
+            column,
+            reason,
+        }),
+    };
+}
+
+/// Parses a catalog from a gettext `.mo` file.
+///
+/// # Parameters
+///
+/// - `file_path`: The path of the `.mo` file.
+/// - `content`: The content of the `.mo` file.
+///
+/// # Returns
+///
+/// A `Result` containing the catalog or the error that occurred while parsing it. The
+/// position of errors is reported as line 0 and the byte offset as the column.
+fn mo_catalog(file_path: String, content: &[u8]) -> Result<Catalog, errors::Error> {
+    return match gettext::parse_mo(content) {
+        Ok(value) => Ok(value),
+        Err((offset, reason)) => Err(errors::Error::InvalidFile {
+            path: file_path,
+            line: 0,
+            column: offset,
+            reason,
+        }),
+    };
+}
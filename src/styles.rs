@@ -0,0 +1,1332 @@
+#![doc = include_str!("../.github/styles.md")]
+// localizer-rs styles
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::collections::BTreeMap;
+use std::env;
+use std::io::IsTerminal;
+
+use crate::errors;
+use crate::sources;
+
+
+////////////
+// STYLES //
+////////////
+
+/// The `{{name}}` placeholders that are replaced with terminal formatting codes.
+pub(crate) const STYLES: [(&str, &str); 37] = [
+    // Formatting codes
+    ("end", "\x1b[0m"),
+    ("bold", "\x1b[1m"),
+    ("italic", "\x1b[3m"),
+    ("underline", "\x1b[4m"),
+    ("overline", "\x1b[53m"),
+    // Foreground colors
+    ("color.black", "\x1b[30m"),
+    ("color.red", "\x1b[31m"),
+    ("color.green", "\x1b[32m"),
+    ("color.yellow", "\x1b[33m"),
+    ("color.blue", "\x1b[34m"),
+    ("color.magenta", "\x1b[35m"),
+    ("color.cyan", "\x1b[36m"),
+    ("color.white", "\x1b[37m"),
+    // Bright foreground colors
+    ("color.bright_black", "\x1b[90m"),
+    ("color.bright_red", "\x1b[91m"),
+    ("color.bright_green", "\x1b[92m"),
+    ("color.bright_yellow", "\x1b[93m"),
+    ("color.bright_blue", "\x1b[94m"),
+    ("color.bright_magenta", "\x1b[95m"),
+    ("color.bright_cyan", "\x1b[96m"),
+    ("color.bright_white", "\x1b[97m"),
+    // Background colors
+    ("back.black", "\x1b[40m"),
+    ("back.red", "\x1b[41m"),
+    ("back.green", "\x1b[42m"),
+    ("back.yellow", "\x1b[43m"),
+    ("back.blue", "\x1b[44m"),
+    ("back.magenta", "\x1b[45m"),
+    ("back.cyan", "\x1b[46m"),
+    ("back.white", "\x1b[47m"),
+    // Bright background colors
+    ("back.bright_black", "\x1b[100m"),
+    ("back.bright_red", "\x1b[101m"),
+    ("back.bright_green", "\x1b[102m"),
+    ("back.bright_yellow", "\x1b[103m"),
+    ("back.bright_blue", "\x1b[104m"),
+    ("back.bright_magenta", "\x1b[105m"),
+    ("back.bright_cyan", "\x1b[106m"),
+    ("back.bright_white", "\x1b[107m"),
+];
+
+
+////////////
+// COLORS //
+////////////
+
+/// The colors of the 16 basic terminal colors, as used by xterm.
+const BASIC_COLORS: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// The names of the basic colors, in the order of their palette indexes. The bright colors are
+/// prefixed with `bright_`.
+const COLOR_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+/// The levels of the color channels in the 6x6x6 color cube of the 256 color palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Color of a `{{color.name}}` or `{{back.name}}` placeholder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Color {
+    /// A color of the 256 color palette, eg. `{{color.214}}`.
+    Indexed(u8),
+    /// A 24-bit color, eg. `{{color.#ff8800}}` or `{{color.rgb(255,136,0)}}`.
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// Parses the color of a placeholder.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The color, eg. `red`, `214`, `#ff8800`, `#f80` or `rgb(255, 136, 0)`.
+    ///
+    /// # Returns
+    ///
+    /// The color or `None` if the value is not a valid color.
+    pub(crate) fn parse(value: &str) -> Option<Color> {
+        let (name, offset) = match value.strip_prefix("bright_") {
+            Some(name) => (name, 8),
+            None => (value, 0),
+        };
+        if let Some(index) = COLOR_NAMES.iter().position(|color: &&str| *color == name) {
+            return Some(Color::Indexed(index as u8 + offset));
+        }
+
+        if let Some(hex) = value.strip_prefix('#') {
+            if !hex
+                .chars()
+                .all(|character: char| character.is_ascii_hexdigit())
+            {
+                return None;
+            }
+            let channel = |digits: &str| -> Option<u8> {
+                return u8::from_str_radix(digits, 16).ok();
+            };
+
+            return match hex.len() {
+                3 => Some(Color::Rgb(
+                    channel(&hex[0..1])? * 17,
+                    channel(&hex[1..2])? * 17,
+                    channel(&hex[2..3])? * 17,
+                )),
+                6 => Some(Color::Rgb(
+                    channel(&hex[0..2])?,
+                    channel(&hex[2..4])?,
+                    channel(&hex[4..6])?,
+                )),
+                _ => None,
+            };
+        }
+
+        if let Some(channels) = value
+            .strip_prefix("rgb(")
+            .and_then(|value: &str| value.strip_suffix(')'))
+        {
+            let channels: Vec<u8> = channels
+                .split(',')
+                .map(|channel: &str| channel.trim().parse::<u8>().ok())
+                .collect::<Option<Vec<u8>>>()?;
+
+            return match channels.as_slice() {
+                [red, green, blue] => Some(Color::Rgb(*red, *green, *blue)),
+                _ => None,
+            };
+        }
+
+        if value
+            .chars()
+            .all(|character: char| character.is_ascii_digit())
+        {
+            return value.parse::<u8>().ok().map(Color::Indexed);
+        }
+
+        return None;
+    }
+
+    /// Returns the red, green and blue channels of the color.
+    fn rgb(self) -> (u8, u8, u8) {
+        return match self {
+            Color::Rgb(red, green, blue) => (red, green, blue),
+            Color::Indexed(index @ 0..=15) => BASIC_COLORS[index as usize],
+            Color::Indexed(index @ 16..=231) => {
+                let index: usize = index as usize - 16;
+                (
+                    CUBE_LEVELS[index / 36],
+                    CUBE_LEVELS[index / 6 % 6],
+                    CUBE_LEVELS[index % 6],
+                )
+            }
+            Color::Indexed(index) => {
+                let level: u8 = 8 + 10 * (index - 232);
+                (level, level, level)
+            }
+        };
+    }
+
+    /// Returns the color as a css hex code, eg. `#ff8800`.
+    fn hex(self) -> String {
+        let (red, green, blue) = self.rgb();
+        return format!("#{:02x}{:02x}{:02x}", red, green, blue);
+    }
+
+    /// Returns the closest color of the 256 color palette.
+    fn indexed(self) -> u8 {
+        let (red, green, blue) = match self {
+            Color::Indexed(index) => return index,
+            Color::Rgb(red, green, blue) => (red, green, blue),
+        };
+        let level = |channel: u8| -> usize {
+            return (0..CUBE_LEVELS.len())
+                .min_by_key(|level: &usize| CUBE_LEVELS[*level].abs_diff(channel))
+                .unwrap_or_default();
+        };
+
+        let cube: u8 = (16 + 36 * level(red) + 6 * level(green) + level(blue)) as u8;
+        let average: u32 = (red as u32 + green as u32 + blue as u32) / 3;
+        let gray: u8 = 232 + (average.saturating_sub(3) / 10).min(23) as u8;
+
+        return match distance(Color::Indexed(gray).rgb(), (red, green, blue))
+            < distance(Color::Indexed(cube).rgb(), (red, green, blue))
+        {
+            true => gray,
+            false => cube,
+        };
+    }
+
+    /// Returns the closest of the 16 basic terminal colors.
+    fn basic(self) -> u8 {
+        if let Color::Indexed(index @ 0..=15) = self {
+            return index;
+        }
+        let rgb: (u8, u8, u8) = self.rgb();
+
+        return (0..BASIC_COLORS.len())
+            .min_by_key(|index: &usize| distance(BASIC_COLORS[*index], rgb))
+            .unwrap_or_default() as u8;
+    }
+
+    /// Returns the terminal formatting code of the color.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The color.
+    /// - `background`: Whether the code sets the background color.
+    /// - `depth`: The colors supported by the terminal. Colors are replaced by the closest
+    ///   supported color.
+    ///
+    /// # Returns
+    ///
+    /// The formatting code of the color.
+    pub(crate) fn code(self, background: bool, depth: ColorDepth) -> String {
+        let layer: u8 = match background {
+            true => 48,
+            false => 38,
+        };
+
+        return match (depth, self) {
+            (ColorDepth::Basic, color) => {
+                let index: u8 = color.basic();
+                let base: u8 = match (background, index < 8) {
+                    (false, true) => 30,
+                    (false, false) => 90 - 8,
+                    (true, true) => 40,
+                    (true, false) => 100 - 8,
+                };
+                format!("\x1b[{}m", base + index)
+            }
+            (ColorDepth::Palette, color) | (_, color @ Color::Indexed(_)) => {
+                format!("\x1b[{};5;{}m", layer, color.indexed())
+            }
+            (_, Color::Rgb(red, green, blue)) => {
+                format!("\x1b[{};2;{};{};{}m", layer, red, green, blue)
+            }
+        };
+    }
+}
+
+/// Returns the squared distance of two colors.
+fn distance(first: (u8, u8, u8), second: (u8, u8, u8)) -> u32 {
+    let channel = |first: u8, second: u8| -> u32 {
+        return (first.abs_diff(second) as u32).pow(2);
+    };
+
+    return channel(first.0, second.0) + channel(first.1, second.1) + channel(first.2, second.2);
+}
+
+
+////////////////
+// COLOR MODE //
+////////////////
+
+/// Whether style placeholders like `{{bold}}` are replaced with terminal formatting codes.
+///
+/// # Variants
+///
+/// - `Always`: Formatting codes are always written. This is the default.
+/// - `Never`: Style placeholders are removed from the messages.
+/// - `Auto`: Formatting codes are written if the standard output is a terminal, following the
+///   `NO_COLOR`, `CLICOLOR` and `CLICOLOR_FORCE` environment variables.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// let mut config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+/// config.set_color_mode(localizer_rs::styles::ColorMode::Never);
+///
+/// assert_eq!(
+///     config.t("error", vec![("details", "Something went wrong")]),
+///     "Error: Something went wrong"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum ColorMode {
+    /// Formatting codes are always written.
+    #[default]
+    Always,
+    /// Style placeholders are removed from the messages.
+    Never,
+    /// Formatting codes are written if the standard output is a terminal.
+    Auto,
+}
+
+impl ColorMode {
+    /// Returns whether formatting codes are written.
+    ///
+    /// In `Auto` mode, the environment variables and the standard output are checked as described
+    /// in [`ColorMode::enabled_with()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The color mode.
+    ///
+    /// # Returns
+    ///
+    /// A `bool` indicating whether style placeholders are replaced with formatting codes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// assert!(localizer_rs::styles::ColorMode::Always.enabled());
+    /// assert!(!localizer_rs::styles::ColorMode::Never.enabled());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`ColorMode::enabled_with()`]
+    pub fn enabled(&self) -> bool {
+        return self.enabled_with(
+            |name: &str| env::var_os(name).map(|value| value.to_string_lossy().into_owned()),
+            std::io::stdout().is_terminal(),
+        );
+    }
+
+    /// Returns whether formatting codes are written, using the specified environment.
+    ///
+    /// In `Auto` mode, a `CLICOLOR_FORCE` variable other than `0` enables formatting codes. A
+    /// non-empty `NO_COLOR` variable or `CLICOLOR=0` disables them. Otherwise they are written if
+    /// the output is a terminal.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The color mode.
+    /// - `variable`: Returns the value of an environment variable or `None` if it is not set.
+    /// - `terminal`: Whether the output is a terminal.
+    ///
+    /// # Returns
+    ///
+    /// A `bool` indicating whether style placeholders are replaced with formatting codes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// use localizer_rs::styles::ColorMode;
+    ///
+    /// let variable = |name: &str| (name == "NO_COLOR").then(|| "1".to_owned());
+    ///
+    /// assert!(!ColorMode::Auto.enabled_with(variable, true));
+    /// assert!(ColorMode::Always.enabled_with(variable, true));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`ColorMode::enabled()`]
+    pub fn enabled_with<F: Fn(&str) -> Option<String>>(&self, variable: F, terminal: bool) -> bool {
+        return match self {
+            ColorMode::Always => true,
+            ColorMode::Never => false,
+            ColorMode::Auto => {
+                if variable("CLICOLOR_FORCE").is_some_and(|value| value != "0") {
+                    true
+                } else if variable("NO_COLOR").is_some_and(|value| !value.is_empty())
+                    || variable("CLICOLOR").is_some_and(|value| value == "0")
+                {
+                    false
+                } else {
+                    terminal
+                }
+            }
+        };
+    }
+}
+
+
+/////////////////
+// COLOR DEPTH //
+/////////////////
+
+/// Colors supported by the terminal.
+///
+/// Colors that are not supported are replaced by the closest supported color.
+///
+/// # Variants
+///
+/// - `Auto`: Detects the supported colors using the `COLORTERM` and `TERM` environment variables.
+///   This is the default.
+/// - `Basic`: The 16 basic terminal colors.
+/// - `Palette`: The 256 color palette.
+/// - `TrueColor`: All 24-bit colors.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// use localizer_rs::styles::ColorDepth;
+///
+/// let mut config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+/// config.set_color_depth(ColorDepth::Palette);
+///
+/// assert_eq!(
+///     config.t("warning", vec![("details", "Low disk space")]),
+///     "\x1b[38;5;208m\x1b[1mWarning:\x1b[0m Low disk space"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum ColorDepth {
+    /// Detects the supported colors using the environment variables.
+    #[default]
+    Auto,
+    /// The 16 basic terminal colors.
+    Basic,
+    /// The 256 color palette.
+    Palette,
+    /// All 24-bit colors.
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// Returns the colors supported by the terminal.
+    ///
+    /// In `Auto` mode, the environment variables are checked as described in
+    /// [`ColorDepth::resolve_with()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The color depth.
+    ///
+    /// # Returns
+    ///
+    /// The color depth, which is never `Auto`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// use localizer_rs::styles::ColorDepth;
+    ///
+    /// assert_eq!(ColorDepth::Palette.resolve(), ColorDepth::Palette);
+    /// assert_ne!(ColorDepth::Auto.resolve(), ColorDepth::Auto);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`ColorDepth::resolve_with()`]
+    pub fn resolve(&self) -> ColorDepth {
+        return self.resolve_with(|name: &str| {
+            env::var_os(name).map(|value| value.to_string_lossy().into_owned())
+        });
+    }
+
+    /// Returns the colors supported by the terminal, using the specified environment.
+    ///
+    /// In `Auto` mode, `COLORTERM=truecolor` or `COLORTERM=24bit` and the Windows Terminal
+    /// support 24-bit colors and `TERM` values like `xterm-256color` support the 256 color palette.
+    /// All other terminals use the basic colors.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The color depth.
+    /// - `variable`: Returns the value of an environment variable or `None` if it is not set.
+    ///
+    /// # Returns
+    ///
+    /// The color depth, which is never `Auto`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// use localizer_rs::styles::ColorDepth;
+    ///
+    /// let variable = |name: &str| (name == "TERM").then(|| "xterm-256color".to_owned());
+    ///
+    /// assert_eq!(ColorDepth::Auto.resolve_with(variable), ColorDepth::Palette);
+    /// assert_eq!(ColorDepth::Basic.resolve_with(variable), ColorDepth::Basic);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`ColorDepth::resolve()`]
+    pub fn resolve_with<F: Fn(&str) -> Option<String>>(&self, variable: F) -> ColorDepth {
+        if *self != ColorDepth::Auto {
+            return *self;
+        }
+
+        let color_term: String = variable("COLORTERM").unwrap_or_default();
+        let term: String = variable("TERM").unwrap_or_default();
+
+        if color_term == "truecolor" || color_term == "24bit" || variable("WT_SESSION").is_some() {
+            return ColorDepth::TrueColor;
+        }
+        if term.ends_with("-direct") {
+            return ColorDepth::TrueColor;
+        }
+        if term.contains("256color") {
+            return ColorDepth::Palette;
+        }
+
+        return ColorDepth::Basic;
+    }
+}
+
+
+////////////
+// THEMES //
+////////////
+
+/// The styles of the default theme and their attributes.
+const DEFAULT_THEME: [(&str, &str); 5] = [
+    ("error", "color.red bold"),
+    ("warning", "color.yellow bold"),
+    ("success", "color.green"),
+    ("info", "color.cyan"),
+    ("highlight", "bold underline"),
+];
+
+/// Named styles used by `{{style.name}}` placeholders.
+///
+/// Every style is composed of built-in style placeholders, eg. `color.red bold`. The default
+/// theme contains the `error`, `warning`, `success`, `info` and `highlight` styles, which can be
+/// overridden or extended using [`Theme::set()`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// let mut theme: localizer_rs::styles::Theme = localizer_rs::styles::Theme::new();
+/// theme.set("error", "color.#d70000 bold");
+/// theme.set("brand", "color.magenta italic");
+///
+/// assert_eq!(
+///     theme.get("brand"),
+///     Some(&["color.magenta".to_owned(), "italic".to_owned()][..])
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Theme {
+    /// The attributes of the styles by name.
+    styles: BTreeMap<String, Vec<String>>,
+}
+
+impl Theme {
+    /// Creates the default theme.
+    ///
+    /// # Returns
+    ///
+    /// A new `Theme` containing the `error`, `warning`, `success`, `info` and `highlight` styles.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// let theme: localizer_rs::styles::Theme = localizer_rs::styles::Theme::new();
+    ///
+    /// assert!(theme.get("error").is_some());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Theme::empty()`]
+    pub fn new() -> Theme {
+        let mut theme: Theme = Theme::empty();
+        for (name, attributes) in DEFAULT_THEME {
+            theme.styles.insert(
+                name.to_owned(),
+                attributes.split_whitespace().map(str::to_owned).collect(),
+            );
+        }
+
+        return theme;
+    }
+
+    /// Creates a theme without any styles.
+    ///
+    /// # Returns
+    ///
+    /// A new empty `Theme`.
+    ///
+    /// # See also
+    ///
+    /// - [`Theme::new()`]
+    pub fn empty() -> Theme {
+        return Theme {
+            styles: BTreeMap::new(),
+        };
+    }
+
+    /// Loads a theme file, exiting on errors.
+    ///
+    /// # Parameters
+    ///
+    /// - `path`: The path of the theme file.
+    ///
+    /// # Returns
+    ///
+    /// The default theme extended with the styles of the file.
+    ///
+    /// # See also
+    ///
+    /// - [`Theme::try_from_file()`]
+    pub fn from_file(path: &str) -> Theme {
+        return match Theme::try_from_file(path) {
+            Ok(value) => value,
+            Err(error) => error.raise(),
+        };
+    }
+
+    /// Loads a theme file without exiting on errors.
+    ///
+    /// The theme file is a json object mapping the names of the styles to their attributes, eg.
+    /// `{"error": "color.#d70000 bold"}`. Its styles override or extend the default theme.
+    ///
+    /// # Parameters
+    ///
+    /// - `path`: The path of the theme file.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the theme or the error that occurred while loading it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - The file does not exist or could not be read
+    /// - The file is not a json object of strings
+    /// - A style uses an unknown attribute
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// let theme: localizer_rs::styles::Theme =
+    ///     localizer_rs::styles::Theme::try_from_file("examples/translations/theme.json").unwrap();
+    ///
+    /// assert!(theme.get("brand").is_some());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Theme::from_file()`]
+    pub fn try_from_file(path: &str) -> Result<Theme, errors::Error> {
+        let content: Vec<u8> = sources::read_file(path)?;
+        return Theme::parse(path, &content);
+    }
+
+    /// Parses the content of a theme file.
+    ///
+    /// # Parameters
+    ///
+    /// - `path`: The path of the theme file, used in errors.
+    /// - `content`: The content of the theme file.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the theme or the error that occurred while parsing it.
+    pub(crate) fn parse(path: &str, content: &[u8]) -> Result<Theme, errors::Error> {
+        let styles: BTreeMap<String, String> = match serde_json::from_slice(content) {
+            Ok(value) => value,
+            Err(_error) => {
+                return Err(errors::Error::Parse {
+                    path: path.to_owned(),
+                    line: _error.line(),
+                    column: _error.column(),
+                    source: _error,
+                });
+            }
+        };
+
+        let mut theme: Theme = Theme::new();
+        for (name, attributes) in styles {
+            theme.try_set(&name, &attributes)?;
+        }
+
+        return Ok(theme);
+    }
+
+    /// Sets the attributes of a style, exiting on errors.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The theme object. This must be mutable.
+    /// - `name`: The name of the style, used as `{{style.name}}`.
+    /// - `attributes`: The built-in styles separated by spaces, eg. `color.red bold`.
+    ///
+    /// # Returns
+    ///
+    /// The modified `Theme` object.
+    ///
+    /// # See also
+    ///
+    /// - [`Theme::try_set()`]
+    pub fn set(&mut self, name: &str, attributes: &str) -> &Theme {
+        if let Err(error) = self.try_set(name, attributes) {
+            error.raise();
+        }
+
+        return self;
+    }
+
+    /// Sets the attributes of a style without exiting on errors.
+    ///
+    /// The theme is left unchanged if an error occurs.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The theme object. This must be mutable.
+    /// - `name`: The name of the style, used as `{{style.name}}`.
+    /// - `attributes`: The built-in styles separated by spaces, eg. `color.red bold`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the modified `Theme` object or the error that occurred.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`errors::Error::InvalidStyle`] if an attribute is not a built-in style.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use localizer_rs;
+    /// let mut theme: localizer_rs::styles::Theme = localizer_rs::styles::Theme::new();
+    ///
+    /// assert!(theme.try_set("brand", "color.magenta bold").is_ok());
+    /// assert!(theme.try_set("brand", "color.purple").is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Theme::set()`]
+    pub fn try_set(&mut self, name: &str, attributes: &str) -> Result<&Theme, errors::Error> {
+        let attributes: Vec<String> = attributes.split_whitespace().map(str::to_owned).collect();
+
+        if let Some(attribute) = attributes.iter().find(|attribute| !is_builtin(attribute)) {
+            return Err(errors::Error::InvalidStyle {
+                name: name.to_owned(),
+                attribute: attribute.to_owned(),
+            });
+        }
+        self.styles.insert(name.to_owned(), attributes);
+
+        return Ok(self);
+    }
+
+    /// Removes a style.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The theme object. This must be mutable.
+    /// - `name`: The name of the style.
+    ///
+    /// # Returns
+    ///
+    /// The modified `Theme` object.
+    pub fn remove(&mut self, name: &str) -> &Theme {
+        self.styles.remove(name);
+        return self;
+    }
+
+    /// Returns the attributes of a style.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The theme object.
+    /// - `name`: The name of the style.
+    ///
+    /// # Returns
+    ///
+    /// The built-in styles the style is composed of or `None` if the theme does not contain it.
+    pub fn get(&self, name: &str) -> Option<&[String]> {
+        return self.styles.get(name).map(Vec::as_slice);
+    }
+
+    /// Returns the names of the styles.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The theme object.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the sorted names of the styles.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        return self.styles.keys().map(String::as_str);
+    }
+}
+
+impl Default for Theme {
+    /// Creates the default theme.
+    ///
+    /// # Returns
+    ///
+    /// The same theme as [`Theme::new()`].
+    fn default() -> Theme {
+        return Theme::new();
+    }
+}
+
+
+///////////////
+// RENDERERS //
+///////////////
+
+/// The characters escaped with a backslash by the `Markdown` renderer.
+const MARKDOWN_ESCAPES: [char; 11] = ['\\', '`', '*', '_', '~', '[', ']', '<', '>', '#', '|'];
+
+/// How style placeholders like `{{bold}}` are rendered.
+///
+/// # Variants
+///
+/// - `Ansi`: Styles are written as terminal formatting codes. This is the default.
+/// - `Plain`: Styles are removed from the messages.
+/// - `Html`: Styles are written as html tags, eg. `<strong>` or `<span style="color: #cd0000">`.
+///   The text of the messages is escaped.
+/// - `Markdown`: Bold and italic styles are written as `**` and `*`, other styles are removed.
+///   Markdown characters in the text of the messages are escaped.
+///
+/// The color mode and the color depth of the config only apply to the `Ansi` renderer.
+///
+/// # Examples
+///
+/// ```rust
+/// # use localizer_rs;
+/// let mut config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+/// config.set_renderer(localizer_rs::styles::Renderer::Html);
+///
+/// assert_eq!(
+///     config.t("error", vec![("details", "<script>")]),
+///     "<span style=\"color: #cd0000\"><strong>Error:</strong></span> &lt;script&gt;"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Renderer {
+    /// Styles are written as terminal formatting codes.
+    #[default]
+    Ansi,
+    /// Styles are removed from the messages.
+    Plain,
+    /// Styles are written as html tags.
+    Html,
+    /// Styles are written as Markdown emphasis.
+    Markdown,
+}
+
+/// A built-in style placeholder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Attribute {
+    /// `{{end}}`, resetting all styles.
+    End,
+    /// `{{bold}}`.
+    Bold,
+    /// `{{italic}}`.
+    Italic,
+    /// `{{underline}}`.
+    Underline,
+    /// `{{overline}}`.
+    Overline,
+    /// `{{color.name}}`.
+    Color(Color),
+    /// `{{back.name}}`.
+    Back(Color),
+}
+
+impl Attribute {
+    /// Parses a built-in style placeholder.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the placeholder, eg. `bold` or `color.red`.
+    ///
+    /// # Returns
+    ///
+    /// The attribute or `None` if the name is not a built-in style.
+    fn parse(name: &str) -> Option<Attribute> {
+        return match name {
+            "end" => Some(Attribute::End),
+            "bold" => Some(Attribute::Bold),
+            "italic" => Some(Attribute::Italic),
+            "underline" => Some(Attribute::Underline),
+            "overline" => Some(Attribute::Overline),
+            _ => match (name.strip_prefix("color."), name.strip_prefix("back.")) {
+                (Some(color), _) => Color::parse(color).map(Attribute::Color),
+                (None, Some(color)) => Color::parse(color).map(Attribute::Back),
+                (None, None) => None,
+            },
+        };
+    }
+
+    /// Returns the html tags of the attribute.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The attribute.
+    ///
+    /// # Returns
+    ///
+    /// The opening and the closing tag or `None` for `{{end}}`.
+    fn html(self) -> Option<(String, &'static str)> {
+        return match self {
+            Attribute::End => None,
+            Attribute::Bold => Some(("<strong>".to_owned(), "</strong>")),
+            Attribute::Italic => Some(("<em>".to_owned(), "</em>")),
+            Attribute::Underline => Some(("<u>".to_owned(), "</u>")),
+            Attribute::Overline => Some((
+                "<span style=\"text-decoration: overline\">".to_owned(),
+                "</span>",
+            )),
+            Attribute::Color(color) => Some((
+                format!("<span style=\"color: {}\">", color.hex()),
+                "</span>",
+            )),
+            Attribute::Back(color) => Some((
+                format!("<span style=\"background-color: {}\">", color.hex()),
+                "</span>",
+            )),
+        };
+    }
+
+    /// Returns the Markdown marker of the attribute.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The attribute.
+    ///
+    /// # Returns
+    ///
+    /// The marker opening and closing the attribute or `None` if Markdown does not support it.
+    fn markdown(self) -> Option<&'static str> {
+        return match self {
+            Attribute::Bold => Some("**"),
+            Attribute::Italic => Some("*"),
+            _ => None,
+        };
+    }
+}
+
+/// Returns the built-in styles of a style placeholder.
+///
+/// # Parameters
+///
+/// - `name`: The name of the placeholder, eg. `bold` or `style.error`.
+/// - `theme`: The styles of `{{style.name}}` placeholders.
+///
+/// # Returns
+///
+/// The attributes or `None` if the name is not a style.
+fn attributes(name: &str, theme: &Theme) -> Option<Vec<Attribute>> {
+    return match name.strip_prefix("style.") {
+        Some(style) => theme
+            .get(style)?
+            .iter()
+            .map(|attribute: &String| Attribute::parse(attribute))
+            .collect(),
+        None => Attribute::parse(name).map(|attribute: Attribute| vec![attribute]),
+    };
+}
+
+/// A message rendered with the renderer of a styling.
+#[derive(Debug)]
+pub(crate) struct Output<'a> {
+    /// How the style placeholders are written.
+    styling: &'a Styling<'a>,
+    /// The rendered message.
+    text: String,
+    /// The closing tags or markers of the open styles, in the order the styles were opened.
+    open: Vec<&'static str>,
+    /// The names of the open style tags and the number of open styles before each tag.
+    tags: Vec<(&'a str, usize)>,
+}
+
+impl<'a> Output<'a> {
+    /// Creates an empty message.
+    ///
+    /// # Parameters
+    ///
+    /// - `styling`: How the style placeholders are written.
+    ///
+    /// # Returns
+    ///
+    /// A new empty `Output`.
+    pub(crate) fn new(styling: &'a Styling<'a>) -> Output<'a> {
+        return Output {
+            styling,
+            text: String::new(),
+            open: vec![],
+            tags: vec![],
+        };
+    }
+
+    /// Appends text, escaping it for the renderer.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The message.
+    /// - `text`: The text to append.
+    pub(crate) fn text(&mut self, text: &str) {
+        match self.styling.renderer {
+            Renderer::Html => {
+                for character in text.chars() {
+                    match character {
+                        '&' => self.text.push_str("&amp;"),
+                        '<' => self.text.push_str("&lt;"),
+                        '>' => self.text.push_str("&gt;"),
+                        '"' => self.text.push_str("&quot;"),
+                        '\'' => self.text.push_str("&#39;"),
+                        character => self.text.push(character),
+                    }
+                }
+            }
+            Renderer::Markdown => {
+                for character in text.chars() {
+                    if MARKDOWN_ESCAPES.contains(&character) {
+                        self.text.push('\\');
+                    }
+                    self.text.push(character);
+                }
+            }
+            Renderer::Ansi | Renderer::Plain => self.text.push_str(text),
+        }
+    }
+
+    /// Appends a style placeholder.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The message.
+    /// - `name`: The name of the placeholder, eg. `bold` or `style.error`.
+    ///
+    /// # Returns
+    ///
+    /// A `bool` indicating whether the placeholder is a style. Nothing is appended otherwise.
+    pub(crate) fn style(&mut self, name: &str) -> bool {
+        if self.styling.renderer == Renderer::Ansi {
+            return match code(name, self.styling) {
+                Some(code) => {
+                    self.text.push_str(&code);
+                    true
+                }
+                None => false,
+            };
+        }
+
+        let attributes: Vec<Attribute> = match attributes(name, self.styling.theme) {
+            Some(value) => value,
+            None => return false,
+        };
+        for attribute in attributes {
+            match (self.styling.renderer, attribute) {
+                (_, Attribute::End) => self.reset(),
+                (Renderer::Html, attribute) => {
+                    if let Some((open, close)) = attribute.html() {
+                        self.text.push_str(&open);
+                        self.open.push(close);
+                    }
+                }
+                (Renderer::Markdown, attribute) => {
+                    if let Some(marker) = attribute.markdown() {
+                        self.text.push_str(marker);
+                        self.open.push(marker);
+                    }
+                }
+                (Renderer::Ansi | Renderer::Plain, _attribute) => {}
+            }
+        }
+
+        return true;
+    }
+
+    /// Opens a style tag like `{{bold}}...{{/bold}}`.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The message.
+    /// - `name`: The name of the style, eg. `bold` or `style.error`.
+    pub(crate) fn open(&mut self, name: &'a str) {
+        self.tags.push((name, self.open.len()));
+        self.style(name);
+    }
+
+    /// Closes the innermost style tag, keeping the styles of the outer tags.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The message.
+    pub(crate) fn close(&mut self) {
+        let depth: usize = match self.tags.pop() {
+            Some((_name, depth)) => depth,
+            None => return,
+        };
+
+        if self.styling.renderer == Renderer::Ansi {
+            // Terminals can only reset all styles, so the outer styles are written again
+            self.style("end");
+            let outer: Vec<&str> = self.tags.iter().map(|(name, _depth)| *name).collect();
+            for name in outer {
+                self.style(name);
+            }
+            return;
+        }
+        while self.open.len() > depth {
+            if let Some(close) = self.open.pop() {
+                self.text.push_str(close);
+            }
+        }
+    }
+
+    /// Closes all open styles.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The message.
+    fn reset(&mut self) {
+        while let Some(close) = self.open.pop() {
+            self.text.push_str(close);
+        }
+    }
+
+    /// Closes the open styles and returns the rendered message.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The message.
+    ///
+    /// # Returns
+    ///
+    /// The rendered message.
+    pub(crate) fn finish(mut self) -> String {
+        self.reset();
+        return self.text;
+    }
+}
+
+
+/////////////
+// STYLING //
+/////////////
+
+/// How style placeholders are written, resolved from the config once per message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Styling<'a> {
+    /// Whether formatting codes are written. Styles are replaced by an empty string otherwise.
+    pub(crate) color: bool,
+    /// The colors supported by the terminal.
+    pub(crate) depth: ColorDepth,
+    /// The styles of `{{style.name}}` placeholders.
+    pub(crate) theme: &'a Theme,
+    /// How the style placeholders are rendered.
+    pub(crate) renderer: Renderer,
+}
+
+impl<'a> Styling<'a> {
+    /// Resolves the styling of a color mode and a color depth.
+    ///
+    /// # Parameters
+    ///
+    /// - `mode`: Whether formatting codes are written.
+    /// - `depth`: The colors supported by the terminal.
+    /// - `theme`: The styles of `{{style.name}}` placeholders.
+    /// - `renderer`: How the style placeholders are rendered.
+    ///
+    /// # Returns
+    ///
+    /// The `Styling` to write the style placeholders with.
+    pub(crate) fn new(
+        mode: ColorMode,
+        depth: ColorDepth,
+        theme: &'a Theme,
+        renderer: Renderer,
+    ) -> Styling<'a> {
+        let color: bool = renderer == Renderer::Ansi && mode.enabled();
+
+        return Styling {
+            color,
+            // The terminal is only inspected if colors are written
+            depth: match color {
+                true => depth.resolve(),
+                false => ColorDepth::Basic,
+            },
+            theme,
+            renderer,
+        };
+    }
+}
+
+/// Returns the terminal formatting code of a style placeholder.
+///
+/// # Parameters
+///
+/// - `name`: The name of the placeholder, eg. `color.red`, `color.#ff8800`, `back.214` or
+///   `style.error`.
+/// - `styling`: How the formatting codes are written.
+///
+/// # Returns
+///
+/// The formatting code or `None` if the name is not a style.
+pub(crate) fn code(name: &str, styling: &Styling) -> Option<String> {
+    if let Some(style) = name.strip_prefix("style.") {
+        let mut code: String = String::new();
+        for attribute in styling.theme.get(style)? {
+            code.push_str(&self::code(attribute, styling)?);
+        }
+        return Some(code);
+    }
+
+    let code: String = match STYLES.iter().find(|(style, _code)| *style == name) {
+        Some((_style, code)) => (*code).to_owned(),
+        None => {
+            let (background, color) =
+                match (name.strip_prefix("color."), name.strip_prefix("back.")) {
+                    (Some(color), _) => (false, color),
+                    (None, Some(color)) => (true, color),
+                    (None, None) => return None,
+                };
+            Color::parse(color)?.code(background, styling.depth)
+        }
+    };
+
+    return match styling.color {
+        true => Some(code),
+        false => Some(String::new()),
+    };
+}
+
+/// Returns whether a placeholder name is a built-in style.
+///
+/// # Parameters
+///
+/// - `name`: The name of the placeholder, eg. `bold`.
+///
+/// # Returns
+///
+/// A `bool` indicating whether the placeholder is replaced with a formatting code regardless of
+/// the theme.
+fn is_builtin(name: &str) -> bool {
+    let theme: Theme = Theme::empty();
+    let styling: Styling = Styling {
+        color: true,
+        depth: ColorDepth::TrueColor,
+        theme: &theme,
+        renderer: Renderer::Ansi,
+    };
+
+    return code(name, &styling).is_some();
+}
+
+/// Returns whether a placeholder name is a style.
+///
+/// Themes are only known at runtime, so every `{{style.name}}` placeholder is considered a style.
+///
+/// # Parameters
+///
+/// - `name`: The name of the placeholder, eg. `bold`, `style.error` or the closing tag `/bold`.
+///
+/// # Returns
+///
+/// A `bool` indicating whether the placeholder is replaced with a formatting code.
+pub(crate) fn is_style(name: &str) -> bool {
+    let name: &str = name.strip_prefix('/').unwrap_or(name);
+    return name.starts_with("style.") || is_builtin(name);
+}
+
+/// Returns whether a placeholder name is a style of a theme.
+///
+/// # Parameters
+///
+/// - `name`: The name of the placeholder, eg. `bold` or `style.error`.
+/// - `theme`: The styles of `{{style.name}}` placeholders.
+///
+/// # Returns
+///
+/// A `bool` indicating whether the placeholder is a built-in style or a style of the theme.
+pub(crate) fn is_style_of(name: &str, theme: &Theme) -> bool {
+    return attributes(name, theme).is_some();
+}
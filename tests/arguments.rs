@@ -0,0 +1,96 @@
+// localizer-rs arguments tests
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+#[allow(unused_imports, clippy::single_component_path_imports)]
+use localizer_rs;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use localizer_rs::arguments::Args;
+    use localizer_rs::arguments::Value;
+
+    #[test]
+    fn test_value() {
+        assert_eq!(Value::from(-3), Value::Int(-3));
+        assert_eq!(Value::from(3_u64), Value::Int(3));
+        assert_eq!(Value::from(3_usize), Value::from(3_i32));
+        assert_eq!(Value::from(u64::MAX), Value::UInt(u64::MAX));
+        assert_eq!(Value::from(2.5), Value::Float(2.5));
+        assert_eq!(Value::from(true), Value::Bool(true));
+        assert_eq!(Value::from("text"), Value::Str("text".to_owned()));
+        assert_eq!(Value::from(2.5).to_string(), "2.5");
+        assert_eq!(Value::from(false).to_string(), "false");
+    }
+
+    #[test]
+    fn test_args() {
+        let mut arguments: Args = Args::new().with("user", "John Doe").with("count", 3);
+        arguments.set("user", "Jane Doe");
+
+        assert_eq!(arguments.len(), 2);
+        assert_eq!(arguments.get("user"), Some(&Value::from("Jane Doe")));
+        assert_eq!(
+            arguments
+                .iter()
+                .map(|(name, _value)| name)
+                .collect::<Vec<&str>>(),
+            vec!["user", "count"]
+        );
+        assert_eq!(
+            Args::from(vec![("a", "1"), ("a", "2")]).get("a"),
+            Some(&Value::from("1"))
+        );
+        assert_eq!(
+            Args::from([("a", 1), ("a", 2)]).get("a"),
+            Some(&Value::from(2))
+        );
+        assert!(Args::from(vec![]).is_empty());
+    }
+
+    #[test]
+    fn test_translate() {
+        let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+        let arguments: Args = Args::new().with("balance", 10.5).with("user", "John Doe");
+
+        assert_eq!(
+            config.t("success", &arguments),
+            config.t("success", vec![("balance", "10.5"), ("user", "John Doe")])
+        );
+        assert_eq!(config.t("files", Args::new().with("count", 1)), "1 file");
+        assert_eq!(config.t("files", [("count", 2.5)]), "2.5 files");
+        assert_eq!(localizer_rs::t!(config, "files", "count" = 0), "No files");
+        assert_eq!(
+            config.translate_plural("files", 2, Args::new().with("count", "two")),
+            "two files"
+        );
+    }
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "macros")]
+// localizer-rs checked tests
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+#[allow(unused_imports, clippy::single_component_path_imports)]
+use localizer_rs;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    macro_rules! tr {
+        ($($arguments:tt)*) => {
+            localizer_rs::checked_t!("examples/translations/en.json", $($arguments)*)
+        };
+    }
+
+    #[test]
+    fn test_checked_t() {
+        let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+        let balance: String = format!("{}€", 10);
+
+        assert_eq!(tr!(config, "test"), "Something that can be translated.");
+        assert_eq!(
+            tr!(config, "success", balance, user = "Bob"),
+            "\x1b[32m\x1b[1mSuccess:\x1b[0m Successfully transferred 10€ to Bob"
+        );
+        assert_eq!(tr!(config, "menu.file.save\\.as"), "Save as");
+        assert_eq!(tr!(config, "files", count = 0), "No files");
+        assert_eq!(
+            tr!(
+                config,
+                "invitation",
+                count = 2,
+                "host" = "Ann",
+                "guest" = "Bob"
+            ),
+            config.translate_plural("invitation", 2, vec![("host", "Ann"), ("guest", "Bob")])
+        );
+    }
+
+    #[test]
+    fn test_checked_t_language() {
+        let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "de");
+
+        assert_eq!(tr!(config, "files", count = 1), "1 Datei");
+    }
+}
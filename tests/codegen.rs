@@ -0,0 +1,97 @@
+// localizer-rs codegen tests
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+#[allow(unused_imports, clippy::single_component_path_imports)]
+use localizer_rs;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    #[allow(dead_code)]
+    mod generated {
+        include!("translations/codegen/en.rs");
+    }
+
+    #[test]
+    fn test_source() {
+        assert_eq!(
+            localizer_rs::codegen::source("tests/translations/codegen", "en"),
+            include_str!("translations/codegen/en.rs")
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        let config: localizer_rs::Config =
+            localizer_rs::Config::new("tests/translations/codegen", "en");
+
+        assert_eq!(generated::greeting(&config, "Bob"), "Hello Bob!");
+        assert_eq!(
+            generated::type_(&config, "Name", "bob"),
+            "\x1b[1mName:\x1b[0m bob"
+        );
+        assert_eq!(generated::files(&config, 1, "docs"), "1 file in docs");
+        assert_eq!(generated::try_files_(&config), "Try again");
+        assert_eq!(
+            generated::total(&config, 1234.5, vec!["tea", "cake"]),
+            "Total: 1,234.50 for tea and cake"
+        );
+        assert_eq!(generated::try_try_files_(&config).unwrap(), "Try again");
+        assert_eq!(generated::menu::save_as(&config), "Save as");
+        assert_eq!(generated::menu::try_open(&config).unwrap(), "Open");
+    }
+
+    #[test]
+    fn test_generate() {
+        let output: std::path::PathBuf =
+            std::env::temp_dir().join(format!("localizer_rs_codegen_{}.rs", std::process::id()));
+
+        localizer_rs::codegen::try_generate(
+            "tests/translations/codegen",
+            "en",
+            output.to_str().unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&output).unwrap(),
+            include_str!("translations/codegen/en.rs")
+        );
+        std::fs::remove_file(&output).unwrap();
+        assert!(matches!(
+            localizer_rs::codegen::try_source("tests/translations/codegen", "de"),
+            Err(localizer_rs::errors::Error::FileNotFound { .. })
+        ));
+        assert!(matches!(
+            localizer_rs::codegen::try_generate("tests/translations/codegen", "en", "tests"),
+            Err(localizer_rs::errors::Error::FileUnwritable { .. })
+        ));
+    }
+}
@@ -0,0 +1,225 @@
+#![cfg(feature = "chrono")]
+// localizer-rs dates tests
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+#[allow(unused_imports, clippy::single_component_path_imports)]
+use localizer_rs;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use localizer_rs::dates::{DateFormat, DateStyle};
+
+    fn when() -> chrono::NaiveDateTime {
+        chrono::NaiveDate::from_ymd_opt(2023, 10, 5)
+            .unwrap()
+            .and_hms_opt(14, 30, 5)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_date() {
+        let date: chrono::NaiveDate = when().date();
+
+        assert_eq!(
+            localizer_rs::dates::format("en", date, &DateFormat::date(DateStyle::Short)),
+            "10/5/23"
+        );
+        assert_eq!(
+            localizer_rs::dates::format("en", date, &DateFormat::date(DateStyle::Medium)),
+            "Oct 5, 2023"
+        );
+        assert_eq!(
+            localizer_rs::dates::format("en_GB", date, &DateFormat::date(DateStyle::Long)),
+            "5 October 2023"
+        );
+        assert_eq!(
+            localizer_rs::dates::format("de", date, &DateFormat::date(DateStyle::Short)),
+            "05.10.23"
+        );
+        assert_eq!(
+            localizer_rs::dates::format("es", date, &DateFormat::date(DateStyle::Full)),
+            "jueves, 5 de octubre de 2023"
+        );
+        assert_eq!(
+            localizer_rs::dates::format("ru", date, &DateFormat::date(DateStyle::Long)),
+            "5 октября 2023 г."
+        );
+        assert_eq!(
+            localizer_rs::dates::format("ja", date, &DateFormat::date(DateStyle::Full)),
+            "2023年10月5日木曜日"
+        );
+        assert_eq!(
+            localizer_rs::dates::format("xx", date, &DateFormat::date(DateStyle::Long)),
+            "October 5, 2023"
+        );
+    }
+
+    #[test]
+    fn test_time() {
+        let time: chrono::NaiveTime = when().time();
+
+        assert_eq!(
+            localizer_rs::dates::format("en", time, &DateFormat::time(DateStyle::Short)),
+            "2:30 PM"
+        );
+        assert_eq!(
+            localizer_rs::dates::format("en-GB", time, &DateFormat::time(DateStyle::Medium)),
+            "14:30:05"
+        );
+        assert_eq!(
+            localizer_rs::dates::format("es", time, &DateFormat::time(DateStyle::Short)),
+            "14:30"
+        );
+        assert_eq!(
+            localizer_rs::dates::format(
+                "en",
+                chrono::NaiveTime::from_hms_opt(0, 5, 0).unwrap(),
+                &DateFormat::time(DateStyle::Short)
+            ),
+            "12:05 AM"
+        );
+    }
+
+    #[test]
+    fn test_datetime() {
+        assert_eq!(
+            localizer_rs::dates::format("en", when(), &DateFormat::datetime(DateStyle::Long)),
+            "October 5, 2023 at 2:30:05 PM"
+        );
+        assert_eq!(
+            localizer_rs::dates::format("de", when(), &DateFormat::datetime(DateStyle::Medium)),
+            "05.10.2023, 14:30:05"
+        );
+        assert_eq!(
+            localizer_rs::dates::format("fr", when(), &DateFormat::datetime(DateStyle::Full)),
+            "jeudi 5 octobre 2023 à 14:30:05"
+        );
+        assert_eq!(
+            localizer_rs::dates::format("de", when(), &DateFormat::date(DateStyle::Long)),
+            "5. Oktober 2023"
+        );
+
+        let utc: chrono::DateTime<chrono::Utc> =
+            chrono::DateTime::from_naive_utc_and_offset(when(), chrono::Utc);
+        let offset: chrono::FixedOffset = chrono::FixedOffset::east_opt(2 * 3600).unwrap();
+        assert_eq!(
+            localizer_rs::dates::format(
+                "en",
+                utc.with_timezone(&offset),
+                &DateFormat::time(DateStyle::Short)
+            ),
+            "4:30 PM"
+        );
+    }
+
+    #[test]
+    fn test_unsupported_values() {
+        assert_eq!(
+            localizer_rs::dates::format("en", "tomorrow", &DateFormat::default()),
+            "tomorrow"
+        );
+        assert_eq!(
+            localizer_rs::dates::format("en", 5, &DateFormat::default()),
+            "5"
+        );
+        assert_eq!(
+            localizer_rs::dates::format("en", when().time(), &DateFormat::date(DateStyle::Long)),
+            "14:30:05"
+        );
+        assert_eq!(
+            localizer_rs::dates::format(
+                "en",
+                when().date(),
+                &DateFormat::datetime(DateStyle::Short)
+            ),
+            "10/5/23"
+        );
+    }
+
+    #[test]
+    fn test_placeholders() {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "en");
+        let when: chrono::NaiveDateTime = when();
+
+        assert_eq!(
+            localizer_rs::t!(config, "appointment", when),
+            "Your appointment is on Thursday, October 5, 2023 at 2:30 PM."
+        );
+        assert_eq!(
+            config.format_date(when, &DateFormat::datetime(DateStyle::Short)),
+            "10/5/23, 2:30 PM"
+        );
+
+        config.set_language("de");
+        assert_eq!(
+            config.t(
+                "appointment",
+                localizer_rs::arguments::Args::new().with("when", when)
+            ),
+            "Ihr Termin ist am Donnerstag, 5. Oktober 2023 um 14:30."
+        );
+        assert_eq!(
+            config.t("appointment", vec![("when", "morgen")]),
+            "Ihr Termin ist am morgen um morgen."
+        );
+    }
+
+    #[test]
+    fn test_message_format() {
+        let mut config: localizer_rs::Config = localizer_rs::Config::with_source(
+            localizer_rs::sources::Memory::new().with(
+                "en",
+                [(
+                    "appointment",
+                    "On {when, date, short} at {when, time}, {when, date, long} ({day, date})",
+                )],
+            ),
+            "en",
+        );
+        config.set_syntax(localizer_rs::Syntax::MessageFormat);
+
+        assert_eq!(
+            localizer_rs::t!(config, "appointment", when = when(), day = "tomorrow"),
+            "On 10/5/23 at 2:30:05 PM, October 5, 2023 (tomorrow)"
+        );
+    }
+
+    #[test]
+    fn test_fluent() {
+        let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "fr");
+
+        assert_eq!(
+            localizer_rs::t!(config, "appointment", when = when()),
+            "Votre rendez-vous est le jeudi 5 octobre 2023 à 14:30."
+        );
+    }
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "macros")]
+// localizer-rs embed tests
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+#[allow(unused_imports, clippy::single_component_path_imports)]
+use localizer_rs;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use localizer_rs::sources::CatalogSource;
+
+    const TRANSLATIONS: localizer_rs::sources::Embedded =
+        localizer_rs::embed!("examples/translations");
+
+    #[test]
+    fn test_embed() {
+        assert_eq!(
+            TRANSLATIONS.languages().unwrap(),
+            vec!["de", "en", "es", "fr", "pl"]
+        );
+        assert_eq!(TRANSLATIONS.files().len(), 5);
+        assert_eq!(
+            TRANSLATIONS.load("en").unwrap(),
+            localizer_rs::sources::Files::new("examples/translations")
+                .load("en")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_translate() {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::with_source(TRANSLATIONS, "fr-CA");
+        config.set_fallbacks(&["en"]);
+
+        assert_eq!(config.t("menu.open", vec![]), "Ouvrir un fichier");
+        assert_eq!(config.t("menu.file.open", vec![]), "Open file");
+        assert_eq!(config.translate_plural("files", 2, vec![]), "2 fichiers");
+        assert!(matches!(
+            localizer_rs::Config::with_source(TRANSLATIONS, "it").try_t("test", vec![]),
+            Err(localizer_rs::errors::Error::LanguageNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_theme() {
+        let config: localizer_rs::Config = localizer_rs::Config::with_source(TRANSLATIONS, "en");
+
+        assert_eq!(
+            TRANSLATIONS.theme().unwrap(),
+            Some(
+                localizer_rs::styles::Theme::try_from_file("examples/translations/theme.json")
+                    .unwrap()
+            )
+        );
+        assert_eq!(Some(config.theme), TRANSLATIONS.theme().unwrap());
+        assert_eq!(
+            localizer_rs::sources::Embedded::new(&[]).theme().unwrap(),
+            None
+        );
+    }
+}
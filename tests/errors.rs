@@ -25,7 +25,7 @@
 // IMPORTS AND USE STATEMENTS //
 ////////////////////////////////
 
-#[allow(unused_imports)]
+#[allow(unused_imports, clippy::single_component_path_imports)]
 use localizer_rs;
 
 
@@ -35,33 +35,120 @@ use localizer_rs;
 
 #[cfg(test)]
 mod tests {
+    use std::error::Error;
+
     #[test]
     fn test_error() {
-        let error: localizer_rs::errors::Error =
-            localizer_rs::errors::Error::new("name", "description", 1);
+        let error: localizer_rs::errors::Error = localizer_rs::errors::Error::KeyNotFound {
+            key: "key".to_owned(),
+            segment: "key".to_owned(),
+            language: "en".to_owned(),
+        };
 
+        assert_eq!(error.name(), "Indexing error");
+        assert_eq!(error.exit_code(), 6);
         assert_eq!(
-            error,
-            localizer_rs::errors::Error {
-                name: "name".to_owned(),
-                description: "description".to_owned(),
-                exit_code: 1
-            }
+            error.to_string(),
+            "Could not find translation key (key: \"key\", segment: \"key\", language: \"en\")"
         );
+        assert!(error.source().is_none());
+    }
+
+    #[test]
+    fn test_exit_codes() {
+        let errors: Vec<localizer_rs::errors::Error> = vec![
+            localizer_rs::errors::Error::PathNotFound {
+                path: "path".to_owned(),
+            },
+            localizer_rs::errors::Error::PathUnreadable {
+                path: "path".to_owned(),
+                source: std::io::Error::new(std::io::ErrorKind::Other, "io"),
+            },
+            localizer_rs::errors::Error::InvalidPath {
+                path: "path".to_owned(),
+            },
+            localizer_rs::errors::Error::FileNotFound {
+                path: "path".to_owned(),
+                source: std::io::Error::new(std::io::ErrorKind::Other, "io"),
+            },
+            localizer_rs::errors::Error::Parse {
+                path: "path".to_owned(),
+                line: 1,
+                column: 1,
+                source: serde_json::from_str::<serde_json::Value>("{").unwrap_err(),
+            },
+            localizer_rs::errors::Error::KeyNotFound {
+                key: "key".to_owned(),
+                segment: "key".to_owned(),
+                language: "en".to_owned(),
+            },
+            localizer_rs::errors::Error::FileUnreadable {
+                path: "path".to_owned(),
+                source: std::io::Error::new(std::io::ErrorKind::Other, "io"),
+            },
+            localizer_rs::errors::Error::InvalidMessage {
+                key: "key".to_owned(),
+                language: "en".to_owned(),
+                position: 0,
+                reason: "reason".to_owned(),
+            },
+            localizer_rs::errors::Error::InvalidFile {
+                path: "path".to_owned(),
+                line: 1,
+                column: 1,
+                reason: "reason".to_owned(),
+            },
+            localizer_rs::errors::Error::LanguageNotFound {
+                language: "en".to_owned(),
+            },
+            localizer_rs::errors::Error::SourceFailed {
+                language: "en".to_owned(),
+                source: "source".into(),
+            },
+            localizer_rs::errors::Error::FileUnwritable {
+                path: "path".to_owned(),
+                source: std::io::Error::new(std::io::ErrorKind::Other, "io"),
+            },
+            localizer_rs::errors::Error::InvalidStyle {
+                name: "style".to_owned(),
+                attribute: "attribute".to_owned(),
+            },
+            localizer_rs::errors::Error::UnbalancedTags {
+                key: "key".to_owned(),
+                language: "en".to_owned(),
+                position: 0,
+                reason: "reason".to_owned(),
+            },
+        ];
+
+        for (index, error) in errors.iter().enumerate() {
+            assert_eq!(error.exit_code(), index as i32 + 1);
+        }
+    }
+
+    #[test]
+    fn test_source() {
+        let error: localizer_rs::errors::Error = localizer_rs::errors::Error::FileNotFound {
+            path: "path".to_owned(),
+            source: std::io::Error::new(std::io::ErrorKind::NotFound, "not found"),
+        };
+
+        assert_eq!(error.source().unwrap().to_string(), "not found");
     }
 
     #[test]
     #[ignore]
     fn raise_helper() {
-        let error: localizer_rs::errors::Error =
-            localizer_rs::errors::Error::new("name", "description", 1);
-        error.raise("details");
+        let error: localizer_rs::errors::Error = localizer_rs::errors::Error::PathNotFound {
+            path: "path".to_owned(),
+        };
+        error.raise();
     }
 
     #[test]
     fn test_raise() {
         let status = std::process::Command::new("cargo")
-            .args(&["test", "--", "--ignored"])
+            .args(["test", "--", "--ignored"])
             .stdout(std::process::Stdio::null())
             .stderr(std::process::Stdio::null())
             .status()
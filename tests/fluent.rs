@@ -0,0 +1,98 @@
+// localizer-rs fluent tests
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+#[allow(unused_imports, clippy::single_component_path_imports)]
+use localizer_rs;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_message() {
+        let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "fr");
+
+        assert_eq!(
+            config.t("test", vec![]),
+            "Quelque chose qui peut être traduit."
+        );
+        assert_eq!(
+            config.t("welcome", vec![("user", "Marie")]),
+            "Bienvenue dans Localizer, Marie !"
+        );
+        assert_eq!(config.t("welcome.title", vec![]), "Localizer");
+        assert_eq!(config.t("menu.open", vec![]), "Ouvrir un fichier");
+    }
+
+    #[test]
+    fn test_styles() {
+        let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "fr");
+
+        assert_eq!(
+            config.t("error", vec![("details", "détails")]),
+            "\x1b[31m\x1b[1mErreur :\x1b[0m détails"
+        );
+    }
+
+    #[test]
+    fn test_select() {
+        let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "fr");
+
+        assert_eq!(config.translate_plural("files", 0, vec![]), "Aucun fichier");
+        assert_eq!(config.translate_plural("files", 1, vec![]), "1 fichier");
+        assert_eq!(config.translate_plural("files", 1.5, vec![]), "1.5 fichier");
+        assert_eq!(config.translate_plural("files", 3, vec![]), "3 fichiers");
+        assert_eq!(config.t("place", vec![("place", "1")]), "1er");
+        assert_eq!(config.t("place", vec![("place", "2")]), "2e");
+    }
+
+    #[test]
+    fn test_fallback() {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "fr-CA");
+        config.set_fallbacks(&["en"]);
+
+        assert_eq!(config.language_of("test"), "fr");
+        assert_eq!(config.language_of("all"), "en");
+    }
+
+    #[test]
+    fn test_invalid_file() {
+        let config: localizer_rs::Config =
+            localizer_rs::Config::new("tests/translations", "invalid");
+
+        match config.try_t("valid", vec![]) {
+            Err(localizer_rs::errors::Error::InvalidFile { line, column, .. }) => {
+                assert_eq!((line, column), (4, 1));
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+}
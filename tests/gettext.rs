@@ -0,0 +1,158 @@
+// localizer-rs gettext tests
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+#[allow(unused_imports, clippy::single_component_path_imports)]
+use localizer_rs;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_po() {
+        let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "es");
+
+        assert_eq!(config.t("test", vec![]), "Algo que se puede traducir.");
+        assert_eq!(config.t("menu\u{4}Open", vec![]), "Abrir");
+        assert_eq!(config.t("verb\u{4}Open", vec![]), "Abrir ahora");
+        assert_eq!(
+            config.t("error", vec![("details", "detalles")]),
+            "\x1b[31m\x1b[1mError:\x1b[0m detalles"
+        );
+    }
+
+    #[test]
+    fn test_fuzzy() {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "es");
+        config.set_fallbacks(&["en"]);
+
+        assert_eq!(config.language_of("success"), "en");
+    }
+
+    #[test]
+    fn test_plural_forms() {
+        let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "es");
+
+        assert_eq!(config.translate_plural("files", 1, vec![]), "1 archivo");
+        assert_eq!(config.translate_plural("files", 2, vec![]), "2 archivos");
+        assert_eq!(config.t("files", vec![]), "{{count}} archivos");
+
+        for path in ["tests/translations", "tests/translations/mo"] {
+            let config: localizer_rs::Config = localizer_rs::Config::new(path, "pl");
+
+            assert_eq!(config.translate_plural("file", 1, vec![]), "1 plik");
+            assert_eq!(config.translate_plural("file", 3, vec![]), "3 pliki");
+            assert_eq!(config.translate_plural("file", 5, vec![]), "5 plików");
+            assert_eq!(config.translate_plural("file", 22, vec![]), "22 pliki");
+            assert_eq!(config.translate_plural("file", 112, vec![]), "112 plików");
+        }
+    }
+
+    #[test]
+    fn test_mo() {
+        let config: localizer_rs::Config = localizer_rs::Config::new("tests/translations/mo", "pl");
+
+        assert_eq!(config.t("menu\u{4}Open", vec![]), "Otwórz");
+        assert_eq!(config.t("escapes", vec![]), "Tab:\t\"quoted\" \\");
+    }
+
+    #[test]
+    fn test_context() {
+        let config: localizer_rs::Config = localizer_rs::Config::new("tests/translations", "pl");
+        let key: String = format!("menu{}Open", localizer_rs::sources::CONTEXT_SEPARATOR);
+
+        assert_eq!(config.t(&key, vec![]), "Otwórz");
+        assert_eq!(config.t("menu.Open", vec![]), "Otwórz menu");
+    }
+
+    #[test]
+    fn test_escapes() {
+        let config: localizer_rs::Config = localizer_rs::Config::new("tests/translations", "pl");
+
+        assert_eq!(config.t("escapes", vec![]), "Tab:\t\"quoted\" \\");
+    }
+
+    #[test]
+    fn test_invalid_file() {
+        let config: localizer_rs::Config =
+            localizer_rs::Config::new("tests/translations", "broken");
+
+        match config.try_t("broken", vec![]) {
+            Err(localizer_rs::errors::Error::InvalidFile { line, column, .. }) => {
+                assert_eq!((line, column), (2, 8));
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_nested_plural_forms() {
+        // Translation paths are relative to the working directory
+        let path: std::path::PathBuf = std::path::Path::new("target")
+            .join(format!("localizer_rs_gettext_{}", std::process::id()));
+        std::fs::create_dir_all(&path).unwrap();
+
+        let expressions: [(&str, String); 3] = [
+            (
+                "parentheses",
+                format!("{}n{}", "(".repeat(100000), ")".repeat(100000)),
+            ),
+            ("negations", format!("{}n", "!".repeat(100000))),
+            ("operators", format!("n{}", "+n".repeat(100000))),
+        ];
+        for (language, expression) in &expressions {
+            std::fs::write(
+                path.join(format!("{}.po", language)),
+                format!(
+                    "msgid \"\"\nmsgstr \"\"\n\"Plural-Forms: nplurals=2; plural={};\\n\"\n",
+                    expression
+                ),
+            )
+            .unwrap();
+        }
+
+        for (language, _expression) in &expressions {
+            let config: localizer_rs::Config =
+                localizer_rs::Config::new(path.to_str().unwrap(), language);
+
+            assert!(
+                matches!(
+                    config.try_t("file", vec![]),
+                    Err(localizer_rs::errors::Error::InvalidFile { .. })
+                ),
+                "{}",
+                language
+            );
+        }
+
+        std::fs::remove_dir_all(&path).unwrap();
+    }
+}
@@ -25,7 +25,7 @@
 // IMPORTS AND USE STATEMENTS //
 ////////////////////////////////
 
-#[allow(unused_imports)]
+#[allow(unused_imports, clippy::single_component_path_imports)]
 use localizer_rs;
 
 
@@ -39,12 +39,16 @@ mod tests {
     fn test_config() {
         let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
 
+        assert_eq!(config.path, "examples/translations");
+        assert_eq!(config.language, "en");
+        assert!(config.fallbacks.is_empty());
+        assert_eq!(config.syntax, localizer_rs::Syntax::Placeholders);
+        assert_eq!(config.color_mode, localizer_rs::styles::ColorMode::Always);
+        assert_eq!(config.color_depth, localizer_rs::styles::ColorDepth::Auto);
+        assert_eq!(config.renderer, localizer_rs::styles::Renderer::Ansi);
         assert_eq!(
-            config,
-            localizer_rs::Config {
-                path: "examples/translations".to_owned(),
-                language: "en".to_owned()
-            }
+            config.theme,
+            localizer_rs::styles::Theme::from_file("examples/translations/theme.json")
         );
     }
 
@@ -54,13 +58,9 @@ mod tests {
             localizer_rs::Config::new("examples/translations", "en");
         config.set_path("examples");
 
-        assert_eq!(
-            config,
-            localizer_rs::Config {
-                path: "examples".to_owned(),
-                language: "en".to_owned()
-            }
-        );
+        assert_eq!(config.path, "examples");
+        assert_eq!(config.language, "en");
+        assert_eq!(config.theme, localizer_rs::styles::Theme::default());
     }
 
     #[test]
@@ -69,13 +69,9 @@ mod tests {
             localizer_rs::Config::new("examples/translations", "en");
         config.set_language("not_en");
 
-        assert_eq!(
-            config,
-            localizer_rs::Config {
-                path: "examples/translations".to_owned(),
-                language: "not_en".to_owned()
-            }
-        );
+        assert_eq!(config.path, "examples/translations");
+        assert_eq!(config.language, "not_en");
+        assert!(config.fallbacks.is_empty());
     }
 
     #[test]
@@ -112,4 +108,194 @@ mod tests {
             "\x1b[31m\x1b[1mError:\x1b[0m Something went wrong"
         );
     }
+
+    #[test]
+    fn test_translate_macro_expressions() {
+        struct User {
+            name: String,
+        }
+
+        let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+        let user: User = User {
+            name: "John Doe".to_owned(),
+        };
+        let balance: f64 = 10.5;
+        let details: &str = "Something went wrong";
+
+        assert_eq!(
+            localizer_rs::t!(config, "success", "balance" = balance, "user" = user.name),
+            "\x1b[32m\x1b[1mSuccess:\x1b[0m Successfully transferred 10.5 to John Doe"
+        );
+        assert_eq!(
+            localizer_rs::t!(
+                config,
+                "success",
+                balance = format!("${}", balance),
+                user = user.name,
+            ),
+            "\x1b[32m\x1b[1mSuccess:\x1b[0m Successfully transferred $10.5 to John Doe"
+        );
+        assert_eq!(
+            localizer_rs::t!(config, "error", details),
+            "\x1b[31m\x1b[1mError:\x1b[0m Something went wrong"
+        );
+        assert_eq!(localizer_rs::t!(config, "files", count = 2 + 1), "3 files");
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert!(localizer_rs::Config::try_new("examples/translations", "en").is_ok());
+        assert!(matches!(
+            localizer_rs::Config::try_new("does_not_exist", "en"),
+            Err(localizer_rs::errors::Error::PathNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_try_set_path() {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "en");
+
+        assert!(config.try_set_path("does_not_exist").is_err());
+        assert_eq!(config.path, "examples/translations");
+    }
+
+    #[test]
+    fn test_try_translate() {
+        let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+        let translation: Result<String, localizer_rs::errors::Error> =
+            config.try_translate("error", vec![("details", "Something went wrong")]);
+
+        assert_eq!(
+            translation.unwrap().as_str(),
+            "\x1b[31m\x1b[1mError:\x1b[0m Something went wrong"
+        );
+        assert!(matches!(
+            config.try_translate("does_not_exist", vec![]),
+            Err(localizer_rs::errors::Error::KeyNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_try_translate_missing_language() {
+        let config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "does_not_exist");
+
+        assert!(matches!(
+            config.try_t("test", vec![]),
+            Err(localizer_rs::errors::Error::FileNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_reload() {
+        let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+
+        assert!(config.try_reload().is_ok());
+        assert_eq!(
+            config.translate("test", vec![]),
+            "Something that can be translated."
+        );
+    }
+
+    #[test]
+    fn test_try_reload_missing_language() {
+        let config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "does_not_exist");
+
+        assert!(matches!(
+            config.try_reload(),
+            Err(localizer_rs::errors::Error::FileNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_clear_cache() {
+        let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+        let cloned: localizer_rs::Config = config.clone();
+        config.reload();
+        config.clear_cache();
+
+        assert_eq!(config, cloned);
+        assert_eq!(
+            config.translate("test", vec![]),
+            "Something that can be translated."
+        );
+    }
+
+    #[test]
+    fn test_path_field() {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "en");
+        assert_eq!(
+            config.translate("test", vec![]),
+            "Something that can be translated."
+        );
+
+        config.path = "tests/translations".to_owned();
+        assert!(matches!(
+            config.try_translate("test", vec![]),
+            Err(localizer_rs::errors::Error::KeyNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_fallback_chain() {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "de-AT");
+        config.set_fallbacks(&["en-GB", "de", "en"]);
+
+        assert_eq!(config.fallback_chain(), vec!["de-AT", "de", "en-GB", "en"]);
+    }
+
+    #[test]
+    fn test_translate_fallback() {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "de-AT");
+
+        assert_eq!(
+            config.translate("error", vec![("details", "Etwas ist schiefgelaufen")]),
+            "\x1b[31m\x1b[1mFehler:\x1b[0m Etwas ist schiefgelaufen"
+        );
+        assert_eq!(config.language_of("error"), "de");
+        assert!(matches!(
+            config.try_translate("test", vec![]),
+            Err(localizer_rs::errors::Error::KeyNotFound { .. })
+        ));
+
+        config.set_fallbacks(&["en"]);
+
+        assert_eq!(
+            config.translate("test", vec![]),
+            "Something that can be translated."
+        );
+        assert_eq!(config.language_of("test"), "en");
+    }
+
+    #[test]
+    fn test_translate_nested() {
+        let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+
+        assert_eq!(config.translate("menu.file.open", vec![]), "Open file");
+        assert_eq!(config.translate("menu.file.save\\.as", vec![]), "Save as");
+    }
+
+    #[test]
+    fn test_translate_nested_error() {
+        let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+
+        match config.try_translate("menu.edit.copy", vec![]) {
+            Err(localizer_rs::errors::Error::KeyNotFound { key, segment, .. }) => {
+                assert_eq!(key, "menu.edit.copy");
+                assert_eq!(segment, "edit");
+            }
+            _ => panic!("Expected a KeyNotFound error"),
+        }
+        match config.try_translate("menu.file", vec![]) {
+            Err(localizer_rs::errors::Error::KeyNotFound { segment, .. }) => {
+                assert_eq!(segment, "file");
+            }
+            _ => panic!("Expected a KeyNotFound error"),
+        }
+    }
 }
@@ -0,0 +1,148 @@
+// localizer-rs lists tests
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+#[allow(unused_imports, clippy::single_component_path_imports)]
+use localizer_rs;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use localizer_rs::lists::ListStyle;
+
+    #[test]
+    fn test_and() {
+        let empty: Vec<&str> = vec![];
+
+        assert_eq!(localizer_rs::lists::format("en", empty, ListStyle::And), "");
+        assert_eq!(
+            localizer_rs::lists::format("en", ["a"], ListStyle::And),
+            "a"
+        );
+        assert_eq!(
+            localizer_rs::lists::format("en", ["a", "b"], ListStyle::And),
+            "a and b"
+        );
+        assert_eq!(
+            localizer_rs::lists::format("en", ["a", "b", "c", "d"], ListStyle::And),
+            "a, b, c, and d"
+        );
+        assert_eq!(
+            localizer_rs::lists::format("en_GB", ["a", "b", "c"], ListStyle::And),
+            "a, b and c"
+        );
+        assert_eq!(
+            localizer_rs::lists::format("de-AT", ["a", "b", "c"], ListStyle::And),
+            "a, b und c"
+        );
+        assert_eq!(
+            localizer_rs::lists::format("zh", ["a", "b", "c"], ListStyle::And),
+            "a、b和c"
+        );
+        assert_eq!(
+            localizer_rs::lists::format("xx", ["a", "b", "c"], ListStyle::And),
+            "a, b, and c"
+        );
+    }
+
+    #[test]
+    fn test_or() {
+        assert_eq!(
+            localizer_rs::lists::format("en", ["a", "b", "c"], ListStyle::Or),
+            "a, b, or c"
+        );
+        assert_eq!(
+            localizer_rs::lists::format("ru", ["a", "b", "c"], ListStyle::Or),
+            "a, b или c"
+        );
+        assert_eq!(
+            localizer_rs::lists::format("ja", ["a", "b", "c"], ListStyle::Or),
+            "a、b、またはc"
+        );
+        assert_eq!(
+            localizer_rs::lists::format("ja", ["a", "b"], ListStyle::Or),
+            "aまたはb"
+        );
+    }
+
+    #[test]
+    fn test_values() {
+        let names: Vec<String> = vec!["{1}".to_owned(), "{0}".to_owned()];
+
+        assert_eq!(
+            localizer_rs::lists::format("en", names, ListStyle::And),
+            "{1} and {0}"
+        );
+        assert_eq!(
+            localizer_rs::lists::format("en", [1, 2, 3], ListStyle::Or),
+            "1, 2, or 3"
+        );
+        assert_eq!(
+            localizer_rs::lists::format("en", &["a", "b"][..], ListStyle::And),
+            "a and b"
+        );
+        assert_eq!(
+            localizer_rs::lists::format("en", "text", ListStyle::And),
+            "text"
+        );
+        assert_eq!(
+            localizer_rs::arguments::Value::from(vec!["a", "b"]).to_string(),
+            "a, b"
+        );
+    }
+
+    #[test]
+    fn test_placeholders() {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "en");
+        let names: Vec<&str> = vec!["Anna", "Ben", "Clara"];
+
+        assert_eq!(
+            localizer_rs::t!(config, "members", names),
+            "Anna, Ben, and Clara joined the group."
+        );
+        assert_eq!(
+            config.format_list(vec!["Anna", "Ben"], ListStyle::Or),
+            "Anna or Ben"
+        );
+
+        config.set_language("de");
+        assert_eq!(
+            config.t(
+                "members",
+                localizer_rs::arguments::Args::new().with("names", names)
+            ),
+            "Anna, Ben und Clara sind der Gruppe beigetreten."
+        );
+        assert_eq!(
+            config.t("members", vec![("names", "Anna")]),
+            "Anna sind der Gruppe beigetreten."
+        );
+    }
+}
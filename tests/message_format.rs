@@ -0,0 +1,148 @@
+// localizer-rs message format tests
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+#[allow(unused_imports, clippy::single_component_path_imports)]
+use localizer_rs;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    fn config() -> localizer_rs::Config {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "en");
+        config.set_syntax(localizer_rs::Syntax::MessageFormat);
+        config
+    }
+
+    #[test]
+    fn test_plural() {
+        let config: localizer_rs::Config = config();
+
+        assert_eq!(
+            config.translate("messages", vec![("count", "0")]),
+            "You have no new messages."
+        );
+        assert_eq!(
+            config.translate("messages", vec![("count", "1")]),
+            "You have one new message."
+        );
+        assert_eq!(
+            config.translate_plural("messages", 12, vec![]),
+            "You have 12 new messages."
+        );
+    }
+
+    #[test]
+    fn test_plural_offset_and_select() {
+        let config: localizer_rs::Config = config();
+        let arguments = |guests: &'static str, gender: &'static str| {
+            vec![
+                ("host", "Alice"),
+                ("guest", "Bob"),
+                ("guests", guests),
+                ("gender", gender),
+            ]
+        };
+
+        assert_eq!(
+            config.translate("invitation", arguments("0", "female")),
+            "Alice invited nobody to her party."
+        );
+        assert_eq!(
+            config.translate("invitation", arguments("1", "male")),
+            "Alice invited Bob to his party."
+        );
+        assert_eq!(
+            config.translate("invitation", arguments("2", "female")),
+            "Alice invited Bob and one other person to her party."
+        );
+        assert_eq!(
+            config.translate("invitation", arguments("5", "unknown")),
+            "Alice invited Bob and 4 other people to their party."
+        );
+    }
+
+    #[test]
+    fn test_selectordinal() {
+        let config: localizer_rs::Config = config();
+        let expected: Vec<(&str, &str)> = vec![
+            ("1", "You finished 1st!"),
+            ("2", "You finished 2nd!"),
+            ("3", "You finished 3rd!"),
+            ("11", "You finished 11th!"),
+            ("23", "You finished 23rd!"),
+        ];
+
+        for (place, translation) in expected {
+            assert_eq!(
+                config.translate("place", vec![("place", place)]),
+                translation
+            );
+        }
+    }
+
+    #[test]
+    fn test_placeholders() {
+        let config: localizer_rs::Config = config();
+
+        assert_eq!(
+            config.translate("error", vec![("details", "Something went wrong")]),
+            "\x1b[31m\x1b[1mError:\x1b[0m Something went wrong"
+        );
+    }
+
+    #[test]
+    fn test_disabled() {
+        let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+
+        assert_eq!(
+            config.translate("place", vec![("place", "1")]),
+            "You finished {place, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}!"
+        );
+    }
+
+    #[test]
+    fn test_invalid_message() {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::new("tests/translations", "en");
+        config.set_syntax(localizer_rs::Syntax::MessageFormat);
+
+        for (key, expected_position) in [("missing_other", 28), ("unclosed", 11), ("unmatched", 10)]
+        {
+            match config.try_translate(key, vec![]) {
+                Err(localizer_rs::errors::Error::InvalidMessage { position, .. }) => {
+                    assert_eq!(position, expected_position, "{}", key);
+                }
+                _ => panic!("Expected an InvalidMessage error for {:?}", key),
+            }
+        }
+    }
+}
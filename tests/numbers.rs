@@ -0,0 +1,264 @@
+// localizer-rs numbers tests
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+#[allow(unused_imports, clippy::single_component_path_imports)]
+use localizer_rs;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use localizer_rs::numbers::NumberFormat;
+
+    #[test]
+    fn test_decimal() {
+        let format: NumberFormat = NumberFormat::decimal();
+
+        assert_eq!(
+            localizer_rs::numbers::format("en", 1234567.5, &format),
+            "1,234,567.5"
+        );
+        assert_eq!(
+            localizer_rs::numbers::format("de", 1234567.5, &format),
+            "1.234.567,5"
+        );
+        assert_eq!(
+            localizer_rs::numbers::format("de-CH", 1234567.5, &format),
+            "1’234’567.5"
+        );
+        assert_eq!(
+            localizer_rs::numbers::format("fr_FR", 1234567.5, &format),
+            "1\u{202f}234\u{202f}567,5"
+        );
+        assert_eq!(
+            localizer_rs::numbers::format("hi", 123456789, &format),
+            "12,34,56,789"
+        );
+        assert_eq!(localizer_rs::numbers::format("pl", 1234, &format), "1234");
+        assert_eq!(
+            localizer_rs::numbers::format("pl", 12345, &format),
+            "12\u{a0}345"
+        );
+        assert_eq!(localizer_rs::numbers::format("sv", -1.5, &format), "−1,5");
+        assert_eq!(localizer_rs::numbers::format("xx", 1000, &format), "1,000");
+        assert_eq!(
+            localizer_rs::numbers::format("en", u64::MAX, &format),
+            "18,446,744,073,709,551,615"
+        );
+        assert_eq!(localizer_rs::numbers::format("en", "text", &format), "text");
+    }
+
+    #[test]
+    fn test_fraction_digits() {
+        assert_eq!(
+            localizer_rs::numbers::format(
+                "en",
+                2.0,
+                &NumberFormat::decimal().fraction_digits(2, 2)
+            ),
+            "2.00"
+        );
+        assert_eq!(
+            localizer_rs::numbers::format("en", 1.23456, &NumberFormat::decimal()),
+            "1.235"
+        );
+        assert_eq!(
+            localizer_rs::numbers::format(
+                "en",
+                2.5,
+                &NumberFormat::decimal().fraction_digits(0, 0)
+            ),
+            "2"
+        );
+        assert_eq!(
+            localizer_rs::numbers::format(
+                "en",
+                3.5,
+                &NumberFormat::decimal().fraction_digits(0, 0)
+            ),
+            "4"
+        );
+        assert_eq!(
+            localizer_rs::numbers::format(
+                "en",
+                999.96,
+                &NumberFormat::decimal().fraction_digits(0, 1)
+            ),
+            "1,000"
+        );
+        assert_eq!(
+            localizer_rs::numbers::format(
+                "en",
+                -0.001,
+                &NumberFormat::decimal().fraction_digits(0, 2)
+            ),
+            "0"
+        );
+    }
+
+    #[test]
+    fn test_percent() {
+        let format: NumberFormat = NumberFormat::percent();
+
+        assert_eq!(localizer_rs::numbers::format("en", 0.256, &format), "26%");
+        assert_eq!(
+            localizer_rs::numbers::format("de", 0.5, &format),
+            "50\u{a0}%"
+        );
+        assert_eq!(localizer_rs::numbers::format("tr", 0.5, &format), "%50");
+        assert_eq!(
+            localizer_rs::numbers::format("en", 12.345, &format.fraction_digits(1, 1)),
+            "1,234.5%"
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        let euro: NumberFormat = NumberFormat::currency("EUR");
+
+        assert_eq!(
+            localizer_rs::numbers::format("en", 1234.5, &euro),
+            "€1,234.50"
+        );
+        assert_eq!(localizer_rs::numbers::format("en", -10, &euro), "-€10.00");
+        assert_eq!(
+            localizer_rs::numbers::format("de", 1234.5, &euro),
+            "1.234,50\u{a0}€"
+        );
+        assert_eq!(
+            localizer_rs::numbers::format("de-AT", 1234.5, &euro),
+            "€\u{a0}1\u{a0}234,50"
+        );
+        assert_eq!(
+            localizer_rs::numbers::format("nl", 10, &euro),
+            "€\u{a0}10,00"
+        );
+        assert_eq!(
+            localizer_rs::numbers::format("fr", 1234.5, &NumberFormat::currency("usd")),
+            "1\u{202f}234,50\u{a0}$US"
+        );
+        assert_eq!(
+            localizer_rs::numbers::format("en", 10, &NumberFormat::currency("CHF")),
+            "CHF\u{a0}10.00"
+        );
+        assert_eq!(
+            localizer_rs::numbers::format("ja", 1234.5, &NumberFormat::currency("JPY")),
+            "￥1,234"
+        );
+        assert_eq!(
+            localizer_rs::numbers::format("pl", 5, &NumberFormat::currency("PLN")),
+            "5,00\u{a0}zł"
+        );
+        assert_eq!(
+            localizer_rs::numbers::format("en", 5, &NumberFormat::currency("XYZ")),
+            "XYZ\u{a0}5.00"
+        );
+
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "en");
+        assert_eq!(
+            localizer_rs::t!(config, "charge", amount = 10),
+            "€10.00 have been charged to your account."
+        );
+        config.set_language("de-AT");
+        assert_eq!(
+            localizer_rs::t!(config, "charge", amount = 1234.5),
+            "€\u{a0}1\u{a0}234,50 wurden von Ihrem Konto abgebucht."
+        );
+    }
+
+    #[test]
+    fn test_placeholders() {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "en");
+
+        assert_eq!(
+            localizer_rs::t!(config, "balance", balance = 1234567.5),
+            "Your balance is 1,234,567.50."
+        );
+        assert_eq!(
+            localizer_rs::t!(config, "progress", ratio = 0.5),
+            "50% done"
+        );
+
+        config.set_language("de");
+        assert_eq!(
+            localizer_rs::t!(config, "balance", balance = 1234567.5),
+            "Ihr Kontostand beträgt 1.234.567,50."
+        );
+        assert_eq!(
+            config.t("progress", vec![("ratio", "0.25")]),
+            "25\u{a0}% erledigt"
+        );
+        assert_eq!(config.t("progress", vec![]), "{{ratio, percent}} erledigt");
+    }
+
+    #[test]
+    fn test_message_format() {
+        let mut config: localizer_rs::Config = localizer_rs::Config::with_source(
+            localizer_rs::sources::Memory::new().with(
+                "de",
+                [("total", "{total, number} ({share, number, percent})")],
+            ),
+            "de",
+        );
+        config.set_syntax(localizer_rs::Syntax::MessageFormat);
+
+        assert_eq!(
+            localizer_rs::t!(config, "total", total = 1234.5, share = 0.5),
+            "1.234,5 (50\u{a0}%)"
+        );
+
+        config.set_language("de-AT");
+        assert_eq!(
+            localizer_rs::t!(config, "total", total = 1234.5, share = 0.5),
+            "1\u{a0}234,5 (50\u{a0}%)"
+        );
+    }
+
+    #[test]
+    fn test_fallback_language() {
+        let mut config: localizer_rs::Config = localizer_rs::Config::with_source(
+            localizer_rs::sources::Memory::new()
+                .with("de", localizer_rs::sources::Catalog::default())
+                .with(
+                    "en",
+                    [("flat", "{{total, number:2}}"), ("icu", "{total, number}")],
+                ),
+            "de",
+        );
+        config.set_fallbacks(&["en"]);
+
+        assert_eq!(localizer_rs::t!(config, "flat", total = 1234.5), "1,234.50");
+
+        config.set_syntax(localizer_rs::Syntax::MessageFormat);
+        assert_eq!(localizer_rs::t!(config, "icu", total = 1234.5), "1,234.5");
+    }
+}
@@ -0,0 +1,158 @@
+// localizer-rs plural tests
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+#[allow(unused_imports, clippy::single_component_path_imports)]
+use localizer_rs;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use localizer_rs::plurals::{cardinal, Category, Operands};
+
+    #[test]
+    fn test_operands() {
+        let operands: Operands = Operands::parse("-1.230").unwrap();
+
+        assert_eq!(operands.n, 1.23);
+        assert_eq!(operands.i, 1);
+        assert_eq!(operands.v, 3);
+        assert_eq!(operands.w, 2);
+        assert_eq!(operands.f, 230);
+        assert_eq!(operands.t, 23);
+        assert!(operands.negative);
+        assert_eq!(operands.to_string(), "-1.230");
+
+        assert_eq!(Operands::from(2.5).to_string(), "2.5");
+        assert_eq!(Operands::from(-3).to_string(), "-3");
+        assert!(Operands::parse("1.").is_none());
+        assert!(Operands::parse("").is_none());
+    }
+
+    #[test]
+    fn test_english() {
+        assert_eq!(cardinal("en", &Operands::from(0)), Category::Other);
+        assert_eq!(cardinal("en", &Operands::from(1)), Category::One);
+        assert_eq!(cardinal("en-GB", &Operands::from(2)), Category::Other);
+        assert_eq!(
+            cardinal("en", &Operands::parse("1.0").unwrap()),
+            Category::Other
+        );
+    }
+
+    #[test]
+    fn test_french() {
+        assert_eq!(cardinal("fr", &Operands::from(0)), Category::One);
+        assert_eq!(cardinal("fr", &Operands::from(1.5)), Category::One);
+        assert_eq!(cardinal("fr", &Operands::from(2)), Category::Other);
+        assert_eq!(cardinal("fr", &Operands::from(1_000_000)), Category::Many);
+    }
+
+    #[test]
+    fn test_polish() {
+        let expected: Vec<(u64, Category)> = vec![
+            (1, Category::One),
+            (2, Category::Few),
+            (4, Category::Few),
+            (5, Category::Many),
+            (12, Category::Many),
+            (21, Category::Many),
+            (22, Category::Few),
+            (112, Category::Many),
+        ];
+
+        for (number, category) in expected {
+            assert_eq!(cardinal("pl", &Operands::from(number)), category);
+        }
+        assert_eq!(cardinal("pl", &Operands::from(1.5)), Category::Other);
+    }
+
+    #[test]
+    fn test_russian() {
+        let expected: Vec<(u64, Category)> = vec![
+            (1, Category::One),
+            (21, Category::One),
+            (11, Category::Many),
+            (3, Category::Few),
+            (13, Category::Many),
+            (25, Category::Many),
+        ];
+
+        for (number, category) in expected {
+            assert_eq!(cardinal("ru", &Operands::from(number)), category);
+        }
+        assert_eq!(cardinal("ru", &Operands::from(2.5)), Category::Other);
+    }
+
+    #[test]
+    fn test_arabic() {
+        let expected: Vec<(u64, Category)> = vec![
+            (0, Category::Zero),
+            (1, Category::One),
+            (2, Category::Two),
+            (3, Category::Few),
+            (110, Category::Few),
+            (11, Category::Many),
+            (199, Category::Many),
+            (100, Category::Other),
+            (102, Category::Other),
+        ];
+
+        for (number, category) in expected {
+            assert_eq!(cardinal("ar", &Operands::from(number)), category);
+        }
+    }
+
+    #[test]
+    fn test_without_plurals() {
+        assert_eq!(cardinal("ja", &Operands::from(1)), Category::Other);
+        assert_eq!(cardinal("zh_Hant", &Operands::from(1)), Category::Other);
+    }
+
+    #[test]
+    fn test_translate_plural() {
+        let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "pl");
+
+        assert_eq!(config.translate_plural("files", 1, vec![]), "1 plik");
+        assert_eq!(config.translate_plural("files", 3, vec![]), "3 pliki");
+        assert_eq!(config.translate_plural("files", 5, vec![]), "5 plików");
+        assert_eq!(config.translate_plural("files", 1.5, vec![]), "1.5 pliku");
+    }
+
+    #[test]
+    fn test_translate_plural_macro() {
+        let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+
+        assert_eq!(localizer_rs::t!(config, "files", count = 0), "No files");
+        assert_eq!(localizer_rs::t!(config, "files", count = 1), "1 file");
+        assert_eq!(localizer_rs::t!(config, "files", count = 2), "2 files");
+        assert_eq!(localizer_rs::t!(config, "files", "count" = "1"), "1 file");
+    }
+}
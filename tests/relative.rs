@@ -0,0 +1,223 @@
+// localizer-rs relative tests
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+#[allow(unused_imports, clippy::single_component_path_imports)]
+use localizer_rs;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use localizer_rs::relative::{RelativeFormat, TimeUnit};
+
+    #[test]
+    fn test_units() {
+        let days: RelativeFormat = RelativeFormat::unit(TimeUnit::Day);
+
+        assert_eq!(localizer_rs::relative::format("en", 1, &days), "in 1 day");
+        assert_eq!(localizer_rs::relative::format("en", -1, &days), "1 day ago");
+        assert_eq!(localizer_rs::relative::format("en", 0, &days), "in 0 days");
+        assert_eq!(
+            localizer_rs::relative::format("en", 1.5, &days),
+            "in 1.5 days"
+        );
+        assert_eq!(
+            localizer_rs::relative::format("en", -1000, &days),
+            "1,000 days ago"
+        );
+        assert_eq!(
+            localizer_rs::relative::format("de", -2, &days),
+            "vor 2 Tagen"
+        );
+        assert_eq!(
+            localizer_rs::relative::format("de_AT", 1, &days),
+            "in 1 Tag"
+        );
+        assert_eq!(
+            localizer_rs::relative::format("es", -3, &days),
+            "hace 3 días"
+        );
+        assert_eq!(localizer_rs::relative::format("ja", 3, &days), "3 日後");
+        assert_eq!(localizer_rs::relative::format("xx", 3, &days), "in 3 days");
+        assert_eq!(
+            localizer_rs::relative::format("en", 1, &RelativeFormat::unit(TimeUnit::Year)),
+            "in 1 year"
+        );
+        assert_eq!(
+            localizer_rs::relative::format("fr", -1, &RelativeFormat::unit(TimeUnit::Month)),
+            "il y a 1 mois"
+        );
+    }
+
+    #[test]
+    fn test_plural_forms() {
+        let minutes: RelativeFormat = RelativeFormat::unit(TimeUnit::Minute);
+
+        assert_eq!(
+            localizer_rs::relative::format("pl", 1, &minutes),
+            "za 1 minutę"
+        );
+        assert_eq!(
+            localizer_rs::relative::format("pl", 3, &minutes),
+            "za 3 minuty"
+        );
+        assert_eq!(
+            localizer_rs::relative::format("pl", -5, &minutes),
+            "5 minut temu"
+        );
+        assert_eq!(
+            localizer_rs::relative::format("pl", -22, &minutes),
+            "22 minuty temu"
+        );
+        assert_eq!(
+            localizer_rs::relative::format("ru", -21, &minutes),
+            "21 минуту назад"
+        );
+        assert_eq!(
+            localizer_rs::relative::format("ru", 1.5, &minutes),
+            "через 1,5 минуты"
+        );
+        assert_eq!(
+            localizer_rs::relative::format("ru", -11, &minutes),
+            "11 минут назад"
+        );
+    }
+
+    #[test]
+    fn test_auto() {
+        let format: RelativeFormat = RelativeFormat::auto();
+
+        assert_eq!(
+            localizer_rs::relative::format("en", -30, &format),
+            "30 seconds ago"
+        );
+        assert_eq!(
+            localizer_rs::relative::format("en", -180, &format),
+            "3 minutes ago"
+        );
+        assert_eq!(
+            localizer_rs::relative::format("en", 5400, &format),
+            "in 2 hours"
+        );
+        assert_eq!(
+            localizer_rs::relative::format("en", -172800, &format),
+            "2 days ago"
+        );
+        assert_eq!(
+            localizer_rs::relative::format("en", 1209600, &format),
+            "in 2 weeks"
+        );
+        assert_eq!(
+            localizer_rs::relative::format("en", -7776000, &format),
+            "3 months ago"
+        );
+        assert_eq!(
+            localizer_rs::relative::format("en", 63072000, &format),
+            "in 2 years"
+        );
+        assert_eq!(
+            localizer_rs::relative::format("de", -3600, &format),
+            "vor 1 Stunde"
+        );
+    }
+
+    #[test]
+    fn test_auto_rounding() {
+        let format: RelativeFormat = RelativeFormat::auto();
+
+        assert_eq!(
+            localizer_rs::relative::format("en", 3599, &format),
+            "in 1 hour"
+        );
+        assert_eq!(
+            localizer_rs::relative::format("en", -86399, &format),
+            "1 day ago"
+        );
+        assert_eq!(
+            localizer_rs::relative::format("en", 59.6, &format),
+            "in 1 minute"
+        );
+        assert_eq!(
+            localizer_rs::relative::format("en", -3569, &format),
+            "59 minutes ago"
+        );
+    }
+
+    #[test]
+    fn test_unsupported_values() {
+        let format: RelativeFormat = RelativeFormat::default();
+
+        assert_eq!(
+            localizer_rs::relative::format("en", "soon", &format),
+            "soon"
+        );
+        assert_eq!(localizer_rs::relative::format("en", true, &format), "true");
+        assert_eq!(
+            localizer_rs::relative::format("en", f64::NAN, &format),
+            "NaN"
+        );
+        assert_eq!(
+            localizer_rs::relative::format("en", "-60", &format),
+            "1 minute ago"
+        );
+    }
+
+    #[test]
+    fn test_placeholders() {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "en");
+
+        assert_eq!(
+            localizer_rs::t!(config, "updated", elapsed = -180),
+            "Updated 3 minutes ago"
+        );
+        assert_eq!(
+            localizer_rs::t!(config, "trial", days = 1),
+            "Your trial ends in 1 day."
+        );
+        assert_eq!(
+            config.format_relative(-2, &RelativeFormat::unit(TimeUnit::Week)),
+            "2 weeks ago"
+        );
+
+        config.set_language("de");
+        assert_eq!(
+            localizer_rs::t!(config, "updated", elapsed = -180),
+            "Aktualisiert vor 3 Minuten"
+        );
+        assert_eq!(
+            config.t("trial", vec![("days", "3")]),
+            "Ihre Testphase endet in 3 Tagen."
+        );
+        assert_eq!(
+            config.t("trial", vec![("days", "bald")]),
+            "Ihre Testphase endet bald."
+        );
+    }
+}
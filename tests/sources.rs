@@ -0,0 +1,162 @@
+// localizer-rs sources tests
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+#[allow(unused_imports, clippy::single_component_path_imports)]
+use localizer_rs;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    fn memory() -> localizer_rs::sources::Memory {
+        localizer_rs::sources::Memory::new()
+            .with("en", [("hello", "Hello {{name}}!"), ("bye", "Goodbye!")])
+            .with("de", [("hello", "Hallo {{name}}!")])
+    }
+
+    #[derive(Debug)]
+    struct Broken;
+
+    impl localizer_rs::sources::CatalogSource for Broken {
+        fn languages(&self) -> Result<Vec<String>, localizer_rs::errors::Error> {
+            Ok(vec![])
+        }
+
+        fn load(
+            &self,
+            language: &str,
+        ) -> Result<localizer_rs::sources::Catalog, localizer_rs::errors::Error> {
+            Err(localizer_rs::errors::Error::SourceFailed {
+                language: language.to_owned(),
+                source: "connection refused".into(),
+            })
+        }
+    }
+
+    #[test]
+    fn test_custom_source() {
+        let mut config: localizer_rs::Config = localizer_rs::Config::with_source(memory(), "de-AT");
+        config.set_fallbacks(&["en"]);
+
+        assert_eq!(config.path, "");
+        assert_eq!(config.t("hello", vec![("name", "Welt")]), "Hallo Welt!");
+        assert_eq!(config.t("bye", vec![]), "Goodbye!");
+        assert_eq!(config.languages(), vec!["de", "en"]);
+    }
+
+    #[test]
+    fn test_source_errors() {
+        let config: localizer_rs::Config = localizer_rs::Config::with_source(memory(), "fr");
+
+        assert!(matches!(
+            config.try_t("hello", vec![]),
+            Err(localizer_rs::errors::Error::LanguageNotFound { .. })
+        ));
+
+        let config: localizer_rs::Config = localizer_rs::Config::with_source(Broken, "broken");
+
+        match config.try_t("hello", vec![]) {
+            Err(error) => {
+                assert_eq!(error.exit_code(), 11);
+                assert_eq!(
+                    std::error::Error::source(&error).unwrap().to_string(),
+                    "connection refused"
+                );
+            }
+            Ok(value) => panic!("Unexpected translation: {:?}", value),
+        }
+    }
+
+    #[test]
+    fn test_set_source() {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "en");
+        assert_eq!(
+            config,
+            localizer_rs::Config::new("examples/translations", "en")
+        );
+
+        config.set_source(memory());
+        assert_eq!(config.t("bye", vec![]), "Goodbye!");
+        assert_ne!(
+            config,
+            localizer_rs::Config::new("examples/translations", "en")
+        );
+
+        config.set_path("examples/translations");
+        assert_eq!(
+            config,
+            localizer_rs::Config::new("examples/translations", "en")
+        );
+    }
+
+    #[test]
+    fn test_files() {
+        use localizer_rs::sources::CatalogSource;
+
+        let files: localizer_rs::sources::Files =
+            localizer_rs::sources::Files::new("tests/translations");
+
+        assert_eq!(
+            files.languages().unwrap(),
+            vec!["broken", "en", "invalid", "pl"]
+        );
+        assert!(files
+            .load("en")
+            .unwrap()
+            .messages()
+            .contains_key("missing_other"));
+        assert!(matches!(
+            files.load("does_not_exist"),
+            Err(localizer_rs::errors::Error::FileNotFound { .. })
+        ));
+        assert!(matches!(
+            localizer_rs::sources::Files::new("does_not_exist").languages(),
+            Err(localizer_rs::errors::Error::PathNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_memory() {
+        use localizer_rs::sources::CatalogSource;
+
+        let mut memory: localizer_rs::sources::Memory = memory();
+        assert_eq!(memory.languages().unwrap(), vec!["de", "en"]);
+        assert_eq!(memory.load("de").unwrap().messages().len(), 1);
+        assert!(matches!(
+            memory.load("fr"),
+            Err(localizer_rs::errors::Error::LanguageNotFound { .. })
+        ));
+
+        memory.set("de", [("bye", "Tschüss!")]);
+        assert!(memory.load("de").unwrap().messages().contains_key("bye"));
+        assert!(!memory.load("de").unwrap().messages().contains_key("hello"));
+    }
+}
@@ -0,0 +1,515 @@
+// localizer-rs styles tests
+// Version: 1.2.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+#[allow(unused_imports, clippy::single_component_path_imports)]
+use localizer_rs;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use localizer_rs::styles::{ColorDepth, ColorMode, Renderer, Theme};
+
+    fn source(style: &str) -> localizer_rs::sources::Memory {
+        localizer_rs::sources::Memory::new().with(
+            "en",
+            [("styled", format!("{{{{{}}}}}text", style).as_str())],
+        )
+    }
+
+    fn styled(depth: ColorDepth, style: &str) -> String {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::with_source(source(style), "en");
+        config.set_color_depth(depth);
+        config.t("styled", vec![])
+    }
+
+    #[test]
+    fn test_color_mode_always() {
+        let config: localizer_rs::Config = localizer_rs::Config::new("examples/translations", "en");
+
+        assert_eq!(config.color_mode, ColorMode::Always);
+        assert_eq!(
+            config.t("error", vec![("details", "Something went wrong")]),
+            "\x1b[31m\x1b[1mError:\x1b[0m Something went wrong"
+        );
+    }
+
+    #[test]
+    fn test_color_mode_never() {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "en");
+        config.set_color_mode(ColorMode::Never);
+
+        assert_eq!(
+            config.t("error", vec![("details", "Something went wrong")]),
+            "Error: Something went wrong"
+        );
+        assert_eq!(
+            config.t("error", vec![("details", "{{bold}}")]),
+            "Error: {{bold}}"
+        );
+        assert_eq!(
+            localizer_rs::t!(config, "success", balance = 10, user = "Bob"),
+            "Success: Successfully transferred 10 to Bob"
+        );
+
+        config.set_syntax(localizer_rs::Syntax::MessageFormat);
+        assert_eq!(
+            config.t("error", vec![("details", "Something went wrong")]),
+            "Error: Something went wrong"
+        );
+    }
+
+    #[test]
+    fn test_color_mode_auto() {
+        let environment = |variables: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                variables
+                    .iter()
+                    .find(|(variable, _value)| *variable == name)
+                    .map(|(_variable, value)| (*value).to_owned())
+            }
+        };
+
+        assert!(ColorMode::Auto.enabled_with(environment(&[]), true));
+        assert!(!ColorMode::Auto.enabled_with(environment(&[]), false));
+        assert!(ColorMode::Auto.enabled_with(environment(&[("CLICOLOR_FORCE", "1")]), false));
+        assert!(ColorMode::Auto.enabled_with(
+            environment(&[("CLICOLOR_FORCE", "1"), ("NO_COLOR", "1")]),
+            false
+        ));
+        assert!(!ColorMode::Auto.enabled_with(
+            environment(&[("CLICOLOR_FORCE", "0"), ("NO_COLOR", "1")]),
+            true
+        ));
+        assert!(!ColorMode::Auto
+            .enabled_with(environment(&[("NO_COLOR", ""), ("CLICOLOR", "0")]), true));
+        assert!(ColorMode::Auto.enabled_with(environment(&[("NO_COLOR", "")]), true));
+        assert!(!ColorMode::Never.enabled_with(environment(&[("CLICOLOR_FORCE", "1")]), true));
+        assert!(ColorMode::Always.enabled_with(environment(&[("NO_COLOR", "1")]), false));
+    }
+
+    #[test]
+    fn test_true_colors() {
+        assert_eq!(
+            styled(ColorDepth::TrueColor, "color.#ff8800"),
+            "\x1b[38;2;255;136;0mtext"
+        );
+        assert_eq!(
+            styled(ColorDepth::TrueColor, "color.#F80"),
+            "\x1b[38;2;255;136;0mtext"
+        );
+        assert_eq!(
+            styled(ColorDepth::TrueColor, "back.rgb(10,20,30)"),
+            "\x1b[48;2;10;20;30mtext"
+        );
+        assert_eq!(
+            styled(ColorDepth::TrueColor, "back.rgb(10, 20, 30)"),
+            "\x1b[48;2;10;20;30mtext"
+        );
+        assert_eq!(
+            styled(ColorDepth::TrueColor, "color.214"),
+            "\x1b[38;5;214mtext"
+        );
+        assert_eq!(styled(ColorDepth::TrueColor, "color.red"), "\x1b[31mtext");
+    }
+
+    #[test]
+    fn test_palette_colors() {
+        assert_eq!(
+            styled(ColorDepth::Palette, "color.#ff8800"),
+            "\x1b[38;5;208mtext"
+        );
+        assert_eq!(
+            styled(ColorDepth::Palette, "back.rgb(128,128,128)"),
+            "\x1b[48;5;244mtext"
+        );
+        assert_eq!(
+            styled(ColorDepth::Palette, "back.214"),
+            "\x1b[48;5;214mtext"
+        );
+    }
+
+    #[test]
+    fn test_basic_colors() {
+        assert_eq!(styled(ColorDepth::Basic, "color.#ff8800"), "\x1b[33mtext");
+        assert_eq!(styled(ColorDepth::Basic, "color.#ff0000"), "\x1b[91mtext");
+        assert_eq!(styled(ColorDepth::Basic, "back.rgb(0,0,0)"), "\x1b[40mtext");
+        assert_eq!(styled(ColorDepth::Basic, "color.9"), "\x1b[91mtext");
+        assert_eq!(styled(ColorDepth::Basic, "back.196"), "\x1b[101mtext");
+        assert_eq!(styled(ColorDepth::Basic, "color.255"), "\x1b[37mtext");
+    }
+
+    #[test]
+    fn test_invalid_colors() {
+        assert_eq!(
+            styled(ColorDepth::TrueColor, "color.#ff88"),
+            "{{color.#ff88}}text"
+        );
+        assert_eq!(
+            styled(ColorDepth::TrueColor, "color.256"),
+            "{{color.256}}text"
+        );
+        assert_eq!(
+            styled(ColorDepth::TrueColor, "back.rgb(1,2)"),
+            "{{back.rgb(1,2)}}text"
+        );
+        assert_eq!(
+            styled(ColorDepth::TrueColor, "color.orange"),
+            "{{color.orange}}text"
+        );
+    }
+
+    #[test]
+    fn test_color_depth_auto() {
+        let environment = |variables: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                variables
+                    .iter()
+                    .find(|(variable, _value)| *variable == name)
+                    .map(|(_variable, value)| (*value).to_owned())
+            }
+        };
+
+        assert_eq!(
+            ColorDepth::Auto.resolve_with(environment(&[("COLORTERM", "truecolor")])),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            ColorDepth::Auto.resolve_with(environment(&[("WT_SESSION", "1"), ("TERM", "xterm")])),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            ColorDepth::Auto.resolve_with(environment(&[("TERM", "xterm-direct")])),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            ColorDepth::Auto.resolve_with(environment(&[("TERM", "xterm-256color")])),
+            ColorDepth::Palette
+        );
+        assert_eq!(
+            ColorDepth::Auto.resolve_with(environment(&[("TERM", "xterm")])),
+            ColorDepth::Basic
+        );
+        assert_eq!(
+            ColorDepth::Auto.resolve_with(environment(&[])),
+            ColorDepth::Basic
+        );
+        assert_eq!(
+            ColorDepth::TrueColor.resolve_with(environment(&[("TERM", "xterm")])),
+            ColorDepth::TrueColor
+        );
+    }
+
+    #[test]
+    fn test_color_depth_config() {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "en");
+        config.set_color_depth(ColorDepth::TrueColor);
+
+        assert_eq!(
+            config.t("warning", vec![("details", "Low disk space")]),
+            "\x1b[38;2;255;136;0m\x1b[1mWarning:\x1b[0m Low disk space"
+        );
+
+        config.set_color_mode(ColorMode::Never);
+        assert_eq!(
+            config.t("warning", vec![("details", "Low disk space")]),
+            "Warning: Low disk space"
+        );
+    }
+
+    #[test]
+    fn test_theme_default() {
+        assert_eq!(
+            styled(ColorDepth::TrueColor, "style.error"),
+            "\x1b[31m\x1b[1mtext"
+        );
+        assert_eq!(
+            styled(ColorDepth::TrueColor, "style.highlight"),
+            "\x1b[1m\x1b[4mtext"
+        );
+        assert_eq!(
+            styled(ColorDepth::TrueColor, "style.missing"),
+            "{{style.missing}}text"
+        );
+    }
+
+    #[test]
+    fn test_theme_config() {
+        let mut theme: Theme = Theme::new();
+        theme.set("error", "color.#d70000 bold");
+        theme.set("brand", "back.magenta italic");
+        theme.remove("info");
+
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::with_source(source("style.error"), "en");
+        config.set_color_depth(ColorDepth::TrueColor);
+        config.set_theme(theme.clone());
+        assert_eq!(config.t("styled", vec![]), "\x1b[38;2;215;0;0m\x1b[1mtext");
+
+        config.set_source(source("style.brand"));
+        assert_eq!(config.t("styled", vec![]), "\x1b[45m\x1b[3mtext");
+
+        config.set_source(source("style.info"));
+        assert_eq!(config.t("styled", vec![]), "{{style.info}}text");
+
+        config.set_source(source("style.brand"));
+        config.set_syntax(localizer_rs::Syntax::MessageFormat);
+        assert_eq!(config.t("styled", vec![]), "\x1b[45m\x1b[3mtext");
+
+        config.set_color_mode(ColorMode::Never);
+        assert_eq!(config.t("styled", vec![]), "text");
+    }
+
+    #[test]
+    fn test_theme_file() {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "en");
+        config.set_color_depth(ColorDepth::TrueColor);
+
+        assert_eq!(
+            config.theme.get("highlight"),
+            Some(&["color.magenta".to_owned(), "underline".to_owned()][..])
+        );
+        assert!(config.theme.get("brand").is_some());
+        assert!(config.theme.get("error").is_some());
+        assert_eq!(
+            config.t("notice", vec![("details", "New version available")]),
+            "\x1b[35m\x1b[4mNotice:\x1b[0m New version available"
+        );
+        assert!(!config.languages().contains(&"theme".to_owned()));
+    }
+
+    #[test]
+    fn test_invalid_theme() {
+        let mut theme: Theme = Theme::new();
+
+        assert!(matches!(
+            theme.try_set("brand", "bold color.purple"),
+            Err(localizer_rs::errors::Error::InvalidStyle { name, attribute })
+                if name == "brand" && attribute == "color.purple"
+        ));
+        assert!(matches!(
+            theme.try_set("brand", "style.error"),
+            Err(localizer_rs::errors::Error::InvalidStyle { .. })
+        ));
+        assert_eq!(theme, Theme::new());
+        assert!(matches!(
+            Theme::try_from_file("examples/translations/missing.json"),
+            Err(localizer_rs::errors::Error::FileNotFound { .. })
+        ));
+        assert!(matches!(
+            Theme::try_from_file("examples/translations/en.json"),
+            Err(localizer_rs::errors::Error::Parse { .. })
+        ));
+    }
+
+    #[test]
+    fn test_renderer_plain() {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "en");
+        config.set_renderer(Renderer::Plain);
+
+        assert_eq!(
+            config.t("warning", vec![("details", "<Low> disk space")]),
+            "Warning: <Low> disk space"
+        );
+        assert_eq!(
+            config.t("notice", vec![("details", "{{bold}}")]),
+            "Notice: {{bold}}"
+        );
+    }
+
+    #[test]
+    fn test_renderer_html() {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "en");
+        config.set_renderer(Renderer::Html);
+        config.set_color_mode(ColorMode::Never);
+
+        assert_eq!(
+            config.t("warning", vec![("details", "\"A\" & 'B' <c>")]),
+            "<span style=\"color: #ff8800\"><strong>Warning:</strong></span> &quot;A&quot; &amp; &#39;B&#39; &lt;c&gt;"
+        );
+        assert_eq!(
+            config.t("notice", vec![("details", "details")]),
+            "<span style=\"color: #cd00cd\"><u>Notice:</u></span> details"
+        );
+
+        config.set_source(source("back.rgb(10,20,30)}}{{overline"));
+        assert_eq!(
+            config.t("styled", vec![]),
+            "<span style=\"background-color: #0a141e\"><span style=\"text-decoration: overline\">text</span></span>"
+        );
+
+        config.set_source(source("style.missing"));
+        assert_eq!(config.t("styled", vec![]), "{{style.missing}}text");
+    }
+
+    #[test]
+    fn test_renderer_markdown() {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "en");
+        config.set_renderer(Renderer::Markdown);
+
+        assert_eq!(
+            config.t("error", vec![("details", "*not* [a] link_")]),
+            "**Error:** \\*not\\* \\[a\\] link\\_"
+        );
+        assert_eq!(
+            config.t("notice", vec![("details", "details")]),
+            "Notice: details"
+        );
+
+        config.set_source(source("bold}}{{italic"));
+        assert_eq!(config.t("styled", vec![]), "***text***");
+    }
+
+    #[test]
+    fn test_renderer_per_call() {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "en");
+
+        assert_eq!(
+            config.translate_as("error", vec![("details", "a < b")], Renderer::Html),
+            "<span style=\"color: #cd0000\"><strong>Error:</strong></span> a &lt; b"
+        );
+        assert_eq!(
+            config.t("error", vec![("details", "a < b")]),
+            "\x1b[31m\x1b[1mError:\x1b[0m a < b"
+        );
+
+        config.set_syntax(localizer_rs::Syntax::MessageFormat);
+        assert_eq!(
+            config
+                .try_translate_as("error", vec![("details", "a < b")], Renderer::Html)
+                .unwrap(),
+            "<span style=\"color: #cd0000\"><strong>Error:</strong></span> a &lt; b"
+        );
+        assert!(config
+            .try_translate_as("does_not_exist", vec![], Renderer::Plain)
+            .is_err());
+    }
+
+    #[test]
+    fn test_style_tags() {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "en");
+        let arguments: Vec<(&str, &str)> = vec![("file", "a.txt"), ("folder", "docs")];
+
+        assert_eq!(
+            config.t("moved", arguments.clone()),
+            "Moved \x1b[1ma.txt to \x1b[32mdocs\x1b[0m\x1b[1m\x1b[0m."
+        );
+        assert_eq!(
+            config.translate_as("moved", arguments.clone(), Renderer::Html),
+            "Moved <strong>a.txt to <span style=\"color: #00cd00\">docs</span></strong>."
+        );
+        assert_eq!(
+            config.translate_as("moved", arguments.clone(), Renderer::Markdown),
+            "Moved **a.txt to docs**."
+        );
+
+        config.set_color_mode(ColorMode::Never);
+        assert_eq!(config.t("moved", arguments.clone()), "Moved a.txt to docs.");
+        assert_eq!(
+            config.t("moved", vec![("file", "{{/bold}}"), ("folder", "docs")]),
+            "Moved {{/bold}} to docs."
+        );
+
+        config.set_color_mode(ColorMode::Always);
+        config.set_source(source("style.error}}a{{/style.error"));
+        assert_eq!(config.t("styled", vec![]), "\x1b[31m\x1b[1ma\x1b[0mtext");
+    }
+
+    #[test]
+    fn test_style_tags_message_format() {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "en");
+        config.set_syntax(localizer_rs::Syntax::MessageFormat);
+        config.set_source(source(
+            "bold}}{count, plural, one {# file} other {# files}}{{/bold",
+        ));
+
+        assert_eq!(
+            config.translate_as("styled", [("count", 2)], Renderer::Html),
+            "<strong>2 files</strong>text"
+        );
+    }
+
+    #[test]
+    fn test_style_arguments_message_format() {
+        let mut config: localizer_rs::Config =
+            localizer_rs::Config::new("examples/translations", "en");
+        config.set_syntax(localizer_rs::Syntax::MessageFormat);
+        config.set_source(source("bold}}{name}{{/bold"));
+
+        assert_eq!(
+            config
+                .try_translate("styled", vec![("name", "{{/bold}}")])
+                .unwrap(),
+            "\x1b[1m{{/bold}}\x1b[0mtext"
+        );
+        assert_eq!(
+            config.translate_as("styled", vec![("name", "{{bold}}")], Renderer::Html),
+            "<strong>{{bold}}</strong>text"
+        );
+
+        config.set_source(source("end}}Hi {name}!{{end"));
+        assert_eq!(
+            config.translate_as("styled", vec![("name", "{{bold}}")], Renderer::Plain),
+            "Hi {{bold}}!text"
+        );
+    }
+
+    #[test]
+    fn test_unbalanced_style_tags() {
+        let unbalanced: [(&str, usize); 5] = [
+            ("bold}}a{{/italic", 9),
+            ("bold}}a{{/bold}}{{italic", 18),
+            ("/bold", 0),
+            ("bold}}a{{end}}{{/bold", 9),
+            ("bold}}{{italic}}a{{/bold}}{{/italic", 19),
+        ];
+
+        for (style, expected) in unbalanced {
+            let config: localizer_rs::Config =
+                localizer_rs::Config::with_source(source(style), "en");
+
+            match config.try_translate("styled", vec![]) {
+                Err(localizer_rs::errors::Error::UnbalancedTags { position, .. }) => {
+                    assert_eq!(position, expected, "{:?}", style);
+                }
+                _ => panic!("Expected an UnbalancedTags error for {:?}", style),
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+// Generated by localizer-rs from the "en" translations in "tests/translations/codegen". Do not edit.
+
+#[doc = "Translates the \"files\" key.\n\nReference translation: {{count}} files in {{folder}}"]
+pub fn files<C: ::std::convert::Into<::localizer_rs::plurals::Operands>>(config: &::localizer_rs::Config, count: C, folder: impl ::std::convert::Into<::localizer_rs::arguments::Value>) -> ::std::string::String {
+    config.translate_plural("files", count, ::localizer_rs::arguments::Args::new().with("folder", folder))
+}
+
+#[doc = "Tries to translate the \"files\" key.\n\nReference translation: {{count}} files in {{folder}}"]
+pub fn try_files<C: ::std::convert::Into<::localizer_rs::plurals::Operands>>(config: &::localizer_rs::Config, count: C, folder: impl ::std::convert::Into<::localizer_rs::arguments::Value>) -> ::std::result::Result<::std::string::String, ::localizer_rs::errors::Error> {
+    config.try_translate_plural("files", count, ::localizer_rs::arguments::Args::new().with("folder", folder))
+}
+
+#[doc = "Translates the \"greeting\" key.\n\nReference translation: Hello {{name}}!"]
+pub fn greeting(config: &::localizer_rs::Config, name: impl ::std::convert::Into<::localizer_rs::arguments::Value>) -> ::std::string::String {
+    config.t("greeting", ::localizer_rs::arguments::Args::new().with("name", name))
+}
+
+#[doc = "Tries to translate the \"greeting\" key.\n\nReference translation: Hello {{name}}!"]
+pub fn try_greeting(config: &::localizer_rs::Config, name: impl ::std::convert::Into<::localizer_rs::arguments::Value>) -> ::std::result::Result<::std::string::String, ::localizer_rs::errors::Error> {
+    config.try_t("greeting", ::localizer_rs::arguments::Args::new().with("name", name))
+}
+
+#[doc = "Translates the \"total\" key.\n\nReference translation: Total: {{amount, number:2}} for {{items, list}}"]
+pub fn total(config: &::localizer_rs::Config, amount: impl ::std::convert::Into<::localizer_rs::arguments::Value>, items: impl ::std::convert::Into<::localizer_rs::arguments::Value>) -> ::std::string::String {
+    config.t("total", ::localizer_rs::arguments::Args::new().with("amount", amount).with("items", items))
+}
+
+#[doc = "Tries to translate the \"total\" key.\n\nReference translation: Total: {{amount, number:2}} for {{items, list}}"]
+pub fn try_total(config: &::localizer_rs::Config, amount: impl ::std::convert::Into<::localizer_rs::arguments::Value>, items: impl ::std::convert::Into<::localizer_rs::arguments::Value>) -> ::std::result::Result<::std::string::String, ::localizer_rs::errors::Error> {
+    config.try_t("total", ::localizer_rs::arguments::Args::new().with("amount", amount).with("items", items))
+}
+
+#[doc = "Translates the \"try_files\" key.\n\nReference translation: Try again"]
+pub fn try_files_(config: &::localizer_rs::Config) -> ::std::string::String {
+    config.t("try_files", ::localizer_rs::arguments::Args::new())
+}
+
+#[doc = "Tries to translate the \"try_files\" key.\n\nReference translation: Try again"]
+pub fn try_try_files_(config: &::localizer_rs::Config) -> ::std::result::Result<::std::string::String, ::localizer_rs::errors::Error> {
+    config.try_t("try_files", ::localizer_rs::arguments::Args::new())
+}
+
+#[doc = "Translates the \"type\" key.\n\nReference translation: {{bold}}{{type}}:{{end}} {{userName}}"]
+pub fn type_(config: &::localizer_rs::Config, type_: impl ::std::convert::Into<::localizer_rs::arguments::Value>, user_name: impl ::std::convert::Into<::localizer_rs::arguments::Value>) -> ::std::string::String {
+    config.t("type", ::localizer_rs::arguments::Args::new().with("type", type_).with("userName", user_name))
+}
+
+#[doc = "Tries to translate the \"type\" key.\n\nReference translation: {{bold}}{{type}}:{{end}} {{userName}}"]
+pub fn try_type_(config: &::localizer_rs::Config, type_: impl ::std::convert::Into<::localizer_rs::arguments::Value>, user_name: impl ::std::convert::Into<::localizer_rs::arguments::Value>) -> ::std::result::Result<::std::string::String, ::localizer_rs::errors::Error> {
+    config.try_t("type", ::localizer_rs::arguments::Args::new().with("type", type_).with("userName", user_name))
+}
+
+pub mod menu {
+    #[doc = "Translates the \"menu.open\" key.\n\nReference translation: Open"]
+    pub fn open(config: &::localizer_rs::Config) -> ::std::string::String {
+        config.t("menu.open", ::localizer_rs::arguments::Args::new())
+    }
+
+    #[doc = "Tries to translate the \"menu.open\" key.\n\nReference translation: Open"]
+    pub fn try_open(config: &::localizer_rs::Config) -> ::std::result::Result<::std::string::String, ::localizer_rs::errors::Error> {
+        config.try_t("menu.open", ::localizer_rs::arguments::Args::new())
+    }
+
+    #[doc = "Translates the \"menu.save\\\\.as\" key.\n\nReference translation: Save as"]
+    pub fn save_as(config: &::localizer_rs::Config) -> ::std::string::String {
+        config.t("menu.save\\.as", ::localizer_rs::arguments::Args::new())
+    }
+
+    #[doc = "Tries to translate the \"menu.save\\\\.as\" key.\n\nReference translation: Save as"]
+    pub fn try_save_as(config: &::localizer_rs::Config) -> ::std::result::Result<::std::string::String, ::localizer_rs::errors::Error> {
+        config.try_t("menu.save\\.as", ::localizer_rs::arguments::Args::new())
+    }
+}
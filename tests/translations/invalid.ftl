@@ -0,0 +1,3 @@
+valid = This message is valid.
+
+invalid = { $unclosed